@@ -1,5 +1,77 @@
+use std::{borrow::Cow, sync::Arc};
+
 use bench::{derive_benchmark_items, DeriveOptions};
 use criterion::{criterion_group, criterion_main, Criterion};
+use rolldown::{
+  plugin::{
+    HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+    HookResolveIdReturn, Plugin, PluginContext,
+  },
+  BundlerOptions, InputItem,
+};
+
+const SYNTHETIC_PREFIX: &str = "synthetic:";
+
+/// Serves a graph of `module_count` modules from memory, where the module `n` imports the modules `2n + 1` and
+/// `2n + 2`. Each module is parsed and scanned in its own task, so the graph shows how well scanning scales with the
+/// worker threads of the runtime.
+#[derive(Debug)]
+struct SyntheticGraph {
+  module_count: usize,
+}
+
+impl Plugin for SyntheticGraph {
+  fn name(&self) -> Cow<'static, str> {
+    "synthetic-graph".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      args
+        .specifier
+        .starts_with(SYNTHETIC_PREFIX)
+        .then(|| HookResolveIdOutput { id: args.specifier.to_string(), ..Default::default() }),
+    )
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let Some(n) = args.id.strip_prefix(SYNTHETIC_PREFIX) else {
+      return Ok(None);
+    };
+    let n: usize = n.parse()?;
+    let children = [2 * n + 1, 2 * n + 2].into_iter().filter(|child| *child < self.module_count);
+    let mut code = String::new();
+    for child in children.clone() {
+      code.push_str(&format!("import {{ value{child} }} from '{SYNTHETIC_PREFIX}{child}';\n"));
+    }
+    code.push_str(&format!(
+      "export class Node{n} {{\n  constructor(items) {{ this.items = items.map((item) => item * {n}); }}\n  sum() {{ return this.items.reduce((a, b) => a + b, 0); }}\n}}\n"
+    ));
+    code.push_str(&format!(
+      "export const value{n} = new Node{n}([{}]).sum();\n",
+      children
+        .map(|child| format!("value{child}"))
+        .chain(std::iter::once("1".to_string()))
+        .collect::<Vec<_>>()
+        .join(", ")
+    ));
+    Ok(Some(HookLoadOutput { code, ..Default::default() }))
+  }
+}
+
+fn synthetic_graph_options() -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: format!("{SYNTHETIC_PREFIX}0"),
+    }]),
+    ..Default::default()
+  }
+}
 
 fn criterion_benchmark(c: &mut Criterion) {
   let mut group = c.benchmark_group("scan");
@@ -38,6 +110,31 @@ fn criterion_benchmark(c: &mut Criterion) {
       });
     });
   });
+
+  // The same graph scanned with a single worker thread and with the default number of worker threads. The runtime is
+  // created once, so only the scan itself is measured.
+  for worker_threads in [Some(1), None] {
+    let name = match worker_threads {
+      Some(1) => "synthetic-5k-single-thread",
+      _ => "synthetic-5k",
+    };
+    let mut runtime = tokio::runtime::Builder::new_multi_thread();
+    if let Some(worker_threads) = worker_threads {
+      runtime.worker_threads(worker_threads);
+    }
+    let runtime = runtime.enable_all().build().unwrap();
+    group.bench_function(format!("scan@{name}"), move |b| {
+      b.iter(|| {
+        runtime.block_on(async {
+          let mut rolldown_bundler = rolldown::Bundler::with_plugins(
+            synthetic_graph_options(),
+            vec![Arc::new(SyntheticGraph { module_count: 5000 })],
+          );
+          let _output = rolldown_bundler.scan().await.unwrap().expect("should not failed in scan");
+        });
+      });
+    });
+  }
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,25 +1,37 @@
 use oxc::{
   ast::{
-    ast::{Expression, IdentifierReference, MemberExpression},
+    ast::{Expression, IdentifierReference, MemberExpression, Statement},
     visit::walk,
     Visit,
   },
   codegen::{self, CodeGenerator, Gen},
+  semantic::ScopeFlags,
   span::GetSpan,
 };
 use rolldown_common::ImportKind;
 
-use crate::utils::call_expression_ext::CallExpressionExt;
+use crate::utils::{
+  call_expression_ext::CallExpressionExt, jsx_element_name::jsx_element_name_reference,
+};
 
-use super::{side_effect_detector::SideEffectDetector, AstScanner};
+use super::{is_import_meta, side_effect_detector::SideEffectDetector, AstScanner};
 
 impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
   fn visit_program(&mut self, program: &oxc::ast::ast::Program<'ast>) {
+    let mut is_unreachable = false;
     for (idx, stmt) in program.body.iter().enumerate() {
       self.current_stmt_info.stmt_idx = Some(idx);
-      self.current_stmt_info.side_effect =
-        SideEffectDetector::new(self.scopes, self.source, self.trivias)
-          .detect_side_effect_of_stmt(stmt);
+      // Module declarations are hoisted, so they are evaluated even if they come after a top-level `throw`.
+      if is_unreachable && stmt.as_module_declaration().is_none() {
+        self.current_stmt_info.is_unreachable = true;
+      } else {
+        self.current_stmt_info.side_effect =
+          SideEffectDetector::new(self.scopes, self.source, self.trivias)
+            .detect_side_effect_of_stmt(stmt);
+      }
+      // Only straight-line control flow of top-level statements is considered.
+      is_unreachable |=
+        matches!(stmt, Statement::ThrowStatement(_) | Statement::ReturnStatement(_));
 
       if cfg!(debug_assertions) {
         let mut codegen = CodeGenerator::new();
@@ -32,6 +44,35 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     }
   }
 
+  fn visit_function(&mut self, func: &oxc::ast::ast::Function<'ast>, flags: ScopeFlags) {
+    self.function_depth += 1;
+    walk::walk_function(self, func, flags);
+    self.function_depth -= 1;
+  }
+
+  fn visit_arrow_function_expression(
+    &mut self,
+    expr: &oxc::ast::ast::ArrowFunctionExpression<'ast>,
+  ) {
+    self.function_depth += 1;
+    walk::walk_arrow_function_expression(self, expr);
+    self.function_depth -= 1;
+  }
+
+  fn visit_await_expression(&mut self, expr: &oxc::ast::ast::AwaitExpression<'ast>) {
+    if self.function_depth == 0 {
+      self.set_top_level_await(expr.span);
+    }
+    walk::walk_await_expression(self, expr);
+  }
+
+  fn visit_for_of_statement(&mut self, stmt: &oxc::ast::ast::ForOfStatement<'ast>) {
+    if stmt.r#await && self.function_depth == 0 {
+      self.set_top_level_await(stmt.span);
+    }
+    walk::walk_for_of_statement(self, stmt);
+  }
+
   fn visit_binding_identifier(&mut self, ident: &oxc::ast::ast::BindingIdentifier) {
     let symbol_id = ident.symbol_id.get().unwrap();
     if self.is_top_level(symbol_id) {
@@ -55,6 +96,19 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
             Expression::Identifier(id) => {
               break self.resolve_identifier_to_top_level_symbol(id);
             }
+            Expression::MetaProperty(meta) if is_import_meta(meta) => {
+              let chain = props_in_reverse_order.iter().rev().fold(
+                String::from("import.meta"),
+                |mut chain, prop| {
+                  chain.push('.');
+                  chain.push_str(prop.name.as_str());
+                  chain
+                },
+              );
+              self.add_import_meta_usage(chain.into(), expr.span());
+              // The chain consists of static properties only, so there is nothing else to visit.
+              return;
+            }
             _ => break None,
           }
         };
@@ -79,14 +133,39 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
       _ => {}
     };
     walk::walk_member_expression(self, expr);
+    self.scan_dynamic_import_member_access(expr);
+  }
+
+  fn visit_meta_property(&mut self, meta: &oxc::ast::ast::MetaProperty<'ast>) {
+    // Accessing properties of `import.meta` is recorded in `visit_member_expression`.
+    if is_import_meta(meta) {
+      self.add_import_meta_usage("import.meta".into(), meta.span);
+    }
+  }
+
+  fn visit_variable_declarator(&mut self, decl: &oxc::ast::ast::VariableDeclarator<'ast>) {
+    walk::walk_variable_declarator(self, decl);
+    self.scan_dynamic_import_destructuring(decl);
   }
 
   fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+    self.try_diagnostic_forbid_import_assign(ident);
     if let Some(top_level_symbol_id) = self.resolve_identifier_to_top_level_symbol(ident) {
       self.add_referenced_symbol(top_level_symbol_id);
     }
   }
 
+  fn visit_jsx_element_name(&mut self, name: &oxc::ast::ast::JSXElementName<'ast>) {
+    // JSX names don't carry a `ReferenceId`, so `<Foo />` is resolved by name. Only top-level bindings matter to
+    // tree shaking, and a local binding shadowing `Foo` would just keep the top-level `Foo` alive.
+    if let Some(name) = jsx_element_name_reference(name) {
+      if let Some(symbol_id) = self.scopes.get_root_binding(name) {
+        self.add_referenced_symbol((self.idx, symbol_id).into());
+      }
+    }
+    walk::walk_jsx_element_name(self, name);
+  }
+
   fn visit_statement(&mut self, stmt: &oxc::ast::ast::Statement<'ast>) {
     if let Some(decl) = stmt.as_module_declaration() {
       self.scan_module_decl(decl);
@@ -102,6 +181,11 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
         expr.source.span().start,
       );
       self.result.imports.insert(expr.span, id);
+      self.result.import_records[id].attributes =
+        expr.arguments.first().map(Self::dynamic_import_attributes).unwrap_or_default();
+      self.mark_ignored_by_comment(id, expr.span.start, request.span.start);
+    } else {
+      self.add_non_literal_dynamic_import(expr);
     }
     walk::walk_import_expression(self, expr);
   }
@@ -111,19 +195,44 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
       oxc::ast::ast::AssignmentTarget::AssignmentTargetIdentifier(id_ref) => {
         self.try_diagnostic_forbid_const_assign(id_ref);
       }
-      _ => {}
+      target => {
+        if let Some(member_expr) = target.as_member_expression() {
+          self.try_diagnostic_assign_to_import_meta(member_expr);
+          self.scan_cjs_exports_assignment(member_expr, &node.right, node.span);
+        }
+      }
     }
     walk::walk_assignment_expression(self, node);
   }
 
+  fn visit_update_expression(&mut self, node: &oxc::ast::ast::UpdateExpression<'ast>) {
+    if let Some(member_expr) = node.argument.as_member_expression() {
+      self.try_diagnostic_assign_to_import_meta(member_expr);
+    }
+    walk::walk_update_expression(self, node);
+  }
+
   fn visit_call_expression(&mut self, expr: &oxc::ast::ast::CallExpression<'ast>) {
     if expr.is_global_require_call(self.scopes) {
-      if let Some(oxc::ast::ast::Argument::StringLiteral(request)) = &expr.arguments.first() {
-        let id =
-          self.add_import_record(request.value.as_str(), ImportKind::Require, request.span().start);
-        self.result.imports.insert(expr.span, id);
+      match expr.arguments.first() {
+        Some(oxc::ast::ast::Argument::StringLiteral(request)) => {
+          let id = self.add_import_record(
+            request.value.as_str(),
+            ImportKind::Require,
+            request.span().start,
+          );
+          self.result.imports.insert(expr.span, id);
+          self.mark_ignored_by_comment(id, expr.span.start, request.span.start);
+        }
+        Some(oxc::ast::ast::Argument::TemplateLiteral(request)) => {
+          self.add_import_pattern(ImportKind::Require, request);
+        }
+        _ => {}
       }
     }
+    self.scan_cjs_es_module_define_property(expr);
+    self.scan_cjs_object_assign_to_exports(expr);
+    self.scan_import_meta_resolve(expr);
 
     walk::walk_call_expression(self, expr);
   }
@@ -0,0 +1,65 @@
+use oxc::index::IndexVec;
+use rolldown_common::{ImportKind, ImportRecordIdx, ImportRecordMeta};
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashMap;
+
+use super::ScanResult;
+
+impl ScanResult {
+  /// Merge records of `import` and `export ... from` statements requesting the same module with the same import
+  /// attributes, like the two records of `'x'` in `import { a } from 'x'; export { b } from 'x'`. The first record in
+  /// source order is kept and receives the `CONTAINS_IMPORT_*` flags of the records merged into it.
+  pub fn merge_duplicate_import_records(&mut self) {
+    let mut merged_records = IndexVec::with_capacity(self.import_records.len());
+    let mut merged_idx_by_old_idx =
+      IndexVec::<ImportRecordIdx, ImportRecordIdx>::with_capacity(self.import_records.len());
+    let mut kept_records: FxHashMap<(Rstr, Vec<(Rstr, Rstr)>), ImportRecordIdx> =
+      FxHashMap::default();
+
+    for rec in std::mem::take(&mut self.import_records) {
+      // Requests ignored by comments are kept as written, so they have to stay separate.
+      let is_mergeable = matches!(rec.kind, ImportKind::Import)
+        && !rec.meta.contains(ImportRecordMeta::IS_IGNORED_BY_COMMENT);
+      if !is_mergeable {
+        merged_idx_by_old_idx.push(merged_records.push(rec));
+        continue;
+      }
+
+      let key = (rec.module_request.clone(), rec.attributes.clone());
+      if let Some(&kept_idx) = kept_records.get(&key) {
+        let kept = &mut merged_records[kept_idx];
+        // `import 'x'; import { a } from 'x'` isn't a plain import anymore after merging.
+        let is_plain_import = kept.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT)
+          && rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT);
+        kept.meta.insert(
+          rec.meta
+            & (ImportRecordMeta::CONTAINS_IMPORT_STAR | ImportRecordMeta::CONTAINS_IMPORT_DEFAULT),
+        );
+        kept.meta.set(ImportRecordMeta::IS_PLAIN_IMPORT, is_plain_import);
+        merged_idx_by_old_idx.push(kept_idx);
+      } else {
+        let kept_idx = merged_records.push(rec);
+        kept_records.insert(key, kept_idx);
+        merged_idx_by_old_idx.push(kept_idx);
+      }
+    }
+
+    self.import_records = merged_records;
+    if self.import_records.len() == merged_idx_by_old_idx.len() {
+      return;
+    }
+
+    let remap = |idx: &mut ImportRecordIdx| *idx = merged_idx_by_old_idx[*idx];
+    self.named_imports.values_mut().for_each(|named_import| remap(&mut named_import.record_id));
+    self.imports.values_mut().for_each(remap);
+    self.star_exports.iter_mut().for_each(remap);
+    self
+      .stmt_infos
+      .iter_mut()
+      .for_each(|stmt_info| stmt_info.import_records.iter_mut().for_each(remap));
+    self.dynamic_import_uses = std::mem::take(&mut self.dynamic_import_uses)
+      .into_iter()
+      .map(|(idx, usage)| (merged_idx_by_old_idx[idx], usage))
+      .collect();
+  }
+}
@@ -1,4 +1,6 @@
 pub mod impl_visit;
+mod merge_import_records;
+pub mod scan_cache;
 pub mod side_effect_detector;
 
 use arcstr::ArcStr;
@@ -6,25 +8,28 @@ use oxc::index::IndexVec;
 use oxc::{
   ast::{
     ast::{
-      ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration, IdentifierReference,
-      ImportDeclaration, ModuleDeclaration, Program,
+      Argument, BindingPatternKind, CallExpression, ExportAllDeclaration, ExportDefaultDeclaration,
+      ExportNamedDeclaration, Expression, IdentifierReference, ImportDeclaration, ImportExpression,
+      MemberExpression, MetaProperty, ModuleDeclaration, ObjectPropertyKind, Program,
+      TemplateElement, TemplateLiteral, VariableDeclarator, WithClause,
     },
-    Trivias, Visit,
+    CommentKind, Trivias, Visit,
   },
-  semantic::SymbolId,
+  semantic::{Reference, SymbolFlags, SymbolId},
   span::{CompactStr, GetSpan, Span},
+  syntax::operator::UnaryOperator,
 };
 use rolldown_common::{
-  AstScopes, ExportsKind, ImportKind, ImportRecordIdx, ImportRecordMeta, LocalExport,
-  MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport, RawImportRecord, Specifier,
-  StmtInfo, StmtInfos, SymbolRef,
+  AstScopes, DynamicImportUse, ExportsKind, ImportKind, ImportPattern, ImportRecordIdx,
+  ImportRecordMeta, LocalExport, MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport,
+  RawImportRecord, Specifier, StmtInfo, StmtInfos, SymbolRef,
 };
 use rolldown_ecmascript::{BindingIdentifierExt, BindingPatternExt};
 use rolldown_error::{BuildDiagnostic, CjsExportSpan, UnhandleableResult};
 use rolldown_rstr::{Rstr, ToRstr};
 use rolldown_utils::ecma_script::legitimize_identifier_name;
 use rolldown_utils::path_ext::PathExt;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
 use super::types::ast_symbols::AstSymbols;
@@ -36,12 +41,46 @@ pub struct ScanResult {
   pub named_exports: FxHashMap<Rstr, LocalExport>,
   pub stmt_infos: StmtInfos,
   pub import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
+  pub import_patterns: Vec<ImportPattern>,
   pub star_exports: Vec<ImportRecordIdx>,
   pub default_export_ref: SymbolRef,
+  /// Whether the module has a `default` export. `default_export_ref` is created for every module, but it's only
+  /// meaningful when this is `true`.
+  pub has_default_export: bool,
   pub imports: FxHashMap<Span, ImportRecordIdx>,
   pub exports_kind: ExportsKind,
   pub warnings: Vec<BuildDiagnostic>,
   pub has_eval: bool,
+  /// Whether the module uses `await` or `for await` outside of any function.
+  pub has_top_level_await: bool,
+  /// Span of the first top-level `await` expression or `for await` loop.
+  pub top_level_await_span: Option<Span>,
+  /// Comments like `/*! ... */`, `//! ...` or comments containing `@license` or `@preserve`. The span covers the
+  /// whole comment including its delimiters and the string is the original text of that span.
+  pub legal_comments: Vec<(Span, String)>,
+  /// Span of `exports.default = ...` in a CommonJS module that also marks itself with `__esModule`, which is how
+  /// Babel-style transpiled ESM exposes its default export.
+  pub es_module_interop_default: Option<Span>,
+  /// Export names of a CommonJS module found in `exports.foo = ...`, `module.exports = { ... }`,
+  /// `Object.assign(exports, { ... })` and `module.exports = Object.assign({}, ...)`. `None` if the module isn't
+  /// CommonJS or its exports can't be enumerated statically, e.g. an assigned object contains a spread.
+  pub cjs_named_exports: Option<Vec<Rstr>>,
+  /// See [DynamicImportUse]. Only `import('...')` records with a known usage are present.
+  pub dynamic_import_uses: FxHashMap<ImportRecordIdx, DynamicImportUse>,
+  /// Symbols of local exports that are written after being declared, like `a` in `export let a = 1; a++`.
+  pub mutated_exports: FxHashSet<SymbolRef>,
+  /// Span of the expression of `export default undefined` or `export default void 0`.
+  pub undefined_default_export: Option<Span>,
+  /// Spans of `import.meta` accesses keyed by the longest static property chain, like `import.meta.url` or
+  /// `import.meta.hot.accept`. Uses of `import.meta` itself, including computed accesses like `import.meta[key]`, are
+  /// keyed by `import.meta`.
+  pub import_meta_usage: FxHashMap<CompactStr, Vec<Span>>,
+  /// Writes to imported bindings, like `a = 1` or `a++` in `import { a } from './foo'`. Whether they are errors or
+  /// warnings is decided by `fail_on_import_reassignment`, so they are kept apart from `warnings`.
+  pub import_reassignments: Vec<BuildDiagnostic>,
+  /// Spans of the local bindings of imports that are never referenced, like `a` in `import { a } from './foo'`.
+  /// Imports without bindings, like `import './foo'`, and re-exported imports are never reported.
+  pub unused_imports: Vec<Span>,
 }
 
 pub struct AstScanner<'me> {
@@ -60,6 +99,12 @@ pub struct AstScanner<'me> {
   pub namespace_object_ref: SymbolRef,
   cjs_exports_ident: Option<Span>,
   cjs_module_ident: Option<Span>,
+  /// How many functions the visitor is currently inside of.
+  function_depth: usize,
+  cjs_default_export: Option<Span>,
+  cjs_es_module_flag: bool,
+  /// Becomes `None` once the scanner meets an export it can't enumerate.
+  cjs_named_exports: Option<Vec<Rstr>>,
 }
 
 impl<'me> AstScanner<'me> {
@@ -93,12 +138,25 @@ impl<'me> AstScanner<'me> {
         stmt_infos
       },
       import_records: IndexVec::new(),
+      import_patterns: Vec::new(),
       star_exports: Vec::new(),
       default_export_ref: (idx, symbol_id_for_default_export_ref).into(),
+      has_default_export: false,
       imports: FxHashMap::default(),
       exports_kind: ExportsKind::None,
       warnings: Vec::new(),
       has_eval: false,
+      has_top_level_await: false,
+      top_level_await_span: None,
+      legal_comments: Vec::new(),
+      es_module_interop_default: None,
+      cjs_named_exports: None,
+      dynamic_import_uses: FxHashMap::default(),
+      mutated_exports: FxHashSet::default(),
+      undefined_default_export: None,
+      import_meta_usage: FxHashMap::default(),
+      import_reassignments: Vec::new(),
+      unused_imports: Vec::new(),
     };
 
     Self {
@@ -113,6 +171,10 @@ impl<'me> AstScanner<'me> {
       namespace_object_ref,
       cjs_module_ident: None,
       cjs_exports_ident: None,
+      function_depth: 0,
+      cjs_default_export: None,
+      cjs_es_module_flag: false,
+      cjs_named_exports: Some(Vec::new()),
       source,
       file_path,
       trivias,
@@ -121,6 +183,9 @@ impl<'me> AstScanner<'me> {
 
   pub fn scan(mut self, program: &Program<'_>) -> UnhandleableResult<ScanResult> {
     self.visit_program(program);
+    self.collect_legal_comments();
+    self.collect_mutated_exports();
+    self.collect_unused_imports();
     let mut exports_kind = ExportsKind::None;
 
     if self.esm_export_keyword.is_some() {
@@ -151,8 +216,26 @@ impl<'me> AstScanner<'me> {
       }
     } else if self.cjs_exports_ident.is_some() || self.cjs_module_ident.is_some() {
       exports_kind = ExportsKind::CommonJs;
+      if let Some(esm_import_span) = self.esm_import_keyword {
+        // Point at whichever CommonJS variable comes first, since either one makes the module CommonJS.
+        let cjs_export_ident_span = match (self.cjs_module_ident, self.cjs_exports_ident) {
+          (Some(module), Some(exports)) if exports.start < module.start => {
+            CjsExportSpan::Exports(exports)
+          }
+          (Some(module), _) => CjsExportSpan::Module(module),
+          (None, exports) => CjsExportSpan::Exports(exports.expect("should have start offset")),
+        };
+        self.result.warnings.push(
+          BuildDiagnostic::esm_import_in_commonjs(
+            self.file_path.to_string(),
+            self.source.clone(),
+            esm_import_span,
+            cjs_export_ident_span,
+          )
+          .with_severity_warning(),
+        );
+      }
     } else {
-      // TODO(hyf0): Should add warnings if the module type doesn't satisfy the exports kind.
       match self.module_type {
         ModuleDefFormat::CJS | ModuleDefFormat::CjsPackageJson => {
           exports_kind = ExportsKind::CommonJs;
@@ -169,6 +252,13 @@ impl<'me> AstScanner<'me> {
     }
 
     self.result.exports_kind = exports_kind;
+    self.result.has_default_export = self.result.named_exports.contains_key(&Rstr::new("default"));
+    if matches!(exports_kind, ExportsKind::CommonJs) && self.cjs_es_module_flag {
+      self.result.es_module_interop_default = self.cjs_default_export;
+    }
+    if matches!(exports_kind, ExportsKind::CommonJs) {
+      self.result.cjs_named_exports = self.cjs_named_exports.take();
+    }
 
     if cfg!(debug_assertions) {
       use rustc_hash::FxHashSet;
@@ -197,6 +287,62 @@ impl<'me> AstScanner<'me> {
     Ok(self.result)
   }
 
+  fn collect_legal_comments(&mut self) {
+    for comment in self.trivias.comments() {
+      let content = comment.span.source_text(self.source);
+      let is_legal =
+        content.starts_with('!') || content.contains("@license") || content.contains("@preserve");
+      if !is_legal {
+        continue;
+      }
+      // `Comment#span` doesn't include the delimiters, which are `/*`, `*/` and `//`.
+      let span = match comment.kind {
+        CommentKind::MultiLine => Span::new(comment.span.start - 2, comment.span.end + 2),
+        CommentKind::SingleLine => Span::new(comment.span.start - 2, comment.span.end),
+      };
+      self.result.legal_comments.push((span, span.source_text(self.source).to_string()));
+    }
+  }
+
+  fn collect_mutated_exports(&mut self) {
+    let mutated_exports = self
+      .result
+      .named_exports
+      .values()
+      .map(|local_export| local_export.referenced)
+      // `default_export_ref` is created by the bundler, so it's unknown to the scope tree and never written
+      .filter(|symbol_ref| *symbol_ref != self.result.default_export_ref)
+      .filter(|symbol_ref| {
+        self.scopes.get_resolved_references(symbol_ref.symbol).any(Reference::is_write)
+      })
+      .collect();
+    self.result.mutated_exports = mutated_exports;
+  }
+
+  fn collect_unused_imports(&mut self) {
+    let referenced = self
+      .result
+      .stmt_infos
+      .iter()
+      .flat_map(|stmt_info| &stmt_info.referenced_symbols)
+      .map(|reference| *reference.symbol_ref())
+      .chain(self.result.named_exports.values().map(|local_export| local_export.referenced))
+      .collect::<FxHashSet<_>>();
+    let mut unused_imports = self
+      .result
+      .named_imports
+      .keys()
+      .filter(|symbol_ref| !referenced.contains(symbol_ref))
+      // `import type { A } from './foo'` is erased when TypeScript is transformed, so it costs nothing.
+      .filter(|symbol_ref| {
+        !self.symbols.get_flag(symbol_ref.symbol).contains(SymbolFlags::TypeImport)
+      })
+      .map(|symbol_ref| self.symbols.get_span(symbol_ref.symbol))
+      .collect::<Vec<_>>();
+    unused_imports.sort_unstable_by_key(|span| span.start);
+    self.result.unused_imports = unused_imports;
+  }
+
   fn set_esm_export_keyword(&mut self, span: Span) {
     self.esm_export_keyword.get_or_insert(span);
   }
@@ -209,6 +355,160 @@ impl<'me> AstScanner<'me> {
     self.scopes.get_root_binding(name).expect("must have")
   }
 
+  fn set_top_level_await(&mut self, span: Span) {
+    self.result.has_top_level_await = true;
+    self.result.top_level_await_span.get_or_insert(span);
+  }
+
+  fn set_import_attributes(&mut self, rec_id: ImportRecordIdx, with_clause: Option<&WithClause>) {
+    if let Some(with_clause) = with_clause {
+      self.result.import_records[rec_id].attributes = with_clause
+        .with_entries
+        .iter()
+        .map(|attr| (attr.key.as_atom().as_str().into(), attr.value.value.as_str().into()))
+        .collect();
+    }
+  }
+
+  /// Collects `{ with: { type: 'json' } }` from the second argument of `import(...)`. Entries whose key or value
+  /// can't be determined statically are ignored.
+  fn dynamic_import_attributes(options: &Expression) -> Vec<(Rstr, Rstr)> {
+    let Expression::ObjectExpression(options) = options else { return vec![] };
+    options
+      .properties
+      .iter()
+      .filter_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop) => Some(prop),
+        ObjectPropertyKind::SpreadProperty(_) => None,
+      })
+      .find_map(|prop| match (&prop.key.static_name(), &prop.value) {
+        (Some(name), Expression::ObjectExpression(with)) if name == "with" => Some(with),
+        _ => None,
+      })
+      .map(|with| {
+        with
+          .properties
+          .iter()
+          .filter_map(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(prop) => match (prop.key.static_name(), &prop.value)
+            {
+              (Some(key), Expression::StringLiteral(value)) => {
+                Some((key.as_ref().into(), value.value.as_str().into()))
+              }
+              _ => None,
+            },
+            ObjectPropertyKind::SpreadProperty(_) => None,
+          })
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Record requests like `` `./cmds/${name}.js` ``, whose static prefix or suffix could tell which modules might
+  /// be requested.
+  fn add_import_pattern(&mut self, kind: ImportKind, request: &TemplateLiteral) {
+    if request.expressions.is_empty() {
+      return;
+    }
+    let cooked = |quasi: Option<&TemplateElement>| {
+      quasi.and_then(|quasi| quasi.value.cooked.as_ref()).map(|cooked| Rstr::new(cooked.as_str()))
+    };
+    let (Some(prefix), Some(suffix)) =
+      (cooked(request.quasis.first()), cooked(request.quasis.last()))
+    else {
+      return;
+    };
+    if prefix.is_empty() && suffix.is_empty() {
+      return;
+    }
+    self.result.import_patterns.push(ImportPattern { kind, prefix, suffix, span: request.span });
+  }
+
+  /// Record `import.meta.resolve('./x.js')`. The module is resolved like any other import but isn't executed by the
+  /// importer, so it's added as a [ImportKind::ResolveOnly] record.
+  fn scan_import_meta_resolve(&mut self, expr: &CallExpression) {
+    let Expression::StaticMemberExpression(callee) = &expr.callee else {
+      return;
+    };
+    let Expression::MetaProperty(meta) = &callee.object else {
+      return;
+    };
+    if callee.property.name != "resolve" || !is_import_meta(meta) {
+      return;
+    }
+    let Some(Argument::StringLiteral(request)) = expr.arguments.first() else {
+      return;
+    };
+    self.add_import_record(request.value.as_str(), ImportKind::ResolveOnly, request.span.start);
+  }
+
+  /// `(await import('./foo')).a` only uses the export `a`. It's called after walking the member expression, so the
+  /// record of the `import('./foo')` already exists.
+  fn scan_dynamic_import_member_access(&mut self, member_expr: &MemberExpression) {
+    let MemberExpression::StaticMemberExpression(member_expr) = member_expr else {
+      return;
+    };
+    if let Some(import_expr) = awaited_import_expression(&member_expr.object) {
+      let names = FxHashSet::from_iter([member_expr.property.name.to_rstr()]);
+      self.set_dynamic_import_use(import_expr, DynamicImportUse::Partial(names));
+    }
+  }
+
+  /// `const { a, b } = await import('./foo')` only uses the exports `a` and `b`. A rest element or a key that isn't
+  /// static could read any export.
+  fn scan_dynamic_import_destructuring(&mut self, decl: &VariableDeclarator) {
+    let Some(import_expr) = decl.init.as_ref().and_then(awaited_import_expression) else {
+      return;
+    };
+    let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind else {
+      return;
+    };
+    if pattern.rest.is_some() {
+      return;
+    }
+    let names = pattern
+      .properties
+      .iter()
+      .map(|prop| prop.key.static_name().map(|name| Rstr::from(name.as_ref())))
+      .collect::<Option<FxHashSet<_>>>();
+    if let Some(names) = names {
+      self.set_dynamic_import_use(import_expr, DynamicImportUse::Partial(names));
+    }
+  }
+
+  fn set_dynamic_import_use(&mut self, import_expr: &ImportExpression, usage: DynamicImportUse) {
+    // `import(foo)` with a non-literal request doesn't have a record
+    if let Some(rec_id) = self.result.imports.get(&import_expr.span) {
+      self.result.dynamic_import_uses.insert(*rec_id, usage);
+    }
+  }
+
+  /// Record `import(expr)` whose request isn't a string literal, like `import(dynamicVar)`. The source text of `expr`
+  /// is used as the request, so a `resolve_dynamic_import` hook can tell what's imported.
+  fn add_non_literal_dynamic_import(&mut self, expr: &ImportExpression) {
+    let source_span = expr.source.span();
+    let request = source_span.source_text(self.source);
+    let id = self.add_import_record(request, ImportKind::DynamicImport, source_span.start);
+    self.result.imports.insert(expr.span, id);
+    self.result.import_records[id].meta.insert(ImportRecordMeta::IS_NON_LITERAL_REQUEST);
+  }
+
+  /// Mark the record if there's a `/* webpackIgnore: true */` comment between `start` and the request, like
+  /// `import(/* webpackIgnore: true */ './foo.js')`.
+  fn mark_ignored_by_comment(&mut self, rec_id: ImportRecordIdx, start: u32, request_start: u32) {
+    let is_ignored = self.trivias.comments_range(start..request_start).any(|comment| {
+      // Magic comments could contain multiple options, such as `webpackIgnore: true, webpackChunkName: "foo"`.
+      comment.span.source_text(self.source).split(',').any(|option| {
+        option
+          .split_once(':')
+          .is_some_and(|(key, value)| key.trim() == "webpackIgnore" && value.trim() == "true")
+      })
+    });
+    if is_ignored {
+      self.result.import_records[rec_id].meta.insert(ImportRecordMeta::IS_IGNORED_BY_COMMENT);
+    }
+  }
+
   fn add_import_record(
     &mut self,
     module_request: &str,
@@ -377,8 +677,11 @@ impl<'me> AstScanner<'me> {
       ImportKind::Import,
       decl.source.span().start,
     );
+    self.set_import_attributes(id, decl.with_clause.as_ref());
+    self.mark_ignored_by_comment(id, decl.span.start, decl.source.span.start);
     if let Some(exported) = &decl.exported {
       // export * as ns from '...'
+      self.try_diagnostic_dangerous_export_name(exported.name().as_str(), exported.span());
       self.add_star_re_export(exported.name().as_str(), id, decl.span);
     } else {
       // export * from '...'
@@ -391,7 +694,13 @@ impl<'me> AstScanner<'me> {
     if let Some(source) = &decl.source {
       let record_id =
         self.add_import_record(source.value.as_str(), ImportKind::Import, source.span().start);
+      self.set_import_attributes(record_id, decl.with_clause.as_ref());
+      self.mark_ignored_by_comment(record_id, decl.span.start, source.span.start);
       decl.specifiers.iter().for_each(|spec| {
+        self.try_diagnostic_dangerous_export_name(
+          spec.exported.name().as_str(),
+          spec.exported.span(),
+        );
         self.add_re_export(
           spec.exported.name().as_str(),
           spec.local.name().as_str(),
@@ -406,6 +715,10 @@ impl<'me> AstScanner<'me> {
       }
     } else {
       decl.specifiers.iter().for_each(|spec| {
+        self.try_diagnostic_dangerous_export_name(
+          spec.exported.name().as_str(),
+          spec.exported.span(),
+        );
         self.add_local_export(
           spec.exported.name().as_str(),
           self.get_root_binding(spec.local.name().as_str()),
@@ -435,7 +748,30 @@ impl<'me> AstScanner<'me> {
             let id = cls_decl.id.as_ref().unwrap();
             self.add_local_export(id.name.as_str(), id.expect_symbol_id(), id.span);
           }
-          _ => unreachable!("doesn't support ts now"),
+          oxc::ast::ast::Declaration::TSEnumDeclaration(enum_decl) if !enum_decl.declare => {
+            let id = &enum_decl.id;
+            self.add_local_export(id.name.as_str(), id.expect_symbol_id(), id.span);
+          }
+          oxc::ast::ast::Declaration::TSModuleDeclaration(module_decl) if !module_decl.declare => {
+            if let oxc::ast::ast::TSModuleDeclarationName::Identifier(id) = &module_decl.id {
+              self.add_local_export(id.name.as_str(), id.expect_symbol_id(), id.span);
+            }
+          }
+          oxc::ast::ast::Declaration::TSImportEqualsDeclaration(import_equals)
+            if import_equals.import_kind.is_value() =>
+          {
+            let id = &import_equals.id;
+            self.add_local_export(id.name.as_str(), id.expect_symbol_id(), id.span);
+          }
+          // Types and ambient declarations don't exist at runtime, so there is nothing to export.
+          oxc::ast::ast::Declaration::TSTypeAliasDeclaration(_)
+          | oxc::ast::ast::Declaration::TSInterfaceDeclaration(_)
+          | oxc::ast::ast::Declaration::TSEnumDeclaration(_)
+          | oxc::ast::ast::Declaration::TSModuleDeclaration(_)
+          | oxc::ast::ast::Declaration::TSImportEqualsDeclaration(_) => {}
+          oxc::ast::ast::Declaration::UsingDeclaration(_) => {
+            unreachable!("`using` declarations can't be exported")
+          }
         }
       }
     }
@@ -469,6 +805,12 @@ impl<'me> AstScanner<'me> {
     let (reference, span) = local_binding_for_default_export
       .unwrap_or((self.result.default_export_ref.symbol, Span::default()));
 
+    if let Some(expr) = decl.declaration.as_expression() {
+      if self.is_undefined_expression(expr) {
+        self.result.undefined_default_export = Some(expr.span());
+      }
+    }
+
     self.add_declared_id(reference);
     self.add_local_default_export(reference, span);
   }
@@ -480,6 +822,8 @@ impl<'me> AstScanner<'me> {
       decl.source.span().start,
     );
     self.result.imports.insert(decl.span, rec_id);
+    self.set_import_attributes(rec_id, decl.with_clause.as_ref());
+    self.mark_ignored_by_comment(rec_id, decl.span.start, decl.source.span.start);
     // // `import '...'` or `import {} from '...'`
     if decl.specifiers.as_ref().map_or(true, |s| s.is_empty()) {
       self.result.import_records[rec_id].meta.insert(ImportRecordMeta::IS_PLAIN_IMPORT);
@@ -543,6 +887,10 @@ impl<'me> AstScanner<'me> {
       .push(MemberExprRef::new(object_ref, props, span).into());
   }
 
+  pub fn add_import_meta_usage(&mut self, chain: CompactStr, span: Span) {
+    self.result.import_meta_usage.entry(chain).or_default().push(span);
+  }
+
   fn is_top_level(&self, symbol_id: SymbolId) -> bool {
     self.scopes.root_scope_id() == self.symbols.scope_id_for(symbol_id)
   }
@@ -568,6 +916,219 @@ impl<'me> AstScanner<'me> {
     }
   }
 
+  /// Imported bindings are read-only views of the exports of another module, so writing them throws at runtime.
+  fn try_diagnostic_forbid_import_assign(&mut self, id_ref: &IdentifierReference) {
+    let Some(ref_id) = id_ref.reference_id.get() else { return };
+    let Some(symbol_id) = self.resolve_symbol_from_reference(id_ref) else { return };
+    if self.symbols.get_flag(symbol_id).contains(SymbolFlags::Import)
+      && self.scopes.references[ref_id].is_write()
+    {
+      self.result.import_reassignments.push(BuildDiagnostic::forbid_import_assign(
+        self.file_path.to_string(),
+        self.source.clone(),
+        self.symbols.get_name(symbol_id).into(),
+        self.symbols.get_span(symbol_id),
+        id_ref.span(),
+      ));
+    }
+  }
+
+  /// Writing to properties of `import.meta` directly, such as `import.meta.foo = 1`, is almost always a mistake.
+  /// Deeper writes like `import.meta.hot.data.foo = 1` are legit and not reported.
+  fn try_diagnostic_assign_to_import_meta(&mut self, member_expr: &MemberExpression) {
+    if let Expression::MetaProperty(meta) = member_expr.object() {
+      if is_import_meta(meta) {
+        self.result.warnings.push(
+          BuildDiagnostic::assign_to_import_meta(
+            self.file_path.to_string(),
+            self.source.clone(),
+            member_expr.span(),
+          )
+          .with_severity_warning(),
+        );
+      }
+    }
+  }
+
+  fn is_global_identifier(&self, expr: &Expression, name: &str) -> bool {
+    let Expression::Identifier(id) = expr else { return false };
+    id.name == name && self.resolve_symbol_from_reference(id).is_none()
+  }
+
+  /// `undefined` or `void 0`, optionally wrapped in parentheses.
+  fn is_undefined_expression(&self, expr: &Expression) -> bool {
+    match expr {
+      Expression::ParenthesizedExpression(paren_expr) => {
+        self.is_undefined_expression(&paren_expr.expression)
+      }
+      Expression::UnaryExpression(unary_expr) => {
+        unary_expr.operator == UnaryOperator::Void
+          && matches!(&unary_expr.argument, Expression::NumericLiteral(lit) if lit.raw == "0")
+      }
+      _ => self.is_global_identifier(expr, "undefined"),
+    }
+  }
+
+  /// Whether `expr` is the global `exports` or `module.exports`.
+  fn is_cjs_exports_object(&self, expr: &Expression) -> bool {
+    match expr {
+      Expression::StaticMemberExpression(member_expr) => {
+        member_expr.property.name == "exports"
+          && self.is_global_identifier(&member_expr.object, "module")
+      }
+      _ => self.is_global_identifier(expr, "exports"),
+    }
+  }
+
+  /// Tracks `exports.default = ...` and `exports.__esModule = true` to detect Babel-style ESM interop, and collects
+  /// the export names assigned to `exports` or `module.exports`.
+  fn scan_cjs_exports_assignment(
+    &mut self,
+    member_expr: &MemberExpression,
+    value: &Expression,
+    span: Span,
+  ) {
+    let MemberExpression::StaticMemberExpression(member_expr) = member_expr else {
+      // `exports[key] = ...`
+      if self.is_cjs_exports_object(member_expr.object()) {
+        self.cjs_named_exports = None;
+      }
+      return;
+    };
+    if member_expr.property.name == "exports"
+      && self.is_global_identifier(&member_expr.object, "module")
+    {
+      // `module.exports = ...`
+      self.scan_cjs_module_exports_value(value);
+      return;
+    }
+    if !self.is_cjs_exports_object(&member_expr.object) {
+      return;
+    }
+    match member_expr.property.name.as_str() {
+      "default" => {
+        self.cjs_default_export.get_or_insert(span);
+        self.add_cjs_named_export("default");
+      }
+      "__esModule" => self.cjs_es_module_flag = true,
+      name => self.add_cjs_named_export(name),
+    }
+  }
+
+  /// Only object literals and `Object.assign({}, ...)` could be enumerated when assigned to `module.exports`.
+  fn scan_cjs_module_exports_value(&mut self, value: &Expression) {
+    match value {
+      Expression::ObjectExpression(_) => self.add_cjs_named_exports_from_objects([value]),
+      Expression::CallExpression(call) if self.is_object_assign_call(call) => {
+        match call.arguments.iter().map(Argument::as_expression).collect::<Option<Vec<_>>>() {
+          Some(sources) if matches!(sources.first(), Some(Expression::ObjectExpression(_))) => {
+            self.add_cjs_named_exports_from_objects(sources);
+          }
+          _ => self.cjs_named_exports = None,
+        }
+      }
+      _ => self.cjs_named_exports = None,
+    }
+  }
+
+  /// Matches `Object.assign(exports, { ... })`. The `module.exports = Object.assign(...)` form is handled by
+  /// `scan_cjs_module_exports_value`.
+  fn scan_cjs_object_assign_to_exports(&mut self, expr: &CallExpression) {
+    if !self.is_object_assign_call(expr) {
+      return;
+    }
+    let Some(target) = expr.arguments.first().and_then(Argument::as_expression) else {
+      return;
+    };
+    if !self.is_cjs_exports_object(target) {
+      return;
+    }
+    match expr.arguments.iter().skip(1).map(Argument::as_expression).collect::<Option<Vec<_>>>() {
+      Some(sources) => self.add_cjs_named_exports_from_objects(sources),
+      // `Object.assign(exports, ...sources)`
+      None => self.cjs_named_exports = None,
+    }
+  }
+
+  fn is_object_assign_call(&self, expr: &CallExpression) -> bool {
+    let Expression::StaticMemberExpression(callee) = &expr.callee else {
+      return false;
+    };
+    callee.property.name == "assign" && self.is_global_identifier(&callee.object, "Object")
+  }
+
+  /// Collects the static keys of object literals. Anything else, such as a spread, a computed key or a source that
+  /// isn't an object literal, makes the exports opaque.
+  fn add_cjs_named_exports_from_objects<'a, 'ast: 'a>(
+    &mut self,
+    sources: impl IntoIterator<Item = &'a Expression<'ast>>,
+  ) {
+    for source in sources {
+      let Expression::ObjectExpression(obj) = source else {
+        self.cjs_named_exports = None;
+        return;
+      };
+      for prop in &obj.properties {
+        match prop {
+          ObjectPropertyKind::ObjectProperty(prop) => match prop.key.static_name() {
+            Some(name) => self.add_cjs_named_export(&name),
+            // `{ [key]: value }`
+            None => {
+              self.cjs_named_exports = None;
+              return;
+            }
+          },
+          ObjectPropertyKind::SpreadProperty(_) => {
+            self.cjs_named_exports = None;
+            return;
+          }
+        }
+      }
+    }
+  }
+
+  fn add_cjs_named_export(&mut self, name: &str) {
+    if let Some(names) = &mut self.cjs_named_exports {
+      if !names.iter().any(|exported| exported.as_str() == name) {
+        names.push(name.into());
+      }
+    }
+  }
+
+  /// Matches `Object.defineProperty(exports, '__esModule', { value: true })`.
+  fn scan_cjs_es_module_define_property(&mut self, expr: &CallExpression) {
+    let Expression::StaticMemberExpression(callee) = &expr.callee else {
+      return;
+    };
+    let is_define_property = callee.property.name == "defineProperty"
+      && self.is_global_identifier(&callee.object, "Object");
+    let defines_es_module_on_exports = expr
+      .arguments
+      .first()
+      .and_then(Argument::as_expression)
+      .is_some_and(|target| self.is_cjs_exports_object(target))
+      && matches!(expr.arguments.get(1), Some(Argument::StringLiteral(name)) if name.value == "__esModule");
+    if is_define_property && defines_es_module_on_exports {
+      self.cjs_es_module_flag = true;
+    }
+  }
+
+  /// `__proto__` as a property key in an object literal sets the prototype instead of defining a property, so
+  /// exporting it would silently go missing from the namespace object.
+  fn try_diagnostic_dangerous_export_name(&mut self, export_name: &str, span: Span) {
+    if export_name == "__proto__" {
+      self.result.warnings.push(
+        BuildDiagnostic::dangerous_export_name(
+          self.file_path.to_string(),
+          self.source.clone(),
+          span,
+          export_name.to_string(),
+        )
+        .with_severity_warning(),
+      );
+    }
+  }
+
   /// resolve the symbol from the identifier reference, and return if it is a top level symbol
   fn resolve_identifier_to_top_level_symbol(
     &mut self,
@@ -603,3 +1164,529 @@ impl<'me> AstScanner<'me> {
     }
   }
 }
+
+fn is_import_meta(meta: &MetaProperty) -> bool {
+  meta.meta.name == "import" && meta.property.name == "meta"
+}
+
+/// Returns `import('./foo')` of `await import('./foo')`, allowing parentheses around either.
+fn awaited_import_expression<'a, 'ast>(
+  expr: &'a Expression<'ast>,
+) -> Option<&'a ImportExpression<'ast>> {
+  match expr {
+    Expression::ParenthesizedExpression(expr) => awaited_import_expression(&expr.expression),
+    Expression::AwaitExpression(expr) => match &expr.argument {
+      Expression::ImportExpression(import_expr) => Some(import_expr),
+      Expression::ParenthesizedExpression(paren) => match &paren.expression {
+        Expression::ImportExpression(import_expr) => Some(import_expr),
+        _ => None,
+      },
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use arcstr::ArcStr;
+  use oxc::{index::Idx, semantic::SemanticBuilder, span::SourceType};
+  use rolldown_common::{
+    AstScopes, DynamicImportUse, ExportsKind, ImportKind, ImportRecordIdx, ImportRecordMeta,
+    ModuleDefFormat, ModuleId, ModuleIdx,
+  };
+  use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+  use rolldown_rstr::Rstr;
+
+  use super::{AstScanner, ScanResult};
+  use crate::{
+    types::ast_symbols::AstSymbols, utils::make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
+  };
+
+  fn parse(code: &str) -> EcmaAst {
+    parse_with_source_type(code, SourceType::default().with_module(true))
+  }
+
+  fn parse_with_source_type(code: &str, source_type: SourceType) -> EcmaAst {
+    EcmaCompiler::parse("<Noop>", ArcStr::from(code), source_type).unwrap()
+  }
+
+  fn scan_with_scopes(
+    ast: &EcmaAst,
+    mut ast_symbols: AstSymbols,
+    ast_scopes: &AstScopes,
+  ) -> ScanResult {
+    let module_id = ModuleId::new("main.js");
+    AstScanner::new(
+      ModuleIdx::from_raw(0),
+      ast_scopes,
+      &mut ast_symbols,
+      "main".to_string(),
+      ModuleDefFormat::Unknown,
+      ast.source(),
+      &module_id,
+      &ast.trivias,
+    )
+    .scan(ast.program())
+    .unwrap()
+  }
+
+  fn scan(code: &str) -> ScanResult {
+    scan_ast(&parse(code))
+  }
+
+  fn scan_typescript(code: &str) -> ScanResult {
+    let source_type = SourceType::default().with_module(true).with_typescript(true);
+    scan_ast(&parse_with_source_type(code, source_type))
+  }
+
+  fn scan_ast(ast: &EcmaAst) -> ScanResult {
+    let (symbols, scopes) = ast.make_symbol_table_and_scope_tree();
+    let (ast_symbols, ast_scopes) = make_ast_scopes_and_symbols(symbols, scopes);
+    scan_with_scopes(ast, ast_symbols, &ast_scopes)
+  }
+
+  #[test]
+  fn scan_with_externally_provided_scopes() {
+    let code =
+      "import { a } from './a'\nexport const b = a + 1;\nexport default function () { return b }";
+    let ast = parse(code);
+    let (mut symbols, scopes) = SemanticBuilder::new(ast.source(), ast.source_type)
+      .build(ast.program())
+      .semantic
+      .into_symbol_table_and_scope_tree();
+    let ast_scopes = AstScopes::new(
+      scopes,
+      std::mem::take(&mut symbols.references),
+      std::mem::take(&mut symbols.resolved_references),
+    );
+    let external = scan_with_scopes(&ast, AstSymbols::from_symbol_table(symbols), &ast_scopes);
+
+    assert_eq!(format!("{external:?}"), format!("{:?}", scan(code)));
+  }
+
+  #[test]
+  fn export_default_function_of_any_kind() {
+    for code in [
+      "export default function* () {}",
+      "export default async function () {}",
+      "export default async function* () {}",
+    ] {
+      let result = scan(code);
+      assert_eq!(
+        result.named_exports[&Rstr::new("default")].referenced,
+        result.default_export_ref,
+        "{code}"
+      );
+    }
+
+    for code in [
+      "export default function* foo() {}",
+      "export default async function foo() {}",
+      "export default async function* foo() {}",
+    ] {
+      let result = scan(code);
+      let default_export = &result.named_exports[&Rstr::new("default")];
+      assert_ne!(default_export.referenced, result.default_export_ref, "{code}");
+      assert_eq!(default_export.span.source_text(code), "foo", "{code}");
+    }
+  }
+
+  #[test]
+  fn export_default_expression_references_imports() {
+    for code in [
+      "import { importedFn } from './foo'\nexport default (0, importedFn)",
+      "import { importedFn } from './foo'\nexport default ((importedFn))",
+      "import { importedFn } from './foo'\nexport default (0, (1, importedFn.bar))",
+    ] {
+      let result = scan(code);
+      let imported = *result.named_imports.keys().next().unwrap();
+      let default_stmt =
+        result.stmt_infos.iter().find(|stmt_info| stmt_info.stmt_idx == Some(1)).unwrap();
+      assert!(default_stmt.declared_symbols.contains(&result.default_export_ref), "{code}");
+      assert!(
+        default_stmt.referenced_symbols.iter().any(|reference| *reference.symbol_ref() == imported),
+        "{code}"
+      );
+    }
+  }
+
+  #[test]
+  fn collect_mutated_exports() {
+    let code = "export let a = 1;\nexport let b = 2;\nexport function c() { a++ }\nlet d;\n[d] = [3];\nexport { d, b as e };\nexport default a;";
+    let result = scan(code);
+    let mut mutated = result
+      .named_exports
+      .iter()
+      .filter(|(_, local_export)| result.mutated_exports.contains(&local_export.referenced))
+      .map(|(name, _)| name.as_str())
+      .collect::<Vec<_>>();
+    mutated.sort_unstable();
+    assert_eq!(mutated, ["a", "d"]);
+  }
+
+  #[test]
+  fn collect_unused_imports() {
+    let code = "import './side-effect';\nimport a, { b, c as d } from './a';\nimport * as ns from './ns';\nimport { e } from './e';\nexport { e };\nexport { f } from './f';\nconsole.log(b, ns.foo);";
+    let unused =
+      scan(code).unused_imports.iter().map(|span| span.source_text(code)).collect::<Vec<_>>();
+    assert_eq!(unused, ["a", "d"]);
+  }
+
+  #[test]
+  fn collect_unused_imports_skips_type_only_imports() {
+    let code = "import type { A } from './a';\nimport { type B, C } from './b';\nimport { D } from './d';\nlet x: D;";
+    let unused = scan_typescript(code)
+      .unused_imports
+      .iter()
+      .map(|span| span.source_text(code))
+      .collect::<Vec<_>>();
+    assert_eq!(unused, ["C"]);
+  }
+
+  #[test]
+  fn detect_undefined_default_export() {
+    for (code, expected) in [
+      ("export default undefined", "undefined"),
+      ("export default void 0", "void 0"),
+      ("export default (undefined)", "(undefined)"),
+    ] {
+      let span = scan(code).undefined_default_export.expect(code);
+      assert_eq!(span.source_text(code), expected);
+    }
+    for code in [
+      "const undefined = 1; export default undefined",
+      "export default void foo()",
+      "export default null",
+      "export default function () {}",
+    ] {
+      assert_eq!(scan(code).undefined_default_export, None, "{code}");
+    }
+  }
+
+  #[test]
+  fn flag_statements_after_top_level_throw_as_unreachable() {
+    let code = "console.log('before')\nif (a) { throw 1 }\nthrow new Error()\nconsole.log('after')\nexport * from './foo'\nfunction foo() {}";
+    let unreachable = scan(code)
+      .stmt_infos
+      .iter()
+      .filter_map(|stmt_info| stmt_info.stmt_idx.map(|idx| (idx, stmt_info)))
+      .map(|(idx, stmt_info)| {
+        if stmt_info.is_unreachable {
+          assert!(!stmt_info.side_effect);
+        }
+        (idx, stmt_info.is_unreachable)
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(unreachable, [(0, false), (1, false), (2, false), (3, true), (4, false), (5, true)]);
+  }
+
+  #[test]
+  fn export_typescript_declarations() {
+    let exports = |code: &str| {
+      let mut names =
+        scan_typescript(code).named_exports.keys().map(ToString::to_string).collect::<Vec<_>>();
+      names.sort_unstable();
+      names
+    };
+
+    assert_eq!(exports("export enum E {}"), ["E"]);
+    assert_eq!(exports("export const enum E { A = 1 }"), ["E"]);
+    assert_eq!(exports("export namespace N { const a = 1 }"), ["N"]);
+    assert_eq!(exports("import * as foo from './foo'\nexport import Bar = foo.Bar"), ["Bar"]);
+    for code in [
+      "export type T = number",
+      "export interface I { a: string }",
+      "export declare enum E {}",
+      "export declare namespace N {}",
+    ] {
+      assert!(exports(code).is_empty(), "{code}");
+    }
+  }
+
+  #[test]
+  fn detect_top_level_await() {
+    for code in [
+      "await foo",
+      "for await (const x of foo) {}",
+      "if (foo) { const bar = await foo }",
+      "export default await foo",
+    ] {
+      assert!(scan(code).has_top_level_await, "{code}");
+    }
+
+    for code in [
+      "async function foo() { await bar }",
+      "const foo = async () => { for await (const x of bar) {} }",
+      "class Foo { async bar() { await baz } }",
+    ] {
+      assert!(!scan(code).has_top_level_await, "{code}");
+      assert!(scan(code).top_level_await_span.is_none(), "{code}");
+    }
+
+    let code = "async function foo() { await bar }\nawait baz; await qux";
+    let span = scan(code).top_level_await_span.unwrap();
+    assert_eq!(&code[span.start as usize..span.end as usize], "await baz");
+  }
+
+  #[test]
+  fn collect_legal_comments() {
+    let code = "/*! banner v1.0.0 | MIT */\n// normal comment\n/** @license MIT */\n//! line\nexport const a = 1;\n/* not legal */";
+    let legal_comments = scan(code)
+      .legal_comments
+      .into_iter()
+      .map(|(span, text)| {
+        assert_eq!(span.source_text(code), text);
+        text
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(legal_comments, ["/*! banner v1.0.0 | MIT */", "/** @license MIT */", "//! line"]);
+  }
+
+  #[test]
+  fn collect_import_attributes() {
+    let attributes = |code: &str| {
+      scan(code)
+        .import_records
+        .into_iter()
+        .map(|rec| {
+          (
+            rec.kind.is_static(),
+            rec
+              .attributes
+              .iter()
+              .map(|(key, value)| (key.to_string(), value.to_string()))
+              .collect::<Vec<_>>(),
+          )
+        })
+        .collect::<Vec<_>>()
+    };
+    let json = vec![("type".to_string(), "json".to_string())];
+
+    assert_eq!(
+      attributes("import('./data.json', { with: { type: 'json' } })"),
+      [(false, json.clone())]
+    );
+    assert_eq!(
+      attributes("import('./data.json', { 'with': { 'type': 'json', [foo]: 'bar', baz } })"),
+      [(false, json.clone())]
+    );
+    assert_eq!(attributes("import('./data.json', options)"), [(false, vec![])]);
+    assert_eq!(
+      attributes("import data from './data.json' with { type: 'json' }"),
+      [(true, json.clone())]
+    );
+    assert_eq!(attributes("export * from './data.json' with { type: 'json' }"), [(true, json)]);
+  }
+
+  #[test]
+  fn record_import_meta_resolve_as_resolve_only() {
+    let result =
+      scan("const url = await import.meta.resolve('./x.js'); import.meta.resolve(specifier)");
+    let [rec] = result.import_records.raw.as_slice() else {
+      panic!("expected a single record, got {:?}", result.import_records);
+    };
+    assert!(matches!(rec.kind, ImportKind::ResolveOnly));
+    assert_eq!(rec.module_request.as_str(), "./x.js");
+    assert!(!rec.kind.is_static());
+  }
+
+  #[test]
+  fn record_non_literal_dynamic_import() {
+    let result = scan("import('./a.js'); import(dynamicVar); import(`./locales/${lang}.js`)");
+    let requests = result
+      .import_records
+      .iter()
+      .map(|rec| {
+        assert!(matches!(rec.kind, ImportKind::DynamicImport));
+        (rec.module_request.as_str(), rec.meta.contains(ImportRecordMeta::IS_NON_LITERAL_REQUEST))
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      requests,
+      [("./a.js", false), ("dynamicVar", true), ("`./locales/${lang}.js`", true)]
+    );
+    assert_eq!(result.imports.len(), 3);
+  }
+
+  #[test]
+  fn collect_import_meta_usage() {
+    let code = "const url = new URL('./a.png', import.meta.url);\nif (import.meta.hot) import.meta.hot.accept();\nconsole.log(import.meta, import.meta[key], import.meta.url)";
+    let result = scan(code);
+    let mut usage = result
+      .import_meta_usage
+      .iter()
+      .map(|(chain, spans)| {
+        (chain.as_str(), spans.iter().map(|span| span.source_text(code)).collect::<Vec<_>>())
+      })
+      .collect::<Vec<_>>();
+    usage.sort_unstable();
+    assert_eq!(
+      usage,
+      [
+        ("import.meta", vec!["import.meta", "import.meta"]),
+        ("import.meta.hot", vec!["import.meta.hot"]),
+        ("import.meta.hot.accept", vec!["import.meta.hot.accept"]),
+        ("import.meta.url", vec!["import.meta.url", "import.meta.url"]),
+      ]
+    );
+  }
+
+  #[test]
+  fn record_exports_used_through_dynamic_imports() {
+    let result = scan(
+      "const a = (await import('./a.js')).a;\nconst { b, c: d } = await import('./b.js');\nconst ns = await import('./c.js');\nconst { ...rest } = await import('./d.js');",
+    );
+    let used = |idx: usize| match result.dynamic_import_uses.get(&ImportRecordIdx::from_usize(idx))
+    {
+      Some(DynamicImportUse::Partial(names)) => {
+        let mut names = names.iter().map(Rstr::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        Some(names)
+      }
+      Some(DynamicImportUse::All) | None => None,
+    };
+    assert_eq!(used(0), Some(vec!["a"]));
+    assert_eq!(used(1), Some(vec!["b", "c"]));
+    assert_eq!(used(2), None);
+    assert_eq!(used(3), None);
+  }
+
+  #[test]
+  fn merge_import_records_of_the_same_request() {
+    let mut result = scan(
+      "import a from './x'\nexport { b } from './x'\nimport './y'\nconst c = import('./x')\nexport * from './x'",
+    );
+    result.merge_duplicate_import_records();
+
+    let records = result
+      .import_records
+      .iter()
+      .map(|rec| (rec.module_request.as_str(), rec.kind.to_string()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      records,
+      [("./x", "import-statement"), ("./y", "import-statement"), ("./x", "dynamic-import")]
+    );
+    let merged = ImportRecordIdx::from_usize(0);
+    assert!(result.import_records[merged].meta.contains(ImportRecordMeta::CONTAINS_IMPORT_DEFAULT));
+    assert!(!result.import_records[merged].meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT));
+    assert!(result.named_imports.values().all(|named_import| named_import.record_id == merged));
+    assert_eq!(result.star_exports, [merged]);
+    let stmt_records = result
+      .stmt_infos
+      .iter()
+      .flat_map(|stmt_info| stmt_info.import_records.iter().map(|idx| idx.index()))
+      .collect::<Vec<_>>();
+    assert_eq!(stmt_records, [0, 0, 1, 2, 0]);
+  }
+
+  #[test]
+  fn record_require_calls() {
+    let result = scan(
+      "const a = require('./a');\nfunction load() { return require('./b') }\nfunction shadowed(require) { require('./c') }\n{ const require = () => {}; require('./d') }",
+    );
+    let records = result
+      .import_records
+      .iter()
+      .map(|rec| {
+        assert!(matches!(rec.kind, ImportKind::Require));
+        assert!(rec.kind.is_static());
+        rec.module_request.as_str()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(records, ["./a", "./b"]);
+  }
+
+  #[test]
+  fn record_template_literal_require_as_pattern() {
+    let result = scan("const cmd = require(`./cmds/${name}.js`)");
+    assert!(result.import_records.is_empty());
+    let [pattern] = result.import_patterns.as_slice() else {
+      panic!("expected a single pattern, got {:?}", result.import_patterns);
+    };
+    assert!(matches!(pattern.kind, ImportKind::Require));
+    assert_eq!(pattern.prefix.as_str(), "./cmds/");
+    assert_eq!(pattern.suffix.as_str(), ".js");
+
+    for code in ["require(`${name}`)", "require(`./cmds/index.js`)", "foo(`./cmds/${name}.js`)"] {
+      assert!(scan(code).import_patterns.is_empty(), "{code}");
+    }
+  }
+
+  #[test]
+  fn detect_babel_style_es_module_interop() {
+    let code =
+      "Object.defineProperty(exports, '__esModule', { value: true });\nexports.default = 'babel';";
+    let span = scan(code).es_module_interop_default.expect("should record `exports.default`");
+    assert_eq!(&code[span.start as usize..span.end as usize], "exports.default = 'babel'");
+
+    let code = "module.exports.__esModule = true;\nmodule.exports.default = 'babel';";
+    assert!(scan(code).es_module_interop_default.is_some());
+
+    for code in [
+      "exports.default = 'cjs';",
+      "exports.__esModule = true;",
+      "const exports = {}; exports.__esModule = true; exports.default = 'local';",
+      "const Object = { defineProperty() {} };\nObject.defineProperty(exports, '__esModule', { value: true });\nexports.default = 'local';",
+      "export const __esModule = true; export default 'esm';",
+    ] {
+      assert!(scan(code).es_module_interop_default.is_none(), "{code}");
+    }
+  }
+
+  #[test]
+  fn record_local_exports_alongside_star_exports() {
+    let result = scan("export * from './a';\nexport const foo = 'local';");
+    let [star_export] = result.star_exports.as_slice() else {
+      panic!("expected a single star export, got {:?}", result.star_exports);
+    };
+    assert_eq!(result.import_records[*star_export].module_request.as_str(), "./a");
+    assert!(result.named_exports.contains_key(&Rstr::new("foo")));
+  }
+
+  #[test]
+  fn computed_exports_assignment_is_commonjs() {
+    let result = scan("const key = ['dyn', 'amic'].join('');\nexports[key] = 1;");
+    assert!(matches!(result.exports_kind, ExportsKind::CommonJs));
+    assert!(result.named_exports.is_empty());
+    assert!(result.cjs_named_exports.is_none());
+  }
+
+  #[test]
+  fn enumerate_cjs_named_exports_from_object_assign() {
+    let names = |code: &str| {
+      scan(code)
+        .cjs_named_exports
+        .map(|names| names.iter().map(|name| name.as_str().to_string()).collect::<Vec<_>>())
+    };
+    assert_eq!(names("Object.assign(exports, { a })"), Some(vec!["a".to_string()]));
+    assert_eq!(
+      names("exports.a = 1;\nmodule.exports = Object.assign({}, { b }, { 'c': 1 });"),
+      Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+    );
+    // A local `Object` isn't the global one, so its `assign` could do anything
+    assert_eq!(
+      names("const Object = { assign() {} };\nexports.b = 1;\nObject.assign(exports, { a });"),
+      Some(vec!["b".to_string()])
+    );
+
+    for code in [
+      "Object.assign(exports, { ...base })",
+      "Object.assign(exports, base)",
+      "Object.assign(module.exports, { [key]: 1 })",
+      "module.exports = Object.assign({}, base, { c })",
+      "const Object = { assign() {} };\nmodule.exports = Object.assign({}, { a });",
+      "module.exports = base",
+    ] {
+      assert_eq!(names(code), None, "{code}");
+    }
+  }
+
+  #[test]
+  fn has_default_export_only_with_default_export() {
+    assert!(!scan("export const foo = 1; export { foo as bar };").has_default_export);
+    assert!(scan("export default 1;").has_default_export);
+    assert!(scan("const foo = 1; export { foo as default };").has_default_export);
+  }
+}
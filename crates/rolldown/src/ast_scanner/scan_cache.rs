@@ -0,0 +1,356 @@
+use std::sync::{Arc, Mutex};
+
+use arcstr::ArcStr;
+use rolldown_common::{
+  AstScopes, LocalExport, MemberExprRef, ModuleDefFormat, ModuleIdx, ModuleType, NamedImport,
+  RawImportRecord, StmtInfo, StmtInfos, StrOrBytes, SymbolOrMemberExprRef, SymbolRef,
+};
+use rolldown_ecmascript::EcmaAst;
+use rolldown_sourcemap::SourceMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use xxhash_rust::xxh3::xxh3_128;
+
+use super::ScanResult;
+use crate::types::ast_symbols::AstSymbols;
+
+pub type SharedScanCache = Arc<ScanCache>;
+
+/// Keeps modules as they are after parsing and scanning across builds of the same bundler, so that unchanged modules
+/// are neither parsed nor scanned again in watch mode. The `transform_ast` hook isn't called for them either.
+///
+/// Linking and generating rewrite the AST in place, so the cache keeps its own copy of the AST and hands out deep
+/// copies of it, which keep the `SymbolId`s and `ReferenceId`s the cached symbols and scopes refer to. The scopes
+/// aren't changed after scanning, so they are shared instead.
+#[derive(Debug, Default)]
+pub struct ScanCache {
+  entries: Mutex<FxHashMap<ArcStr, CachedScan>>,
+}
+
+/// What a cached module depends on besides its id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanCacheKey {
+  module_def_format: ModuleDefFormat,
+  module_type: ModuleType,
+  source_hash: u128,
+}
+
+impl ScanCacheKey {
+  /// `source` is the code that is parsed, i.e. after the `load` and `transform` hooks.
+  pub fn new(
+    module_def_format: ModuleDefFormat,
+    module_type: ModuleType,
+    source: &StrOrBytes,
+  ) -> Self {
+    Self { module_def_format, module_type, source_hash: xxh3_128(source.as_bytes()) }
+  }
+}
+
+/// A module as it is after parsing and scanning, before linking rewrites its AST.
+#[derive(Debug)]
+pub struct ScannedModule {
+  pub ast: EcmaAst,
+  pub ast_scopes: Arc<AstScopes>,
+  pub scan_result: ScanResult,
+  pub ast_symbols: AstSymbols,
+  pub namespace_object_ref: SymbolRef,
+  /// Sourcemaps of the rewrites done while parsing, e.g. by the `module_wrapper` option.
+  pub sourcemap_chain: Vec<SourceMap>,
+}
+
+#[derive(Debug)]
+struct CachedScan {
+  key: ScanCacheKey,
+  module: ScannedModule,
+}
+
+impl ScanCache {
+  /// Returns a copy of the cached module `id` if it was cached with the same `key`.
+  ///
+  /// The module may have had another index in the build it was cached in, so the returned `SymbolRef`s are changed to
+  /// belong to `module_idx`.
+  pub fn get(&self, id: &str, module_idx: ModuleIdx, key: &ScanCacheKey) -> Option<ScannedModule> {
+    let entries = self.entries.lock().expect("scan cache should not be poisoned");
+    let cached = entries.get(id).filter(|cached| cached.key == *key)?;
+    let module = &cached.module;
+    Some(ScannedModule {
+      ast: module.ast.clone_with_another_arena(),
+      ast_scopes: Arc::clone(&module.ast_scopes),
+      scan_result: clone_scan_result(&module.scan_result, module_idx)?,
+      ast_symbols: module.ast_symbols.clone(),
+      namespace_object_ref: SymbolRef { owner: module_idx, ..module.namespace_object_ref },
+      sourcemap_chain: module.sourcemap_chain.clone(),
+    })
+  }
+
+  /// Drops the modules that are no longer in the module graph.
+  pub fn retain<'a>(&self, ids: impl IntoIterator<Item = &'a str>) {
+    let ids = ids.into_iter().collect::<FxHashSet<_>>();
+    let mut entries = self.entries.lock().expect("scan cache should not be poisoned");
+    entries.retain(|id, _| ids.contains(id.as_str()));
+  }
+
+  /// Caches a copy of the module `id`, which must not be linked yet. Modules whose scan reported diagnostics are not
+  /// cached, so the diagnostics are reported again in the next build.
+  pub fn insert(&self, id: ArcStr, key: ScanCacheKey, module: &ScannedModule) {
+    let mut entries = self.entries.lock().expect("scan cache should not be poisoned");
+    let owner = module.namespace_object_ref.owner;
+    let Some(scan_result) = clone_scan_result(&module.scan_result, owner) else {
+      entries.remove(&id);
+      return;
+    };
+    let module = ScannedModule {
+      ast: module.ast.clone_with_another_arena(),
+      ast_scopes: Arc::clone(&module.ast_scopes),
+      scan_result,
+      ast_symbols: module.ast_symbols.clone(),
+      namespace_object_ref: module.namespace_object_ref,
+      sourcemap_chain: module.sourcemap_chain.clone(),
+    };
+    entries.insert(id, CachedScan { key, module });
+  }
+}
+
+/// Clones `scan_result` with all its `SymbolRef`s belonging to `owner`. All of them belong to the scanned module, since
+/// the scanner doesn't know about other modules.
+///
+/// Returns `None` if the scan reported diagnostics, which can't be cloned.
+fn clone_scan_result(scan_result: &ScanResult, owner: ModuleIdx) -> Option<ScanResult> {
+  if !scan_result.warnings.is_empty() || !scan_result.import_reassignments.is_empty() {
+    return None;
+  }
+  let remap = |symbol_ref: SymbolRef| SymbolRef { owner, ..symbol_ref };
+  let remap_referenced = |referenced: &SymbolOrMemberExprRef| match referenced {
+    SymbolOrMemberExprRef::Symbol(symbol_ref) => SymbolOrMemberExprRef::Symbol(remap(*symbol_ref)),
+    SymbolOrMemberExprRef::MemberExpr(member_expr) => {
+      SymbolOrMemberExprRef::MemberExpr(MemberExprRef {
+        object_ref: remap(member_expr.object_ref),
+        ..member_expr.clone()
+      })
+    }
+  };
+
+  let mut stmt_infos = StmtInfos::default();
+  for stmt_info in scan_result.stmt_infos.iter() {
+    stmt_infos.add_stmt_info(StmtInfo {
+      declared_symbols: stmt_info.declared_symbols.iter().copied().map(remap).collect(),
+      referenced_symbols: stmt_info.referenced_symbols.iter().map(remap_referenced).collect(),
+      ..stmt_info.clone()
+    });
+  }
+
+  Some(ScanResult {
+    repr_name: scan_result.repr_name.clone(),
+    named_imports: scan_result
+      .named_imports
+      .iter()
+      .map(|(symbol_ref, named_import)| {
+        let imported_as = remap(named_import.imported_as);
+        (remap(*symbol_ref), NamedImport { imported_as, ..named_import.clone() })
+      })
+      .collect(),
+    named_exports: scan_result
+      .named_exports
+      .iter()
+      .map(|(name, export)| {
+        (name.clone(), LocalExport { referenced: remap(export.referenced), ..export.clone() })
+      })
+      .collect(),
+    stmt_infos,
+    import_records: scan_result
+      .import_records
+      .iter()
+      .map(|record| RawImportRecord {
+        namespace_ref: remap(record.namespace_ref),
+        ..record.clone()
+      })
+      .collect(),
+    import_patterns: scan_result.import_patterns.clone(),
+    star_exports: scan_result.star_exports.clone(),
+    default_export_ref: remap(scan_result.default_export_ref),
+    has_default_export: scan_result.has_default_export,
+    imports: scan_result.imports.clone(),
+    exports_kind: scan_result.exports_kind,
+    warnings: Vec::new(),
+    has_eval: scan_result.has_eval,
+    has_top_level_await: scan_result.has_top_level_await,
+    top_level_await_span: scan_result.top_level_await_span,
+    legal_comments: scan_result.legal_comments.clone(),
+    es_module_interop_default: scan_result.es_module_interop_default,
+    cjs_named_exports: scan_result.cjs_named_exports.clone(),
+    dynamic_import_uses: scan_result.dynamic_import_uses.clone(),
+    mutated_exports: scan_result.mutated_exports.iter().copied().map(remap).collect(),
+    undefined_default_export: scan_result.undefined_default_export,
+    import_meta_usage: scan_result.import_meta_usage.clone(),
+    import_reassignments: Vec::new(),
+    unused_imports: scan_result.unused_imports.clone(),
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+
+  use arcstr::ArcStr;
+  use oxc::{index::Idx, span::SourceType};
+  use rolldown_common::{ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, StrOrBytes};
+  use rolldown_ecmascript::EcmaCompiler;
+
+  use super::{ScanCache, ScanCacheKey, ScannedModule};
+  use crate::{
+    ast_scanner::AstScanner, utils::make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
+  };
+
+  fn scan(code: &str) -> ScannedModule {
+    let ast =
+      EcmaCompiler::parse("<Noop>", ArcStr::from(code), SourceType::default().with_module(true))
+        .unwrap();
+    let (symbols, scopes) = ast.make_symbol_table_and_scope_tree();
+    let (mut ast_symbols, ast_scopes) = make_ast_scopes_and_symbols(symbols, scopes);
+    let module_id = ModuleId::new("main.js");
+    let scanner = AstScanner::new(
+      ModuleIdx::from_raw(0),
+      &ast_scopes,
+      &mut ast_symbols,
+      "main".to_string(),
+      ModuleDefFormat::Unknown,
+      ast.source(),
+      &module_id,
+      &ast.trivias,
+    );
+    let namespace_object_ref = scanner.namespace_object_ref;
+    let scan_result = scanner.scan(ast.program()).unwrap();
+    ScannedModule {
+      ast,
+      ast_scopes: Arc::new(ast_scopes),
+      scan_result,
+      ast_symbols,
+      namespace_object_ref,
+      sourcemap_chain: vec![],
+    }
+  }
+
+  fn key(code: &str) -> ScanCacheKey {
+    ScanCacheKey::new(ModuleDefFormat::Unknown, ModuleType::Js, &StrOrBytes::Str(code.to_string()))
+  }
+
+  #[test]
+  fn reuse_module_with_unchanged_source() {
+    let code = "import { a } from './a'\nexport const b = a + 1;";
+    let cache = ScanCache::default();
+    let scanned = scan(code);
+    cache.insert("main.js".into(), key(code), &scanned);
+
+    let cached = cache
+      .get("main.js", ModuleIdx::from_raw(0), &key(code))
+      .expect("the module should be reused");
+    assert_eq!(
+      cached.scan_result.named_exports.keys().map(ToString::to_string).collect::<Vec<_>>(),
+      ["b"]
+    );
+    assert_eq!(cached.scan_result.import_records.len(), scanned.scan_result.import_records.len());
+    assert_eq!(cached.ast_symbols.names, scanned.ast_symbols.names);
+    assert_eq!(cached.namespace_object_ref, scanned.namespace_object_ref);
+    assert!(Arc::ptr_eq(&cached.ast_scopes, &scanned.ast_scopes));
+  }
+
+  #[test]
+  fn hand_out_copies_of_the_ast_with_semantic_ids() {
+    let code = "import { a } from './a'\nexport const b = a + 1;";
+    let cache = ScanCache::default();
+    let mut scanned = scan(code);
+    cache.insert("main.js".into(), key(code), &scanned);
+
+    // Linking rewrites the AST of the module, which must not affect the cached one.
+    scanned.ast.program.with_mut(|fields| fields.program.body.clear());
+
+    let cached = cache
+      .get("main.js", ModuleIdx::from_raw(0), &key(code))
+      .expect("the module should be reused");
+    let fresh = scan(code);
+    assert_eq!(cached.ast.program().body.len(), fresh.ast.program().body.len());
+    assert!(!std::ptr::eq(cached.ast.allocator(), scanned.ast.allocator()));
+    // The copy can still be used with the cached scopes and symbols.
+    let Some(oxc::ast::ast::Statement::ExportNamedDeclaration(export)) =
+      cached.ast.program().body.get(1)
+    else {
+      panic!("expected an export declaration");
+    };
+    let Some(oxc::ast::ast::Declaration::VariableDeclaration(decl)) = &export.declaration else {
+      panic!("expected a variable declaration");
+    };
+    let symbol_id = decl.declarations[0].id.get_binding_identifier().unwrap().symbol_id.get();
+    assert_eq!(cached.ast_symbols.names[symbol_id.unwrap()].as_str(), "b");
+  }
+
+  #[test]
+  fn reuse_module_with_another_index() {
+    let code = "import { a } from './a'\nexport const b = a + 1;";
+    let cache = ScanCache::default();
+    let scanned = scan(code);
+    cache.insert("main.js".into(), key(code), &scanned);
+
+    let owner = ModuleIdx::from_raw(3);
+    let cached = cache.get("main.js", owner, &key(code)).expect("the module should be reused");
+    let scan_result = &cached.scan_result;
+    assert_eq!(cached.namespace_object_ref.owner, owner);
+    assert_eq!(scan_result.default_export_ref.owner, owner);
+    assert!(scan_result.named_exports.values().all(|export| export.referenced.owner == owner));
+    assert!(scan_result.named_imports.iter().all(|(symbol_ref, named_import)| {
+      symbol_ref.owner == owner && named_import.imported_as.owner == owner
+    }));
+    assert!(scan_result.import_records.iter().all(|record| record.namespace_ref.owner == owner));
+    assert!(scan_result.stmt_infos.iter().all(|stmt_info| {
+      stmt_info.declared_symbols.iter().all(|symbol_ref| symbol_ref.owner == owner)
+        && stmt_info
+          .referenced_symbols
+          .iter()
+          .all(|referenced| referenced.symbol_ref().owner == owner)
+    }));
+    let namespace_stmts =
+      scan_result.stmt_infos.declared_stmts_by_symbol(&cached.namespace_object_ref);
+    assert_eq!(
+      namespace_stmts,
+      scanned.scan_result.stmt_infos.declared_stmts_by_symbol(&scanned.namespace_object_ref)
+    );
+  }
+
+  #[test]
+  fn rescan_changed_module() {
+    let code = "export const b = 1;";
+    let cache = ScanCache::default();
+    cache.insert("main.js".into(), key(code), &scan(code));
+
+    let changed = "export const c = 1;";
+    assert!(cache.get("main.js", ModuleIdx::from_raw(0), &key(changed)).is_none());
+    let as_json = ScanCacheKey::new(
+      ModuleDefFormat::Unknown,
+      ModuleType::Json,
+      &StrOrBytes::Str(code.to_string()),
+    );
+    assert!(cache.get("main.js", ModuleIdx::from_raw(0), &as_json).is_none());
+    assert!(cache.get("other.js", ModuleIdx::from_raw(0), &key(code)).is_none());
+  }
+
+  #[test]
+  fn drop_removed_modules() {
+    let code = "export const b = 1;";
+    let cache = ScanCache::default();
+    cache.insert("main.js".into(), key(code), &scan(code));
+    cache.insert("other.js".into(), key(code), &scan(code));
+
+    cache.retain(["other.js"]);
+    assert!(cache.get("main.js", ModuleIdx::from_raw(0), &key(code)).is_none());
+    assert!(cache.get("other.js", ModuleIdx::from_raw(0), &key(code)).is_some());
+  }
+
+  #[test]
+  fn skip_scan_with_diagnostics() {
+    let code = "import { a } from './a'\na = 1;";
+    let cache = ScanCache::default();
+    let scanned = scan(code);
+    assert!(!scanned.scan_result.import_reassignments.is_empty());
+    cache.insert("main.js".into(), key(code), &scanned);
+
+    assert!(cache.get("main.js", ModuleIdx::from_raw(0), &key(code)).is_none());
+  }
+}
@@ -265,9 +265,9 @@ impl<'a> SideEffectDetector<'a> {
       | Expression::UpdateExpression(_)
       | Expression::YieldExpression(_) => true,
 
-      Expression::JSXElement(_) | Expression::JSXFragment(_) => {
-        unreachable!("jsx should be transpiled")
-      }
+      // JSX only survives to this point with `JsxRuntime::Preserve`. It will be compiled to calls of an unknown
+      // runtime later, so it's treated like any other call.
+      Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
 
       Expression::ArrayExpression(expr) => expr.elements.iter().any(|elem| match elem {
         ArrayExpressionElement::SpreadElement(_) => true,
@@ -323,11 +323,11 @@ impl<'a> SideEffectDetector<'a> {
       Declaration::UsingDeclaration(decl) => {
         decl.is_await || self.detect_side_effect_of_using_declarators(&decl.declarations)
       }
-      Declaration::TSTypeAliasDeclaration(_)
-      | Declaration::TSInterfaceDeclaration(_)
-      | Declaration::TSEnumDeclaration(_)
-      | Declaration::TSModuleDeclaration(_)
-      | Declaration::TSImportEqualsDeclaration(_) => unreachable!("ts should be transpiled"),
+      Declaration::TSTypeAliasDeclaration(_) | Declaration::TSInterfaceDeclaration(_) => false,
+      // Enums and namespaces are initialized by running code, which might have side effects.
+      Declaration::TSEnumDeclaration(decl) => !decl.declare,
+      Declaration::TSModuleDeclaration(decl) => !decl.declare,
+      Declaration::TSImportEqualsDeclaration(decl) => decl.import_kind.is_value(),
     }
   }
 
@@ -742,6 +742,21 @@ mod test {
     assert!(get_statements_side_effect("let a; a?.[a]"));
   }
 
+  #[test]
+  fn test_pure_annotation() {
+    assert!(!get_statements_side_effect("/*#__PURE__*/ foo()"));
+    assert!(!get_statements_side_effect("/*@__PURE__*/ foo()"));
+    assert!(!get_statements_side_effect("/* @__PURE__ */ new Foo()"));
+    assert!(!get_statements_side_effect("const a = /*#__PURE__*/ foo(1, 'a')"));
+    assert!(!get_statements_side_effect("/*#__PURE__*/ foo(/*#__PURE__*/ bar())"));
+    assert!(!get_statements_side_effect("/*#__PURE__*/ new Foo(/*#__PURE__*/ new Bar())"));
+    assert!(get_statements_side_effect("foo()"));
+    assert!(get_statements_side_effect("/*#__PURE__*/ foo(bar())"));
+    assert!(get_statements_side_effect("/*#__PURE__*/ foo(...args)"));
+    assert!(get_statements_side_effect("/*#__PURE__*/ foo(/*#__PURE__*/ bar(baz()))"));
+    assert!(get_statements_side_effect("/*#__PURE__*/ foo; bar()"));
+  }
+
   #[test]
   fn test_other_statements() {
     assert!(get_statements_side_effect("debugger;"));
@@ -1,21 +1,27 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use super::stages::{
   link_stage::{LinkStage, LinkStageOutput},
   scan_stage::ScanStageOutput,
 };
 use crate::{
+  ast_scanner::scan_cache::SharedScanCache,
   bundler_builder::BundlerBuilder,
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
   types::bundle_output::BundleOutput,
+  utils::{
+    normalize_options::normalize_options, render_external_specifiers::render_external_specifiers,
+  },
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
-use rolldown_common::{NormalizedBundlerOptions, SharedFileEmitter};
+use arcstr::ArcStr;
+use rolldown_common::{Module, NormalizedBundlerOptions, Output, OutputFormat, SharedFileEmitter};
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
 use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::{
-  HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver, __inner::SharedPluginable,
+  ChangeEvent, HookBuildEndArgs, HookRenderErrorArgs, HookRenderStartArgs, HookWriteBundleArgs,
+  SharedPluginDriver, __inner::SharedPluginable,
 };
 use tracing_chrome::FlushGuard;
 
@@ -25,7 +31,10 @@ pub struct Bundler {
   pub(crate) fs: OsFileSystem,
   pub(crate) resolver: SharedResolver,
   pub(crate) file_emitter: SharedFileEmitter,
+  /// Scans of modules from previous builds, reused if the modules didn't change.
+  pub(crate) scan_cache: SharedScanCache,
   pub(crate) _log_guard: Option<FlushGuard>,
+  pub(crate) closed: bool,
 }
 
 impl Bundler {
@@ -62,7 +71,11 @@ impl Bundler {
         .map_err(|err| anyhow::anyhow!("Failed to write file in {:?}", dest).context(err))?;
     }
 
-    self.plugin_driver.write_bundle(&mut output.assets).await?;
+    self
+      .plugin_driver
+      .write_bundle(&mut HookWriteBundleArgs { dir: &dir, bundle: &mut output.assets })
+      .await?;
+    output.warnings.extend(self.plugin_driver.take_warnings());
 
     Ok(output)
   }
@@ -72,7 +85,64 @@ impl Bundler {
     self.bundle_up(/* is_write */ false).await
   }
 
+  /// Generates one [BundleOutput] for each item of `outputs`, running the same plugins over the same input.
+  ///
+  /// The module graph is scanned and linked only once, so each item is expected to differ from the options of this
+  /// bundler only in output related fields, such as `format`. Diagnostics of scanning and linking are reported with
+  /// the first output. If the build fails, there is a single output carrying its errors.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub async fn generate_outputs(
+    &mut self,
+    outputs: Vec<BundlerOptions>,
+  ) -> Result<Vec<BundleOutput>> {
+    let outputs = outputs
+      .into_iter()
+      .map(|output| Arc::new(normalize_options(output).options))
+      .collect::<Vec<SharedOptions>>();
+    let output_formats = outputs.iter().map(|output| &output.format).collect();
+    let mut link_stage_output =
+      match self.try_build_for_output_formats(Some(output_formats)).await? {
+        Ok(v) => v,
+        Err(errors) => {
+          return Ok(vec![BundleOutput {
+            assets: vec![],
+            warnings: self.plugin_driver.take_warnings(),
+            errors,
+          }])
+        }
+      };
+
+    let mut bundle_outputs = Vec::with_capacity(outputs.len());
+    for (idx, options) in outputs.iter().enumerate() {
+      // The generate stage rewrites the ASTs and symbols in place, so every output but the last one is generated
+      // from copies of them.
+      let linked = (idx + 1 < outputs.len()).then(|| {
+        let ast_table = link_stage_output
+          .ast_table
+          .iter()
+          .map(|(ast, owner)| (ast.clone_with_another_arena(), *owner))
+          .collect();
+        (ast_table, link_stage_output.symbols.clone())
+      });
+      self.file_emitter.clear_added_files();
+      bundle_outputs
+        .push(self.generate_linked(&mut link_stage_output, options, /* is_write */ false).await?);
+      if let Some((ast_table, symbols)) = linked {
+        link_stage_output.ast_table = ast_table;
+        link_stage_output.symbols = symbols;
+      }
+    }
+    Ok(bundle_outputs)
+  }
+
   pub async fn scan(&mut self) -> Result<DiagnosableResult<ScanStageOutput>> {
+    if self.closed {
+      return Err(anyhow::anyhow!("Bundler is already closed"));
+    }
+
+    // Plugins could emit chunks again when the bundler is reused.
+    self.file_emitter.set_module_loading_finished(false);
+    self.plugin_driver.clear_watch_files();
     self.plugin_driver.build_start().await?;
 
     let mut error_for_build_end_hook = None;
@@ -82,6 +152,8 @@ impl Bundler {
       Arc::clone(&self.plugin_driver),
       self.fs,
       Arc::clone(&self.resolver),
+      Arc::clone(&self.file_emitter),
+      Arc::clone(&self.scan_cache),
     )
     .scan()
     .await
@@ -99,7 +171,16 @@ impl Bundler {
     };
 
     let scan_stage_output = match scan_stage_output {
-      Ok(v) => v,
+      Ok(v) => {
+        self.plugin_driver.set_module_infos(&v.module_table);
+        for module in v.module_table.modules.iter().filter_map(Module::as_ecma) {
+          // Virtual modules can't be watched
+          if Path::new(module.id.as_str()).is_absolute() {
+            self.plugin_driver.add_watch_file(module.id.as_str().into());
+          }
+        }
+        v
+      }
       Err(errs) => {
         if let Some(err_msg) = errs.first().map(ToString::to_string) {
           error_for_build_end_hook = Some(err_msg.clone());
@@ -120,21 +201,60 @@ impl Bundler {
     Ok(Ok(scan_stage_output))
   }
 
-  async fn try_build(&mut self) -> Result<DiagnosableResult<LinkStageOutput>> {
+  /// Scans and links the module graph without generating any output.
+  pub async fn try_build(&mut self) -> Result<DiagnosableResult<LinkStageOutput>> {
+    self.try_build_for_output_formats(None).await
+  }
+
+  /// Scans and links the module graph to generate outputs of `output_formats`, or of the format of this bundler if
+  /// it's `None`.
+  async fn try_build_for_output_formats(
+    &mut self,
+    output_formats: Option<Vec<&OutputFormat>>,
+  ) -> Result<DiagnosableResult<LinkStageOutput>> {
     let build_info = match self.scan().await? {
       Ok(scan_stage_output) => scan_stage_output,
       Err(errors) => return Ok(Err(errors)),
     };
-    Ok(Ok(LinkStage::new(build_info, &self.options).link()))
+    let link_stage = match output_formats {
+      Some(output_formats) => {
+        LinkStage::with_output_formats(build_info, &self.options, output_formats)
+      }
+      None => LinkStage::new(build_info, &self.options),
+    };
+    let mut link_stage_output = link_stage.link();
+    link_stage_output.warnings.extend(self.plugin_driver.take_warnings());
+    Ok(Ok(link_stage_output))
   }
 
-  #[allow(clippy::missing_transmute_annotations)]
   async fn bundle_up(&mut self, is_write: bool) -> Result<BundleOutput> {
     let mut link_stage_output = match self.try_build().await? {
       Ok(v) => v,
-      Err(errors) => return Ok(BundleOutput { assets: vec![], warnings: vec![], errors }),
+      Err(errors) => {
+        return Ok(BundleOutput {
+          assets: vec![],
+          warnings: self.plugin_driver.take_warnings(),
+          errors,
+        })
+      }
     };
 
+    let options = Arc::clone(&self.options);
+    self.generate_linked(&mut link_stage_output, &options, is_write).await
+  }
+
+  /// Generates the output for `options` from the linked module graph.
+  #[allow(clippy::missing_transmute_annotations)]
+  async fn generate_linked(
+    &mut self,
+    link_stage_output: &mut LinkStageOutput,
+    options: &SharedOptions,
+    is_write: bool,
+  ) -> Result<BundleOutput> {
+    // Must run before the `module_table` is shared with the plugin driver below.
+    render_external_specifiers(&self.plugin_driver, options, &mut link_stage_output.module_table)
+      .await?;
+
     self.plugin_driver.set_module_table(unsafe {
       // Can't ensure the safety here. It's only a temporary solution.
       // - We won't mutate the `module_table` in the generate stage.
@@ -143,11 +263,11 @@ impl Bundler {
       std::mem::transmute(&link_stage_output.module_table)
     });
 
-    self.plugin_driver.render_start().await?;
+    self.plugin_driver.render_start(&HookRenderStartArgs { options }).await?;
 
     let mut output = {
       let bundle_output =
-        GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver)
+        GenerateStage::new(link_stage_output, options, &self.plugin_driver, &self.file_emitter)
           .generate()
           .await;
 
@@ -158,10 +278,21 @@ impl Bundler {
       bundle_output?
     };
 
+    self.file_emitter.set_chunk_file_names(|module_id| {
+      output.assets.iter().find_map(|output| match output {
+        Output::Chunk(chunk)
+          if chunk.is_entry && chunk.facade_module_id.as_ref() == Some(module_id) =>
+        {
+          Some(chunk.filename.as_str().to_string())
+        }
+        _ => None,
+      })
+    });
     // Add additional files from build plugins.
     self.file_emitter.add_additional_files(&mut output.assets);
 
     self.plugin_driver.generate_bundle(&mut output.assets, is_write).await?;
+    output.warnings.extend(self.plugin_driver.take_warnings());
 
     Ok(output)
   }
@@ -176,11 +307,65 @@ impl Bundler {
     )
   }
 
+  /// Files that should trigger a rebuild when changed, collected by the last build. They are the files of loaded
+  /// modules and files added by plugins through `PluginContext::add_watch_file`.
+  pub fn watch_files(&self) -> Vec<ArcStr> {
+    self.plugin_driver.watch_files()
+  }
+
+  /// Notifies plugins through the `watch_change` hook that the file `id` has changed. Watchers should call this for
+  /// each changed file before rebuilding, so plugins can invalidate what they cached for it.
+  pub async fn watch_change(&self, id: &str, event: ChangeEvent) -> Result<()> {
+    self.plugin_driver.watch_change(id, event).await
+  }
+
+  /// Notifies plugins through the `close_watcher` hook that the watch session using this bundler has ended.
+  pub async fn close_watcher(&self) -> Result<()> {
+    self.plugin_driver.close_watcher().await
+  }
+
+  /// Lets plugins release their resources through the `close_bundle` hook. The bundler can't build anymore once it's
+  /// closed, and closing it again does nothing. This is the supported way to tear a bundler down: dropping an unclosed
+  /// bundler only spawns `close_bundle` on the current tokio runtime, or skips it if there is none.
+  pub async fn close(&mut self) -> Result<()> {
+    if self.closed {
+      return Ok(());
+    }
+    self.closed = true;
+    self.plugin_driver.close_bundle().await
+  }
+
   pub fn options(&self) -> &NormalizedBundlerOptions {
     &self.options
   }
 }
 
+impl Drop for Bundler {
+  fn drop(&mut self) {
+    if self.closed {
+      return;
+    }
+    // Blocking here until the plugins are closed could stall or deadlock the runtime the bundler is dropped on, so
+    // `close_bundle` is handed off to that runtime instead. There is no one to report errors to anymore, so they are
+    // only logged.
+    match tokio::runtime::Handle::try_current() {
+      Ok(handle) => {
+        let plugin_driver = Arc::clone(&self.plugin_driver);
+        handle.spawn(async move {
+          if let Err(err) = plugin_driver.close_bundle().await {
+            tracing::error!("Failed to close the bundler: {err:?}");
+          }
+        });
+      }
+      Err(_) => {
+        tracing::warn!(
+          "The bundler was dropped without being closed, so plugins didn't run `close_bundle`. Call `Bundler::close` before dropping it."
+        );
+      }
+    }
+  }
+}
+
 fn _test_bundler() {
   #[allow(clippy::needless_pass_by_value)]
   fn _assert_send(_foo: impl Send) {}
@@ -190,4 +375,7 @@ fn _test_bundler() {
   let mut bundler = Bundler::new(BundlerOptions::default());
   let generate_fut = bundler.generate();
   _assert_send(generate_fut);
+  let mut bundler = Bundler::new(BundlerOptions::default());
+  let generate_outputs_fut = bundler.generate_outputs(vec![]);
+  _assert_send(generate_outputs_fut);
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use rolldown_common::FileEmitter;
 use rolldown_fs::OsFileSystem;
-use rolldown_plugin::{PluginDriver, __inner::SharedPluginable};
+use rolldown_plugin::{ContextMeta, PluginDriver, __inner::SharedPluginable};
 use rolldown_resolver::Resolver;
 
 use crate::{
@@ -35,12 +35,19 @@ impl BundlerBuilder {
     apply_inner_plugins(&mut self.plugins);
 
     Bundler {
-      plugin_driver: PluginDriver::new_shared(self.plugins, &resolver, &file_emitter),
+      plugin_driver: PluginDriver::new_shared(
+        self.plugins,
+        &resolver,
+        &file_emitter,
+        ContextMeta::new(options.watch),
+      ),
       file_emitter,
       resolver,
       options,
       fs: OsFileSystem,
+      scan_cache: Arc::default(),
       _log_guard: maybe_guard,
+      closed: false,
     }
   }
 
@@ -1,22 +1,30 @@
 use crate::{
   types::generator::{GenerateContext, GenerateOutput, Generator},
-  utils::{chunk::generate_rendered_chunk, render_ecma_module::render_ecma_module},
+  utils::{
+    chunk::{generate_rendered_chunk, render_chunk_exports::get_export_items},
+    render_ecma_module::{render_ecma_module, render_system_module},
+  },
 };
 
 use anyhow::Result;
 use rolldown_common::{
-  AssetMeta, EcmaAssetMeta, ModuleId, ModuleIdx, OutputFormat, PreliminaryAsset, RenderedModule,
+  AssetMeta, EcmaAssetMeta, EmptyChunkBehavior, ModuleId, ModuleIdx, OutputFormat,
+  PreliminaryAsset, RenderedModule,
 };
-use rolldown_error::DiagnosableResult;
+use rolldown_error::{BuildDiagnostic, DiagnosableResult};
 use rolldown_plugin::HookAddonArgs;
 use rolldown_sourcemap::Source;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
-use super::format::{app::render_app, cjs::render_cjs, esm::render_esm, iife::render_iife};
+use super::format::{
+  amd::render_amd, app::render_app, cjs::render_cjs, esm::render_esm, iife::render_iife,
+  system::render_system, umd::render_umd,
+};
 
-pub type RenderedModuleSources = Vec<(ModuleIdx, ModuleId, Option<Vec<Box<dyn Source + Send>>>)>;
+pub type RenderedModuleSource = (ModuleIdx, ModuleId, Option<Vec<Box<dyn Source + Send>>>);
+pub type RenderedModuleSources = Vec<RenderedModuleSource>;
 
 pub struct EcmaGenerator;
 
@@ -27,25 +35,44 @@ impl Generator for EcmaGenerator {
   ) -> Result<DiagnosableResult<GenerateOutput>> {
     let mut rendered_modules = FxHashMap::default();
 
-    let rendered_module_sources = ctx
+    // `System.register` chunks render the declarations hoisted out of `execute` separately.
+    let (hoisted_module_sources, rendered_module_sources) = ctx
       .chunk
       .modules
       .par_iter()
       .copied()
       .filter_map(|id| ctx.link_output.module_table.modules[id].as_ecma())
       .map(|m| {
-        (
-          m.idx,
-          m.id.clone(),
-          render_ecma_module(
-            m,
-            &ctx.link_output.ast_table[m.ecma_ast_idx()].0,
-            m.id.as_ref(),
-            ctx.options,
-          ),
-        )
+        let ast = &ctx.link_output.ast_table[m.ecma_ast_idx()].0;
+        let (hoisted, rendered) = if matches!(ctx.options.format, OutputFormat::System) {
+          render_system_module(m, ast, m.id.as_ref(), ctx.options)
+        } else {
+          (None, render_ecma_module(m, ast, m.id.as_ref(), ctx.options))
+        };
+        ((m.idx, m.id.clone(), hoisted), (m.idx, m.id.clone(), rendered))
       })
-      .collect::<Vec<_>>();
+      .unzip::<_, _, RenderedModuleSources, RenderedModuleSources>();
+
+    let is_empty_chunk = rendered_module_sources
+      .iter()
+      .chain(&hoisted_module_sources)
+      .all(|(_, _, sources)| sources.is_none())
+      && ctx.chunk.cross_chunk_imports.is_empty()
+      && ctx.chunk.cross_chunk_dynamic_imports.is_empty()
+      && ctx.chunk.imports_from_external_modules.is_empty()
+      && get_export_items(ctx.chunk, ctx.link_output).is_empty();
+    if is_empty_chunk {
+      let chunk_name = ctx.chunk.name.as_ref().map_or_else(String::new, ToString::to_string);
+      match ctx.options.empty_chunk {
+        EmptyChunkBehavior::Ignore => {}
+        EmptyChunkBehavior::Warn => {
+          ctx.warnings.push(BuildDiagnostic::empty_bundle(chunk_name).with_severity_warning());
+        }
+        EmptyChunkBehavior::Error => {
+          return Ok(Err(vec![BuildDiagnostic::empty_bundle(chunk_name)]))
+        }
+      }
+    }
 
     rendered_module_sources.iter().for_each(|(_, module_id, _)| {
       // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
@@ -72,6 +99,13 @@ impl Generator for EcmaGenerator {
         .banner(HookAddonArgs { chunk: &rendered_chunk }, injection.unwrap_or_default())
         .await?
     };
+    let banner = match (
+      banner,
+      render_legal_comments(ctx, rendered_module_sources.iter().chain(&hoisted_module_sources)),
+    ) {
+      (Some(banner), Some(legal_comments)) => Some(format!("{banner}\n{legal_comments}")),
+      (banner, legal_comments) => banner.or(legal_comments),
+    };
 
     let intro = {
       let injection = match ctx.options.intro.as_ref() {
@@ -121,6 +155,27 @@ impl Generator for EcmaGenerator {
           Err(errors) => return Ok(Err(errors)),
         }
       }
+      OutputFormat::Umd => {
+        match render_umd(ctx, rendered_module_sources, banner, footer, intro, outro) {
+          Ok(concat_source) => concat_source,
+          Err(errors) => return Ok(Err(errors)),
+        }
+      }
+      OutputFormat::System => render_system(
+        ctx,
+        hoisted_module_sources,
+        rendered_module_sources,
+        banner,
+        footer,
+        intro,
+        outro,
+      ),
+      OutputFormat::Amd => {
+        match render_amd(ctx, rendered_module_sources, banner, footer, intro, outro) {
+          Ok(concat_source) => concat_source,
+          Err(errors) => return Ok(Err(errors)),
+        }
+      }
     };
 
     let (content, mut map) = concat_source.content_and_sourcemap();
@@ -163,3 +218,19 @@ impl Generator for EcmaGenerator {
     }))
   }
 }
+
+/// Legal comments, like `/*! license */`, of the modules rendered into the chunk. Modules are rendered without
+/// comments, so they're emitted once at the top of the chunk, below the banner.
+fn render_legal_comments<'a>(
+  ctx: &GenerateContext<'_>,
+  module_sources: impl Iterator<Item = &'a RenderedModuleSource>,
+) -> Option<String> {
+  let mut seen = FxHashSet::default();
+  let legal_comments = module_sources
+    .filter(|(_, _, sources)| sources.is_some())
+    .filter_map(|(idx, _, _)| ctx.link_output.module_table.modules[*idx].as_ecma())
+    .flat_map(|module| module.legal_comments.iter().map(|(_, comment)| comment.as_str()))
+    .filter(|comment| seen.insert(*comment))
+    .collect::<Vec<_>>();
+  (!legal_comments.is_empty()).then(|| legal_comments.join("\n"))
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arcstr::ArcStr;
 use oxc::{
   index::IndexVec,
@@ -8,12 +10,15 @@ use rolldown_common::{
   AstScopes, EcmaModule, ModuleDefFormat, ModuleId, ModuleIdx, SymbolRef, TreeshakeOptions,
 };
 use rolldown_ecmascript::EcmaAst;
-use rolldown_error::{DiagnosableResult, UnhandleableResult};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult, UnhandleableResult};
 use rolldown_utils::{ecma_script::legitimize_identifier_name, path_ext::PathExt};
 use sugar_path::SugarPath;
 
 use crate::{
-  ast_scanner::{AstScanner, ScanResult},
+  ast_scanner::{
+    scan_cache::{ScanCacheKey, ScannedModule},
+    AstScanner, ScanResult,
+  },
   types::{
     ast_symbols::AstSymbols,
     module_factory::{CreateModuleArgs, CreateModuleContext, CreateModuleReturn, ModuleFactory},
@@ -26,15 +31,16 @@ use crate::{
 pub struct EcmaModuleFactory;
 
 impl EcmaModuleFactory {
+  /// `ast_symbols` and `ast_scopes` are taken as-is, so callers that already have them don't need to
+  /// run the semantic analysis again.
   fn scan_ast(
     module_idx: ModuleIdx,
     id: &ArcStr,
     ast: &mut EcmaAst,
-    symbols: SymbolTable,
-    scopes: ScopeTree,
+    mut ast_symbols: AstSymbols,
+    ast_scopes: AstScopes,
     module_def_format: ModuleDefFormat,
   ) -> UnhandleableResult<(AstScopes, ScanResult, AstSymbols, SymbolRef)> {
-    let (mut ast_symbols, ast_scopes) = make_ast_scopes_and_symbols(symbols, scopes);
     let module_id = ModuleId::new(ArcStr::clone(id));
     let repr_name = module_id.as_path().representative_file_name();
     let repr_name = legitimize_identifier_name(&repr_name);
@@ -54,42 +60,89 @@ impl EcmaModuleFactory {
 
     Ok((ast_scopes, scan_result, ast_symbols, namespace_object_ref))
   }
-}
 
-impl ModuleFactory for EcmaModuleFactory {
+  /// Same as [ModuleFactory::create_module], but tools that already ran the semantic analysis of the module could pass
+  /// the AST together with its symbol table and scope tree, which are used as they are. `args.source` isn't parsed
+  /// again in that case.
+  ///
+  /// The AST is expected to be in the shape [parse_to_ecma_ast] leaves it in, e.g. with imports hoisted, and the symbol
+  /// table and scope tree to be built from that AST.
   #[allow(clippy::too_many_lines)]
-  async fn create_module<'any>(
-    ctx: &mut CreateModuleContext<'any>,
+  pub async fn create_module_with_semantic(
+    ctx: &mut CreateModuleContext<'_>,
     args: CreateModuleArgs,
+    semantic: Option<(EcmaAst, SymbolTable, ScopeTree)>,
   ) -> anyhow::Result<DiagnosableResult<CreateModuleReturn>> {
     let id = ModuleId::new(ArcStr::clone(&ctx.resolved_id.id));
     let stable_id = id.stabilize(&ctx.options.cwd);
 
-    let parse_result = parse_to_ecma_ast(
-      ctx.plugin_driver,
-      ctx.resolved_id.id.as_path(),
-      &stable_id,
-      ctx.options,
-      &ctx.module_type,
-      args.source,
-      ctx.replace_global_define_config.as_ref(),
-    )?;
-
-    let (mut ast, symbols, scopes) = match parse_result {
-      Ok(parse_result) => parse_result,
-      Err(errs) => {
-        return Ok(Err(errs));
+    // Modules created from a given AST aren't cached, since the cache couldn't tell whether the AST changed.
+    let cache_key = semantic.is_none().then(|| {
+      ScanCacheKey::new(ctx.resolved_id.module_def_format, ctx.module_type.clone(), &args.source)
+    });
+    let cached = cache_key
+      .as_ref()
+      .and_then(|key| ctx.scan_cache.get(&ctx.resolved_id.id, ctx.module_index, key));
+    let scanned = if let Some(cached) = cached {
+      cached
+    } else {
+      let mut sourcemap_chain = vec![];
+      let (mut ast, symbols, scopes) = if let Some(semantic) = semantic {
+        semantic
+      } else {
+        let parse_result = parse_to_ecma_ast(
+          ctx.plugin_driver,
+          &id,
+          &stable_id,
+          ctx.options,
+          &ctx.module_type,
+          args.source,
+          &mut sourcemap_chain,
+          ctx.replace_global_define_config.as_ref(),
+        )?;
+        match parse_result {
+          Ok(parse_result) => parse_result,
+          Err(errs) => {
+            return Ok(Err(errs));
+          }
+        }
+      };
+
+      let (ast_symbols, ast_scopes) = make_ast_scopes_and_symbols(symbols, scopes);
+      let (ast_scopes, scan_result, ast_symbols, namespace_object_ref) = Self::scan_ast(
+        ctx.module_index,
+        &ctx.resolved_id.id,
+        &mut ast,
+        ast_symbols,
+        ast_scopes,
+        ctx.resolved_id.module_def_format,
+      )?;
+      let scanned = ScannedModule {
+        ast,
+        ast_scopes: Arc::new(ast_scopes),
+        scan_result,
+        ast_symbols,
+        namespace_object_ref,
+        sourcemap_chain,
+      };
+      if let Some(cache_key) = cache_key {
+        ctx.scan_cache.insert(ArcStr::clone(&ctx.resolved_id.id), cache_key, &scanned);
       }
+      scanned
     };
-
-    let (scope, scan_result, ast_symbol, namespace_object_ref) = Self::scan_ast(
-      ctx.module_index,
-      &ctx.resolved_id.id,
-      &mut ast,
-      symbols,
-      scopes,
-      ctx.resolved_id.module_def_format,
-    )?;
+    let ScannedModule {
+      ast,
+      ast_scopes: scope,
+      mut scan_result,
+      ast_symbols: ast_symbol,
+      namespace_object_ref,
+      sourcemap_chain: parse_sourcemap_chain,
+    } = scanned;
+    let mut sourcemap_chain = args.sourcemap_chain;
+    sourcemap_chain.extend(parse_sourcemap_chain);
+    if ctx.options.experimental.is_import_record_merging_enabled() {
+      scan_result.merge_duplicate_import_records();
+    }
 
     let resolved_deps = ctx.resolve_dependencies(&scan_result.import_records).await?;
 
@@ -98,15 +151,55 @@ impl ModuleFactory for EcmaModuleFactory {
       named_exports,
       stmt_infos,
       import_records,
+      import_patterns,
       star_exports,
       default_export_ref,
+      has_default_export,
       imports,
       exports_kind,
       repr_name,
       warnings: scan_warnings,
       has_eval,
+      has_top_level_await,
+      top_level_await_span,
+      legal_comments,
+      es_module_interop_default,
+      cjs_named_exports,
+      dynamic_import_uses,
+      mutated_exports,
+      undefined_default_export,
+      import_meta_usage,
+      import_reassignments,
+      unused_imports,
     } = scan_result;
     ctx.warnings.extend(scan_warnings);
+    if ctx.options.fail_on_import_reassignment {
+      ctx.errors.extend(import_reassignments);
+    } else {
+      ctx
+        .warnings
+        .extend(import_reassignments.into_iter().map(BuildDiagnostic::with_severity_warning));
+    }
+    if let Some(span) = undefined_default_export {
+      if ctx.options.experimental.is_undefined_default_export_warning_enabled() {
+        ctx.warnings.push(
+          BuildDiagnostic::undefined_default_export(id.to_string(), ast.source().clone(), span)
+            .with_severity_warning(),
+        );
+      }
+    }
+
+    if ctx.options.experimental.is_unused_import_warning_enabled() {
+      ctx.warnings.extend(unused_imports.into_iter().map(|span| {
+        BuildDiagnostic::unused_import(
+          id.to_string(),
+          ast.source().clone(),
+          span.source_text(ast.source()).to_string(),
+          span,
+        )
+        .with_severity_warning()
+      }));
+    }
 
     let mut imported_ids = vec![];
     let mut dynamically_imported_ids = vec![];
@@ -129,7 +222,9 @@ impl ModuleFactory for EcmaModuleFactory {
         .resolved_id
         .package_json
         .as_ref()
-        .and_then(|p| p.check_side_effects_for(&stable_id).map(DeterminedSideEffects::UserDefined))
+        .and_then(|p| {
+          p.check_side_effects_for(&ctx.resolved_id.id).map(DeterminedSideEffects::UserDefined)
+        })
         .unwrap_or_else(|| {
           let analyzed_side_effects = stmt_infos.iter().any(|stmt_info| stmt_info.side_effect);
           DeterminedSideEffects::Analyzed(analyzed_side_effects)
@@ -169,15 +264,17 @@ impl ModuleFactory for EcmaModuleFactory {
       imports,
       star_exports,
       default_export_ref,
+      has_default_export,
       scope,
       exports_kind,
       namespace_object_ref,
       def_format: ctx.resolved_id.module_def_format,
       debug_id: ctx.resolved_id.debug_id(&ctx.options.cwd),
-      sourcemap_chain: args.sourcemap_chain,
+      sourcemap_chain,
       exec_order: u32::MAX,
       is_user_defined_entry: ctx.is_user_defined_entry,
       import_records: IndexVec::default(),
+      import_patterns,
       is_included: false,
       importers: vec![],
       dynamic_importers: vec![],
@@ -185,7 +282,18 @@ impl ModuleFactory for EcmaModuleFactory {
       dynamically_imported_ids,
       side_effects,
       module_type: ctx.module_type.clone(),
+      source_type: ast.source_type,
       has_eval,
+      has_top_level_await,
+      top_level_await_span,
+      legal_comments,
+      es_module_interop_default,
+      cjs_named_exports,
+      dynamic_import_uses,
+      mutated_exports,
+      import_meta_usage,
+      is_handled_by_plugin: args.is_handled_by_plugin,
+      meta: ctx.resolved_id.meta.clone(),
     };
 
     Ok(Ok(CreateModuleReturn {
@@ -196,3 +304,97 @@ impl ModuleFactory for EcmaModuleFactory {
     }))
   }
 }
+
+impl ModuleFactory for EcmaModuleFactory {
+  async fn create_module<'any>(
+    ctx: &mut CreateModuleContext<'any>,
+    args: CreateModuleArgs,
+  ) -> anyhow::Result<DiagnosableResult<CreateModuleReturn>> {
+    Self::create_module_with_semantic(ctx, args, None).await
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+
+  use oxc::{index::Idx, span::SourceType};
+  use rolldown_common::{
+    FileEmitter, ModuleDefFormat, ModuleIdx, ModuleMeta, ModuleType, ResolvedId, StrOrBytes,
+  };
+  use rolldown_ecmascript::EcmaCompiler;
+  use rolldown_fs::OsFileSystem;
+  use rolldown_plugin::{ContextMeta, PluginDriver};
+  use rolldown_resolver::Resolver;
+
+  use super::EcmaModuleFactory;
+  use crate::{
+    ast_scanner::scan_cache::ScanCache,
+    types::module_factory::{CreateModuleArgs, CreateModuleContext},
+    utils::normalize_options::{normalize_options, NormalizeOptionsReturn},
+    BundlerOptions, SharedResolver,
+  };
+
+  async fn create_module_debug(code: &str, with_semantic: bool) -> String {
+    let NormalizeOptionsReturn { options, resolve_options } =
+      normalize_options(BundlerOptions::default());
+    let resolver: SharedResolver =
+      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
+    let options = Arc::new(options);
+    let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
+    let plugin_driver =
+      PluginDriver::new_shared(vec![], &resolver, &file_emitter, ContextMeta::default());
+    let resolved_id = ResolvedId {
+      id: options.cwd.join("main.js").to_string_lossy().into_owned().into(),
+      ignored: false,
+      module_def_format: ModuleDefFormat::Unknown,
+      is_external: false,
+      is_absolute_external: false,
+      package_json: None,
+      side_effects: None,
+      meta: ModuleMeta::default(),
+    };
+    let semantic = with_semantic.then(|| {
+      let ast =
+        EcmaCompiler::parse("main.js", code, SourceType::default().with_module(true)).unwrap();
+      let (symbols, scopes) = ast.make_symbol_table_and_scope_tree();
+      (ast, symbols, scopes)
+    });
+
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    let ret = EcmaModuleFactory::create_module_with_semantic(
+      &mut CreateModuleContext {
+        module_index: ModuleIdx::from_raw(0),
+        plugin_driver: &plugin_driver,
+        resolved_id: &resolved_id,
+        options: &options,
+        module_type: ModuleType::Js,
+        warnings: &mut warnings,
+        errors: &mut errors,
+        resolver: &resolver,
+        is_user_defined_entry: true,
+        replace_global_define_config: None,
+        scan_cache: &ScanCache::default(),
+      },
+      CreateModuleArgs {
+        source: StrOrBytes::Str(code.to_string()),
+        sourcemap_chain: vec![],
+        hook_side_effects: None,
+        is_handled_by_plugin: false,
+      },
+      semantic,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(warnings.is_empty() && errors.is_empty());
+    format!("{:?}", ret.module)
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn create_module_with_precomputed_semantic() {
+    let code = "export const b = 1;\nexport default function () { return b }";
+    assert_eq!(create_module_debug(code, true).await, create_module_debug(code, false).await);
+  }
+}
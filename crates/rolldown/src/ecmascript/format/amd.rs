@@ -0,0 +1,153 @@
+//! This is the render function for AMD format.
+//! It wraps the chunk content in a factory function passed to `define`.
+//!
+//! ```js
+//! define("my-lib", ["require", "exports", "module", "react"], function(require, exports, module, react) {
+//!   // chunk content
+//! });
+//! ```
+//!
+//! - The module id is only rendered if `output.amd.id` is set. Otherwise, the module is anonymous.
+//! - The external imports are the dependencies of `define`, and they are passed to the factory function in order.
+//! - `exports` is requested as a dependency if using named export, or if `output.amd.exports` is `true`. If using
+//!   default export, the default value is returned by the factory function.
+//! - `require` and `module` are requested if `output.amd.require` and `output.amd.module` are `true`.
+//! - If there are no dependencies at all, it renders `define(function() {...})`.
+//!
+//! The content of the factory function is rendered in the same way as the IIFE format.
+
+use crate::ecmascript::format::iife::render_iife_chunk_imports;
+use crate::utils::chunk::namespace_marker::render_namespace_markers;
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::{
+    determine_export_mode::determine_export_mode,
+    determine_use_strict::determine_use_strict,
+    render_chunk_exports::{get_export_items, render_chunk_exports},
+  },
+};
+use rolldown_common::{ChunkKind, OutputExports};
+use rolldown_error::DiagnosableResult;
+use rolldown_sourcemap::{ConcatSource, RawSource};
+use rolldown_utils::ecma_script::legitimize_identifier_name;
+
+/// The main function for rendering the AMD format chunks.
+pub fn render_amd(
+  ctx: &mut GenerateContext<'_>,
+  module_sources: RenderedModuleSources,
+  banner: Option<String>,
+  footer: Option<String>,
+  intro: Option<String>,
+  outro: Option<String>,
+) -> DiagnosableResult<ConcatSource> {
+  let mut concat_source = ConcatSource::default();
+
+  if let Some(banner) = banner {
+    concat_source.add_source(Box::new(RawSource::new(banner)));
+  }
+
+  // Analyze the export information of the chunk.
+  let export_items = get_export_items(ctx.chunk, ctx.link_output);
+  let has_exports = !export_items.is_empty();
+  let has_default_export = export_items.iter().any(|(name, _)| name.as_str() == "default");
+
+  let entry_module = match ctx.chunk.kind {
+    ChunkKind::EntryPoint { module, .. } => {
+      &ctx.link_output.module_table.modules[module].as_ecma().expect("should be ecma module")
+    }
+    ChunkKind::Common => unreachable!("amd should be entry point chunk"),
+  };
+
+  // We need to transform the `OutputExports::Auto` to suitable `OutputExports`.
+  let export_mode = determine_export_mode(ctx, entry_module, &export_items)?;
+  let named_exports = matches!(&export_mode, OutputExports::Named);
+
+  let (import_code, externals) = render_iife_chunk_imports(ctx);
+
+  let uses_exports = (has_exports && named_exports) || ctx.options.amd.is_exports_forced();
+  concat_source.add_source(Box::new(RawSource::new(render_amd_wrapper_start(
+    ctx,
+    &externals,
+    uses_exports,
+  ))));
+
+  if determine_use_strict(ctx) {
+    concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
+  }
+
+  if let Some(intro) = intro {
+    concat_source.add_source(Box::new(RawSource::new(intro)));
+  }
+
+  if named_exports {
+    if let Some(marker) =
+      render_namespace_markers(&ctx.options.es_module, has_default_export, false)
+    {
+      concat_source.add_source(Box::new(RawSource::new(marker.into())));
+    }
+  }
+
+  concat_source.add_source(Box::new(RawSource::new(import_code)));
+
+  // chunk content
+  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  // Named exports are written to the `exports` argument, and the default export is returned by the factory.
+  if let Some(exports) = render_chunk_exports(ctx, Some(&export_mode)) {
+    concat_source.add_source(Box::new(RawSource::new(exports)));
+  }
+
+  if let Some(outro) = outro {
+    concat_source.add_source(Box::new(RawSource::new(outro)));
+  }
+
+  concat_source.add_source(Box::new(RawSource::new("});".to_string())));
+
+  if let Some(footer) = footer {
+    concat_source.add_source(Box::new(RawSource::new(footer)));
+  }
+
+  Ok(concat_source)
+}
+
+/// Renders `define(` with the module id and the dependencies, and the start of the factory function.
+fn render_amd_wrapper_start(
+  ctx: &GenerateContext<'_>,
+  externals: &[String],
+  uses_exports: bool,
+) -> String {
+  let amd = &ctx.options.amd;
+  let special_deps = [
+    (amd.is_require_passed(), "require"),
+    (uses_exports, "exports"),
+    (amd.is_module_passed(), "module"),
+  ];
+
+  let mut deps = vec![];
+  let mut params = vec![];
+  for (_, name) in special_deps.into_iter().filter(|(passed, _)| *passed) {
+    deps.push(format!("\"{name}\""));
+    params.push(name.to_string());
+  }
+  for external in externals {
+    deps.push(format!("\"{external}\""));
+    params.push(legitimize_identifier_name(external).to_string());
+  }
+
+  let mut define_args = String::new();
+  if let Some(id) = &amd.id {
+    define_args.push_str(&format!("\"{id}\", "));
+  }
+  if !deps.is_empty() {
+    define_args.push_str(&format!("[{}], ", deps.join(", ")));
+  }
+
+  format!("define({define_args}function({}) {{\n", params.join(", "))
+}
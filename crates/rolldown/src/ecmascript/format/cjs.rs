@@ -12,7 +12,7 @@ use crate::{
     render_chunk_exports::render_chunk_exports,
   },
 };
-use rolldown_common::{ChunkKind, ExportsKind, Module, OutputExports, WrapKind};
+use rolldown_common::{ChunkKind, ExportsKind, Module, ModuleDefFormat, OutputExports, WrapKind};
 use rolldown_error::DiagnosableResult;
 use rolldown_sourcemap::{ConcatSource, RawSource};
 
@@ -58,6 +58,10 @@ pub fn render_cjs(
         let meta = &ctx.link_output.metas[entry_id];
         meta.require_bindings_for_star_exports.iter().for_each(|(importee_idx, binding_ref)| {
           let importee = &ctx.link_output.module_table.modules[*importee_idx];
+          let specifier = match importee {
+            Module::External(ext) => ext.rendered_specifier.as_str(),
+            Module::Ecma(_) => importee.stable_id(),
+          };
           let binding_ref_name =
             ctx.link_output.symbols.canonical_name_for(*binding_ref, &ctx.chunk.canonical_names);
             let import_stmt =
@@ -68,7 +72,7 @@ pub fn render_cjs(
   });
 });".replace("$NAME", binding_ref_name);
 
-          concat_source.add_source(Box::new(RawSource::new(format!("var {} = require(\"{}\");", binding_ref_name,&importee.stable_id()))));
+          concat_source.add_source(Box::new(RawSource::new(format!("var {} = require(\"{}\");", binding_ref_name, specifier))));
           concat_source.add_source(Box::new(RawSource::new(import_stmt)));
         });
         Some(export_mode)
@@ -122,12 +126,13 @@ pub fn render_cjs(
         concat_source.add_source(Box::new(RawSource::new(format!("{wrapper_ref_name}();",))));
       }
       WrapKind::Cjs => {
-        // "export default require_xxx();"
+        // "module.exports = require_xxx();"
         let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
         let wrapper_ref_name =
           ctx.link_output.symbols.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
-        concat_source
-          .add_source(Box::new(RawSource::new(format!("export default {wrapper_ref_name}();\n"))));
+        concat_source.add_source(Box::new(RawSource::new(format!(
+          "module.exports = {wrapper_ref_name}();\n"
+        ))));
       }
       WrapKind::None => {}
     }
@@ -180,6 +185,8 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
         if specifiers.is_empty() {
           s.push_str(&format!("{require_path_str};\n"));
         } else {
+          let uses_default_export =
+            specifiers.iter().any(|specifier| specifier.imported == "default");
           let specifiers = specifiers
             .iter()
             .map(|specifier| {
@@ -194,12 +201,7 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
             "const {{ {} }} = {};\n",
             specifiers.join(", "),
             if stmt.is_external {
-              let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
-                .link_output
-                .symbols
-                .par_canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
-
-              format!("{to_esm_fn_name}({require_path_str})")
+              render_to_esm_call(ctx, require_path_str, uses_default_export)
             } else {
               require_path_str
             }
@@ -210,12 +212,7 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
         s.push_str(&format!(
           "const {alias} = {};\n",
           if stmt.is_external {
-            let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
-              .link_output
-              .symbols
-              .par_canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
-
-            format!("{to_esm_fn_name}({require_path_str})")
+            render_to_esm_call(ctx, require_path_str, true)
           } else {
             require_path_str
           }
@@ -226,3 +223,26 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
 
   s
 }
+
+/// Renders `__toESM(require("external"))` like `ScopeHoistingFinalizer::to_esm_call_expr` does for CommonJS modules in
+/// the bundle. External modules are imported once for all the modules of the chunk, so `Interop::Auto` can't follow
+/// the importer and behaves like `Interop::Compat`.
+fn render_to_esm_call(
+  ctx: &GenerateContext<'_>,
+  require_path_str: String,
+  uses_default_export: bool,
+) -> String {
+  let interop = ctx.options.interop;
+  if !interop.requires_to_esm() {
+    return require_path_str;
+  }
+  let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
+    .link_output
+    .symbols
+    .par_canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
+  if uses_default_export && interop.is_node_mode(ModuleDefFormat::Unknown) {
+    format!("{to_esm_fn_name}({require_path_str}, 1)")
+  } else {
+    format!("{to_esm_fn_name}({require_path_str})")
+  }
+}
@@ -41,7 +41,7 @@ pub fn render_esm(
           .filter_map(|importee| {
             let importee = &ctx.link_output.module_table.modules[importee];
             match importee {
-              Module::External(ext) => Some(&ext.name),
+              Module::External(ext) => Some(&ext.rendered_specifier),
               Module::Ecma(_) => None,
             }
           })
@@ -176,7 +176,7 @@ pub fn render_iife(
 }
 
 /// Handling external imports needs to modify the arguments of the wrapper function.
-fn render_iife_chunk_imports(ctx: &GenerateContext<'_>) -> (String, Vec<String>) {
+pub(super) fn render_iife_chunk_imports(ctx: &GenerateContext<'_>) -> (String, Vec<String>) {
   let render_import_stmts =
     collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph);
 
@@ -1,5 +1,8 @@
+pub mod amd;
 pub mod app;
 pub mod cjs;
 pub mod esm;
 pub mod iife;
+pub mod system;
+pub mod umd;
 pub mod utils;
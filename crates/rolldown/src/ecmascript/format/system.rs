@@ -0,0 +1,263 @@
+//! This is the render function for SystemJS format.
+//! It registers the chunk with `System.register`, which lets the loader keep the bindings between modules live.
+//!
+//! ```js
+//! System.register(["node:path"], function(exports, module) {
+//!   var join;
+//!   var value;
+//!   function getValue() {}
+//!   exports("getValue", getValue);
+//!   return {
+//!     setters: [function(module) {
+//!       join = module.join;
+//!     }],
+//!     execute: function() {
+//!       // chunk content
+//!       exports({ value: value });
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! - The bindings imported from external modules are declared in the factory function, and assigned in the setters,
+//!   which the loader calls again whenever the exports of the external module change.
+//! - The top-level declarations of the modules are hoisted into the factory function by the finalizer. Exported
+//!   functions are reported to the loader right away, before `execute` runs, so modules importing the chunk in a cycle
+//!   could call them early.
+//! - The other exports are reported at the end of `execute`. Later updates of them are reported by the
+//!   `exports(name, value)` calls inserted by the finalizer.
+//! - `export * from "external"` in the entry module re-exports everything but `default` in the setter of the module.
+//! - If any module of the chunk uses top-level await, `execute` is an async function.
+
+use itertools::Itertools;
+use rolldown_common::{ChunkKind, Module, WrapKind};
+use rolldown_sourcemap::{ConcatSource, RawSource};
+use rustc_hash::FxHashMap;
+
+use crate::{
+  ecmascript::{ecma_generator::RenderedModuleSources, format::utils::namespace::generate_caller},
+  types::generator::GenerateContext,
+  utils::chunk::{
+    collect_render_chunk_imports::{
+      collect_render_chunk_imports, RenderImportDeclarationSpecifier,
+    },
+    determine_use_strict::determine_use_strict,
+    render_chunk_exports::{get_export_items, hoisted_function_names, render_chunk_exports},
+  },
+};
+
+/// The main function for rendering the SystemJS format chunks.
+///
+/// `hoisted_module_sources` are the declarations of the modules that are hoisted out of `execute`, and `module_sources`
+/// the rest of them.
+pub fn render_system(
+  ctx: &mut GenerateContext<'_>,
+  hoisted_module_sources: RenderedModuleSources,
+  module_sources: RenderedModuleSources,
+  banner: Option<String>,
+  footer: Option<String>,
+  intro: Option<String>,
+  outro: Option<String>,
+) -> ConcatSource {
+  let mut concat_source = ConcatSource::default();
+
+  if let Some(banner) = banner {
+    concat_source.add_source(Box::new(RawSource::new(banner)));
+  }
+
+  let module_id = ctx.options.name.as_ref().map(|name| format!("\"{name}\", ")).unwrap_or_default();
+  let (deps, setters, bindings) = render_system_setters(ctx);
+  concat_source.add_source(Box::new(RawSource::new(format!(
+    "System.register({module_id}[{}], function(exports, module) {{\n",
+    deps.iter().map(|dep| format!("\"{dep}\"")).join(", ")
+  ))));
+
+  if determine_use_strict(ctx) {
+    concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
+  }
+
+  if !bindings.is_empty() {
+    concat_source.add_source(Box::new(RawSource::new(format!("var {};", bindings.join(", ")))));
+  }
+
+  // hoisted declarations
+  hoisted_module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  if let Some(hoisted_exports) = render_hoisted_function_exports(ctx) {
+    concat_source.add_source(Box::new(RawSource::new(hoisted_exports)));
+  }
+
+  let has_top_level_await = ctx.chunk.modules.iter().any(|idx| {
+    ctx.link_output.module_table.modules[*idx].as_ecma().is_some_and(|m| m.has_top_level_await)
+  });
+  concat_source.add_source(Box::new(RawSource::new(format!(
+    "return {{\n  setters: [{}],\n  execute: {}function() {{\n",
+    setters.join(", "),
+    if has_top_level_await { "async " } else { "" }
+  ))));
+
+  if let Some(intro) = intro {
+    concat_source.add_source(Box::new(RawSource::new(intro)));
+  }
+
+  // chunk content
+  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  if let ChunkKind::EntryPoint { module: entry_id, .. } = ctx.chunk.kind {
+    let entry_meta = &ctx.link_output.metas[entry_id];
+    match entry_meta.wrap_kind {
+      WrapKind::Esm => {
+        // init_xxx()
+        let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
+        let wrapper_ref_name =
+          ctx.link_output.symbols.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
+        concat_source.add_source(Box::new(RawSource::new(format!("{wrapper_ref_name}();",))));
+      }
+      WrapKind::Cjs => {
+        // exports("default", require_xxx());
+        let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
+        let wrapper_ref_name =
+          ctx.link_output.symbols.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
+        concat_source.add_source(Box::new(RawSource::new(format!(
+          "exports(\"default\", {wrapper_ref_name}());"
+        ))));
+      }
+      WrapKind::None => {}
+    }
+  }
+
+  if let Some(exports) = render_chunk_exports(ctx, None) {
+    concat_source.add_source(Box::new(RawSource::new(exports)));
+  }
+
+  if let Some(outro) = outro {
+    concat_source.add_source(Box::new(RawSource::new(outro)));
+  }
+
+  concat_source.add_source(Box::new(RawSource::new("  }\n};\n});".to_string())));
+
+  if let Some(footer) = footer {
+    concat_source.add_source(Box::new(RawSource::new(footer)));
+  }
+
+  concat_source
+}
+
+/// `exports("name", fn);` for each exported function, which the loader expects to be reported before `execute`.
+fn render_hoisted_function_exports(ctx: &GenerateContext<'_>) -> Option<String> {
+  let hoisted_functions = hoisted_function_names(ctx.chunk, ctx.link_output);
+  let rendered_items = get_export_items(ctx.chunk, ctx.link_output)
+    .into_iter()
+    .filter_map(|(exported_name, export_ref)| {
+      let canonical_ref = ctx.link_output.symbols.par_canonical_ref_for(export_ref);
+      if ctx.link_output.symbols.get(canonical_ref).namespace_alias.is_some() {
+        return None;
+      }
+      let binding = ctx.chunk.canonical_names.get(&canonical_ref)?;
+      hoisted_functions
+        .contains(binding.as_str())
+        .then(|| format!("exports(\"{exported_name}\", {binding});"))
+    })
+    .collect::<Vec<_>>();
+  (!rendered_items.is_empty()).then(|| rendered_items.join("\n"))
+}
+
+/// Returns the dependencies of the chunk, the setters for each of them, and the bindings assigned by the setters.
+fn render_system_setters(ctx: &GenerateContext<'_>) -> (Vec<String>, Vec<String>, Vec<String>) {
+  // Exported bindings imported from external modules need to be reported to the loader when they change.
+  let export_items = get_export_items(ctx.chunk, ctx.link_output);
+  let mut exported_names_by_binding: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+  for (exported_name, export_ref) in &export_items {
+    let canonical_ref = ctx.link_output.symbols.par_canonical_ref_for(*export_ref);
+    if let Some(binding) = ctx.chunk.canonical_names.get(&canonical_ref) {
+      exported_names_by_binding.entry(binding.as_str()).or_default().push(exported_name.as_str());
+    }
+  }
+
+  let star_reexported_externals = match ctx.chunk.kind {
+    ChunkKind::EntryPoint { module, .. } => ctx.link_output.module_table.modules[module]
+      .as_ecma()
+      .map(|entry_module| {
+        entry_module
+          .star_export_module_ids()
+          .filter_map(|importee| match &ctx.link_output.module_table.modules[importee] {
+            Module::External(ext) => Some(ext.rendered_specifier.to_string()),
+            Module::Ecma(_) => None,
+          })
+          .unique()
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default(),
+    ChunkKind::Common => vec![],
+  };
+
+  let mut deps = vec![];
+  let mut setter_bodies: Vec<Vec<String>> = vec![];
+  let mut bindings = vec![];
+  let mut assign = |setter_body: &mut Vec<String>, binding: &str, value: String| {
+    setter_body.push(format!("{binding} = {value};"));
+    for exported_name in exported_names_by_binding.get(binding).into_iter().flatten() {
+      setter_body.push(format!("exports(\"{exported_name}\", {binding});"));
+    }
+    bindings.push(binding.to_string());
+  };
+
+  for stmt in collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph) {
+    let index = deps.iter().position(|dep| dep == stmt.path.as_str()).unwrap_or_else(|| {
+      deps.push(stmt.path.to_string());
+      setter_bodies.push(vec![]);
+      deps.len() - 1
+    });
+    let setter_body = &mut setter_bodies[index];
+    match &stmt.specifiers {
+      RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
+        for specifier in specifiers {
+          let binding = specifier.alias.unwrap_or(specifier.imported);
+          assign(setter_body, binding, format!("module{}", generate_caller(specifier.imported)));
+        }
+      }
+      RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
+        assign(setter_body, alias, "module".to_string());
+      }
+    }
+  }
+
+  let excluded_names = std::iter::once("\"default\"".to_string())
+    .chain(export_items.iter().map(|(name, _)| format!("\"{name}\"")))
+    .join(", ");
+  for external in star_reexported_externals {
+    let index = deps.iter().position(|dep| *dep == external).unwrap_or_else(|| {
+      deps.push(external);
+      setter_bodies.push(vec![]);
+      deps.len() - 1
+    });
+    setter_bodies[index].push(format!(
+      "exports(Object.fromEntries(Object.entries(module).filter(function(entry) {{ return [{excluded_names}].indexOf(entry[0]) < 0; }})));"
+    ));
+  }
+
+  let setters = setter_bodies
+    .into_iter()
+    .map(|body| {
+      if body.is_empty() {
+        "null".to_string()
+      } else {
+        format!("function(module) {{\n    {}\n  }}", body.join("\n    "))
+      }
+    })
+    .collect();
+
+  (deps, setters, bindings)
+}
@@ -0,0 +1,221 @@
+//! This is the render function for UMD format.
+//! It wraps the chunk content in a factory function, which is called differently depending on the environment.
+//!
+//! ```js
+//! (function(global, factory) {
+//!   typeof exports === "object" && typeof module !== "undefined" ? factory(exports, require("react")) :
+//!   typeof define === "function" && define.amd ? define(["exports", "react"], factory) :
+//!   (global = typeof globalThis !== "undefined" ? globalThis : global || self, factory(global.MyLib = {}, global.React));
+//! })(this, function(exports, react) {
+//!   // chunk content
+//! });
+//! ```
+//!
+//! - In CommonJS, the external imports are required and named exports are written to `exports`. If using default
+//!   export, the return value of the factory is assigned to `module.exports`.
+//! - In AMD, the external imports are the dependencies of `define`, and `exports` is requested as a dependency if
+//!   using named export.
+//! - Otherwise, the external imports are read from the global variables according to `output.globals`, and the
+//!   exports are assigned to the global variable named by `output.name`.
+//!
+//! The content of the factory function is rendered in the same way as the IIFE format.
+
+use crate::ecmascript::format::iife::render_iife_chunk_imports;
+use crate::ecmascript::format::utils::namespace::generate_caller;
+use crate::utils::chunk::namespace_marker::render_namespace_markers;
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::{
+    determine_export_mode::determine_export_mode,
+    determine_use_strict::determine_use_strict,
+    render_chunk_exports::{get_export_items, render_chunk_exports},
+  },
+};
+use arcstr::ArcStr;
+use rolldown_common::{ChunkKind, OutputExports};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult};
+use rolldown_sourcemap::{ConcatSource, RawSource};
+use rolldown_utils::ecma_script::legitimize_identifier_name;
+
+/// The main function for rendering the UMD format chunks.
+pub fn render_umd(
+  ctx: &mut GenerateContext<'_>,
+  module_sources: RenderedModuleSources,
+  banner: Option<String>,
+  footer: Option<String>,
+  intro: Option<String>,
+  outro: Option<String>,
+) -> DiagnosableResult<ConcatSource> {
+  let mut concat_source = ConcatSource::default();
+
+  if let Some(banner) = banner {
+    concat_source.add_source(Box::new(RawSource::new(banner)));
+  }
+
+  // Analyze the export information of the chunk.
+  let export_items = get_export_items(ctx.chunk, ctx.link_output);
+  let has_exports = !export_items.is_empty();
+  let has_default_export = export_items.iter().any(|(name, _)| name.as_str() == "default");
+
+  let entry_module = match ctx.chunk.kind {
+    ChunkKind::EntryPoint { module, .. } => {
+      &ctx.link_output.module_table.modules[module].as_ecma().expect("should be ecma module")
+    }
+    ChunkKind::Common => unreachable!("umd should be entry point chunk"),
+  };
+
+  // We need to transform the `OutputExports::Auto` to suitable `OutputExports`.
+  let export_mode = determine_export_mode(ctx, entry_module, &export_items)?;
+  let named_exports = matches!(&export_mode, OutputExports::Named);
+
+  if has_exports && ctx.options.name.is_none() {
+    return Err(vec![BuildDiagnostic::missing_name_option_for_umd_export()]);
+  }
+
+  let (import_code, externals) = render_iife_chunk_imports(ctx);
+
+  let uses_exports = has_exports && named_exports;
+  let wrapper_start = render_umd_wrapper_start(ctx, &externals, uses_exports, has_exports);
+  concat_source.add_source(Box::new(RawSource::new(wrapper_start)));
+
+  if determine_use_strict(ctx) {
+    concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
+  }
+
+  if let Some(intro) = intro {
+    concat_source.add_source(Box::new(RawSource::new(intro)));
+  }
+
+  if named_exports {
+    if let Some(marker) =
+      render_namespace_markers(&ctx.options.es_module, has_default_export, false)
+    {
+      concat_source.add_source(Box::new(RawSource::new(marker.into())));
+    }
+  }
+
+  concat_source.add_source(Box::new(RawSource::new(import_code)));
+
+  // chunk content
+  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  // Named exports are written to the `exports` argument, and the default export is returned by the factory.
+  if let Some(exports) = render_chunk_exports(ctx, Some(&export_mode)) {
+    concat_source.add_source(Box::new(RawSource::new(exports)));
+  }
+
+  if let Some(outro) = outro {
+    concat_source.add_source(Box::new(RawSource::new(outro)));
+  }
+
+  concat_source.add_source(Box::new(RawSource::new("});".to_string())));
+
+  if let Some(footer) = footer {
+    concat_source.add_source(Box::new(RawSource::new(footer)));
+  }
+
+  Ok(concat_source)
+}
+
+/// Renders everything before the body of the factory function, which detects the environment and calls the factory
+/// with the external imports of that environment.
+fn render_umd_wrapper_start(
+  ctx: &mut GenerateContext<'_>,
+  externals: &[String],
+  uses_exports: bool,
+  has_exports: bool,
+) -> String {
+  let mut factory_params = if uses_exports { vec!["exports".to_string()] } else { vec![] };
+  let mut cjs_args = if uses_exports { vec!["exports".to_string()] } else { vec![] };
+  let mut amd_deps = if uses_exports { vec!["\"exports\"".to_string()] } else { vec![] };
+  let mut global_args = vec![];
+
+  for external in externals {
+    factory_params.push(legitimize_identifier_name(external).to_string());
+    cjs_args.push(format!("require(\"{external}\")"));
+    amd_deps.push(format!("\"{external}\""));
+    global_args.push(render_global_access(ctx, external));
+  }
+
+  // The global variable that receives the exports, like `global.MyLib`, and the statements creating the namespaces
+  // it's nested in.
+  let (global_namespaces, global_target) = match &ctx.options.name {
+    Some(name) if has_exports => render_global_target(name),
+    _ => (String::new(), String::new()),
+  };
+
+  let cjs_factory_call = format!("factory({})", cjs_args.join(", "));
+  let cjs = if has_exports && !uses_exports {
+    format!("module.exports = {cjs_factory_call}")
+  } else {
+    cjs_factory_call
+  };
+
+  let amd = if amd_deps.is_empty() {
+    "define(factory)".to_string()
+  } else {
+    format!("define([{}], factory)", amd_deps.join(", "))
+  };
+
+  let global = if uses_exports {
+    let exports_arg = if ctx.options.extend {
+      format!("{global_target} = {global_target} || {{}}")
+    } else {
+      format!("{global_target} = {{}}")
+    };
+    format!(
+      "{global_namespaces}factory({})",
+      [exports_arg].into_iter().chain(global_args).collect::<Vec<_>>().join(", ")
+    )
+  } else if has_exports {
+    format!("{global_namespaces}{global_target} = factory({})", global_args.join(", "))
+  } else {
+    format!("factory({})", global_args.join(", "))
+  };
+
+  format!(
+    "(function(global, factory) {{
+  typeof exports === \"object\" && typeof module !== \"undefined\" ? {cjs} :
+  typeof define === \"function\" && define.amd ? {amd} :
+  (global = typeof globalThis !== \"undefined\" ? globalThis : global || self, {global});
+}})(this, function({}) {{\n",
+    factory_params.join(", ")
+  )
+}
+
+/// Renders the access to the global variable of an external import, according to `output.globals`.
+fn render_global_access(ctx: &mut GenerateContext<'_>, external: &str) -> String {
+  let global = if let Some(global) = ctx.options.globals.get(external) {
+    global.clone()
+  } else {
+    let target = legitimize_identifier_name(external).to_string();
+    ctx.warnings.push(
+      BuildDiagnostic::missing_global_name(ArcStr::from(external), ArcStr::from(&target))
+        .with_severity_warning(),
+    );
+    target
+  };
+  global.split('.').fold(String::from("global"), |access, part| access + &generate_caller(part))
+}
+
+/// For a name like `a.b.c`, returns `global.a = global.a || {}, global.a.b = global.a.b || {}, ` to create the
+/// namespaces and `global.a.b.c` as the target of the exports.
+fn render_global_target(name: &str) -> (String, String) {
+  let mut namespaces = String::new();
+  let mut target = String::from("global");
+  let parts = name.split('.').collect::<Vec<_>>();
+  for (i, part) in parts.iter().enumerate() {
+    target.push_str(&generate_caller(part));
+    if i < parts.len() - 1 {
+      namespaces.push_str(&format!("{target} = {target} || {{}}, "));
+    }
+  }
+  (namespaces, target)
+}
@@ -115,7 +115,7 @@ pub fn generate_identifier(
 ///
 /// - If the name is not a reserved word and not an invalid identifier, it will generate a caller like `.name`.
 /// - Otherwise, it will generate a caller like `["if"]`.
-fn generate_caller(name: &str) -> String {
+pub fn generate_caller(name: &str) -> String {
   if is_validate_assignee_identifier_name(name) {
     format!(".{name}")
   } else {
@@ -20,7 +20,10 @@ pub(crate) type SharedResolver = Arc<Resolver<OsFileSystem>>;
 pub(crate) type SharedOptions = Arc<NormalizedBundlerOptions>;
 
 pub use crate::{
-  bundler::Bundler, bundler_builder::BundlerBuilder, types::bundle_output::BundleOutput,
+  bundler::Bundler,
+  bundler_builder::BundlerBuilder,
+  stages::link_stage::{compute_execution_order, ModuleCycle},
+  types::bundle_output::BundleOutput,
 };
 
 pub use rolldown_common::bundler_options::*;
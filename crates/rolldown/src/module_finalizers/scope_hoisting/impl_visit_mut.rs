@@ -9,10 +9,12 @@ use oxc::{
   },
   span::{GetSpan, Span, SPAN},
 };
-use rolldown_common::{ExportsKind, Module, ModuleType, SymbolRef, WrapKind};
+use rolldown_common::{ExportsKind, Module, ModuleType, OutputFormat, SymbolRef, WrapKind};
 use rolldown_ecmascript::{AllocatorExt, ExpressionExt, StatementExt, TakeIn};
 
-use crate::utils::call_expression_ext::CallExpressionExt;
+use crate::utils::{
+  call_expression_ext::CallExpressionExt, jsx_element_name::jsx_element_name_reference_mut,
+};
 
 use super::ScopeHoistingFinalizer;
 
@@ -85,7 +87,6 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                     let importer_namespace_name =
                       self.canonical_name_for(self.ctx.module.namespace_object_ref);
                     // __reExport(exports, __toESM(require_xxxx()))
-                    let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
                     let importee_wrapper_ref_name =
                       self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
                     program.body.push(
@@ -94,9 +95,11 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                         .alloc_call_expr_with_2arg_expr_expr(
                           re_export_fn_name,
                           self.snippet.id_ref_expr(importer_namespace_name, SPAN),
-                          self.snippet.call_expr_with_arg_expr_expr(
-                            to_esm_fn_name,
+                          // `export *` doesn't re-export the default export.
+                          self.to_esm_call_expr(
+                            importee,
                             self.snippet.call_expr_expr(importee_wrapper_ref_name),
+                            false,
                           ),
                         )
                         .into_in(self.alloc),
@@ -109,6 +112,9 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                 match self.ctx.options.format {
                   rolldown_common::OutputFormat::Esm
                   | rolldown_common::OutputFormat::Iife
+                  | rolldown_common::OutputFormat::Umd
+                  | rolldown_common::OutputFormat::Amd
+                  | rolldown_common::OutputFormat::System
                   | rolldown_common::OutputFormat::Cjs => {
                     // Just remove the statement
                     return;
@@ -184,6 +190,7 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     if is_namespace_referenced {
       let mut stmts = self.generate_declaration_of_module_namespace_object();
       stmts.extend(program.body.take_in(self.alloc));
+      stmts.extend(self.generate_snapshot_of_module_namespace_object());
       program.body.extend(stmts);
     }
 
@@ -264,28 +271,7 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
           });
           program.body.extend(fn_stmts);
           if !hoisted_names.is_empty() {
-            let mut declarators = allocator::Vec::new_in(self.alloc);
-            declarators.reserve_exact(hoisted_names.len());
-            hoisted_names.into_iter().for_each(|var_name| {
-              declarators.push(ast::VariableDeclarator {
-                id: ast::BindingPattern {
-                  kind: ast::BindingPatternKind::BindingIdentifier(
-                    self.snippet.id(&var_name, SPAN).into_in(self.alloc),
-                  ),
-                  ..TakeIn::dummy(self.alloc)
-                },
-                kind: ast::VariableDeclarationKind::Var,
-                ..TakeIn::dummy(self.alloc)
-              });
-            });
-            program.body.push(ast::Statement::VariableDeclaration(
-              ast::VariableDeclaration {
-                declarations: declarators,
-                kind: ast::VariableDeclarationKind::Var,
-                ..TakeIn::dummy(self.alloc)
-              }
-              .into_in(self.alloc),
-            ));
+            program.body.push(self.hoisted_names_decl_stmt(hoisted_names));
           }
           program.body.push(self.snippet.esm_wrapper_stmt(
             wrap_ref_name,
@@ -296,6 +282,10 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
         WrapKind::None => {}
       }
     }
+
+    if matches!(self.ctx.options.format, OutputFormat::System) {
+      self.hoist_system_declarations(program);
+    }
   }
 
   fn visit_binding_identifier(&mut self, ident: &mut ast::BindingIdentifier<'ast>) {
@@ -325,6 +315,25 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     );
   }
 
+  fn visit_jsx_element_name(&mut self, name: &mut ast::JSXElementName<'ast>) {
+    // Only reachable with `JsxRuntime::Preserve`. The scanner resolves JSX names by top-level binding, so we do the same.
+    if let Some(ident) = jsx_element_name_reference_mut(name) {
+      if let Some(symbol_id) = self.scope.get_root_binding(&ident.name) {
+        let symbol_ref: SymbolRef = (self.ctx.id, symbol_id).into();
+        let canonical_ref = self.ctx.symbols.par_canonical_ref_for(symbol_ref);
+        // A namespace alias can't be written as a JSX name, so imports from CommonJS modules keep their local name.
+        if self.ctx.symbols.get(canonical_ref).namespace_alias.is_none() {
+          let canonical_name = self.canonical_name_for(symbol_ref);
+          if ident.name != canonical_name.as_str() {
+            ident.name = self.snippet.atom(canonical_name);
+          }
+        }
+      }
+    }
+
+    walk_mut::walk_jsx_element_name(self, name);
+  }
+
   fn visit_call_expression(&mut self, expr: &mut ast::CallExpression<'ast>) {
     self.try_rewrite_identifier_reference_expr(&mut expr.callee, true);
 
@@ -333,6 +342,17 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
   #[allow(clippy::collapsible_else_if, clippy::too_many_lines)]
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
+    if matches!(self.ctx.options.format, OutputFormat::System) {
+      if self.try_rewrite_system_import_meta(expr) {
+        return;
+      }
+      if let Some((canonical_ref, names)) = self.system_exported_write(expr) {
+        walk_mut::walk_expression(self, expr);
+        self.wrap_with_system_export_calls(expr, canonical_ref, &names);
+        return;
+      }
+    }
+
     if let Some(call_expr) = expr.as_call_expression_mut() {
       if call_expr.is_global_require_call(self.scope) && !call_expr.span.is_empty() {
         //  `require` calls that can't be recognized by rolldown are ignored in scanning, so they were not stored in `NomralModule#imports`.
@@ -394,7 +414,9 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
               // Rewrite `require('xxx')` to `require('fs')`, if there is an alias that maps 'xxx' to 'fs'
               *request_path = ast::Argument::StringLiteral(
-                self.snippet.alloc_string_literal(&importee.name, request_path.span()),
+                self
+                  .snippet
+                  .alloc_string_literal(&importee.rendered_specifier, request_path.span()),
               );
             }
           }
@@ -427,8 +449,14 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
       _ => {}
     };
 
-    // iife inline dynamic import
-    if matches!(self.ctx.options.format, rolldown_common::OutputFormat::Iife) {
+    // iife, umd, amd and system inline dynamic import
+    if matches!(
+      self.ctx.options.format,
+      rolldown_common::OutputFormat::Iife
+        | rolldown_common::OutputFormat::Umd
+        | rolldown_common::OutputFormat::Amd
+        | rolldown_common::OutputFormat::System
+    ) {
       if let Expression::ImportExpression(import_expr) = expr {
         let rec_id = self.ctx.module.imports[&import_expr.span];
         let rec = &self.ctx.module.import_records[rec_id];
@@ -456,25 +484,25 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
               }
               WrapKind::Cjs => {
                 //  `__toESM(require_foo())`
-                let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
                 let importee_wrapper_ref_name =
                   self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
 
                 *expr = self.snippet.promise_resolve_then_call_expr(
                   expr.span(),
-                  self.snippet.builder.vec1(self.snippet.return_stmt(
-                    self.snippet.call_expr_with_arg_expr_expr(
-                      to_esm_fn_name,
-                      self.snippet.call_expr_expr(importee_wrapper_ref_name),
-                    ),
-                  )),
+                  self.snippet.builder.vec1(self.snippet.return_stmt(self.to_esm_call_expr(
+                    importee,
+                    self.snippet.call_expr_expr(importee_wrapper_ref_name),
+                    true,
+                  ))),
                 );
               }
               WrapKind::None => {}
             }
           }
           Module::External(_) => {
-            // iife format doesn't support external module
+            // iife and umd formats don't support external module. The expression is kept, but the request of a
+            // non-literal `import(expr)` could still reference renamed symbols.
+            walk_mut::walk_expression(self, expr);
           }
         }
         return;
@@ -556,24 +584,34 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
         let importee_id = rec.resolved_module;
         match &self.ctx.modules[importee_id] {
           Module::Ecma(_importee) => {
-            let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
-              .expect("Normal module should belong to a chunk");
-            let importer_chunk = &self.ctx.chunk_graph.chunks[importer_chunk_id];
-
-            let importee_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee_id];
-            let importee_chunk = &self.ctx.chunk_graph.chunks[importee_chunk_id];
-
-            let import_path = importer_chunk.import_path_for(importee_chunk);
+            let import_path = self.dynamic_import_path_for(importee_id);
 
             str.value = self.snippet.atom(&import_path);
           }
           Module::External(importee) => {
-            if str.value != importee.name {
-              str.value = self.snippet.atom(&importee.name);
+            if str.value != importee.rendered_specifier {
+              str.value = self.snippet.atom(&importee.rendered_specifier);
             }
           }
         }
       }
+      source if expr.arguments.len() == 0 => {
+        // `import(dynamicVar)` is only rewritten if a `resolve_dynamic_import` hook resolved it. Otherwise it's an
+        // external named after the expression itself, which is kept as written.
+        let rec_id = self.ctx.module.imports[&expr.span];
+        let rec = &self.ctx.module.import_records[rec_id];
+        let importee_id = rec.resolved_module;
+        let import_path = match &self.ctx.modules[importee_id] {
+          Module::Ecma(_importee) => Some(self.dynamic_import_path_for(importee_id)),
+          Module::External(importee) => {
+            let is_resolved = importee.rendered_specifier.as_str() != rec.module_request.as_str();
+            is_resolved.then(|| importee.rendered_specifier.to_string())
+          }
+        };
+        if let Some(import_path) = import_path {
+          *source = self.snippet.string_literal_expr(&import_path, source.span());
+        }
+      }
       _ => {}
     }
 
@@ -3,7 +3,10 @@ use oxc::{
   ast::ast::{self, IdentifierReference, Statement},
   span::{Atom, SPAN},
 };
-use rolldown_common::{AstScopes, ImportRecordIdx, Module, OutputFormat, SymbolRef, WrapKind};
+use rolldown_common::{
+  AstScopes, EcmaModule, ImportRecordIdx, ImportRecordMeta, Module, ModuleIdx, OutputFormat,
+  SymbolRef, WrapKind,
+};
 use rolldown_ecmascript::{AstSnippet, BindingPatternExt, TakeIn};
 
 mod finalizer_context;
@@ -12,7 +15,10 @@ pub use finalizer_context::ScopeHoistingFinalizerContext;
 use rolldown_rstr::Rstr;
 use rolldown_utils::ecma_script::is_validate_identifier_name;
 
+use crate::utils::namespace_object::is_live_namespace_export;
+
 mod rename;
+mod system;
 
 /// Finalizer for emitting output code with scope hoisting.
 pub struct ScopeHoistingFinalizer<'me, 'ast> {
@@ -41,6 +47,47 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     self.canonical_name_for(sym_ref)
   }
 
+  /// The request of `import()` that loads the entry chunk of the module `importee_id`, relative to the chunk of the
+  /// current module.
+  fn dynamic_import_path_for(&self, importee_id: ModuleIdx) -> String {
+    let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+      .expect("Normal module should belong to a chunk");
+    let importer_chunk = &self.ctx.chunk_graph.chunks[importer_chunk_id];
+
+    let importee_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee_id];
+    let importee_chunk = &self.ctx.chunk_graph.chunks[importee_chunk_id];
+
+    importer_chunk.import_path_for(importee_chunk)
+  }
+
+  /// Converts `module.exports` of the CommonJS module `importee`, like `require_foo()`, to the namespace object of it,
+  /// depending on `options.interop`:
+  /// - `__toESM(require_foo())`
+  /// - `__toESM(require_foo(), 1)` if the `__esModule` marker should be ignored. The marker only matters if the default
+  ///   export is used, which is what `uses_default_export` tells.
+  /// - `require_foo()` for `Interop::EsModule`, or if `importee` is transpiled from ESM by Babel.
+  pub fn to_esm_call_expr(
+    &self,
+    importee: &EcmaModule,
+    module_exports: ast::Expression<'ast>,
+    uses_default_export: bool,
+  ) -> ast::Expression<'ast> {
+    let interop = self.ctx.options.interop;
+    if !importee.requires_to_esm(interop, self.ctx.module.def_format, uses_default_export) {
+      return module_exports;
+    }
+    let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
+    if uses_default_export && interop.is_node_mode(self.ctx.module.def_format) {
+      self.snippet.alloc_call_expr_with_2arg_expr_expr(
+        to_esm_fn_name,
+        module_exports,
+        self.snippet.number_expr(1.0, "1"),
+      )
+    } else {
+      self.snippet.call_expr_with_arg_expr_expr(to_esm_fn_name, module_exports)
+    }
+  }
+
   fn should_remove_import_export_stmt(
     &self,
     stmt: &mut Statement<'ast>,
@@ -57,14 +104,17 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       }
       WrapKind::Cjs => {
         // Replace the statement with something like `var import_foo = __toESM(require_foo())`
-        let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
         let wrapper_ref_name = self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
         let binding_name_for_wrapper_call_ret = self.canonical_name_for(rec.namespace_ref);
+        let uses_default_export = rec.meta.intersects(
+          ImportRecordMeta::CONTAINS_IMPORT_DEFAULT | ImportRecordMeta::CONTAINS_IMPORT_STAR,
+        );
         *stmt = self.snippet.var_decl_stmt(
           binding_name_for_wrapper_call_ret,
-          self.snippet.call_expr_with_arg_expr_expr(
-            to_esm_fn_name,
+          self.to_esm_call_expr(
+            importee,
             self.snippet.call_expr_expr(wrapper_ref_name),
+            uses_default_export,
           ),
         );
         return false;
@@ -213,6 +263,32 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     }
   }
 
+  /// `var a, b;` for the names hoisted by [Self::convert_decl_to_assignment].
+  fn hoisted_names_decl_stmt(&self, hoisted_names: Vec<Atom<'ast>>) -> ast::Statement<'ast> {
+    let mut declarators = oxc::allocator::Vec::new_in(self.alloc);
+    declarators.reserve_exact(hoisted_names.len());
+    hoisted_names.into_iter().for_each(|var_name| {
+      declarators.push(ast::VariableDeclarator {
+        id: ast::BindingPattern {
+          kind: ast::BindingPatternKind::BindingIdentifier(
+            self.snippet.id(&var_name, SPAN).into_in(self.alloc),
+          ),
+          ..TakeIn::dummy(self.alloc)
+        },
+        kind: ast::VariableDeclarationKind::Var,
+        ..TakeIn::dummy(self.alloc)
+      });
+    });
+    ast::Statement::VariableDeclaration(
+      ast::VariableDeclaration {
+        declarations: declarators,
+        kind: ast::VariableDeclarationKind::Var,
+        ..TakeIn::dummy(self.alloc)
+      }
+      .into_in(self.alloc),
+    )
+  }
+
   fn generate_declaration_of_module_namespace_object(&self) -> Vec<ast::Statement<'ast>> {
     let var_name = self.canonical_name_for(self.ctx.module.namespace_object_ref);
     // construct `var ns_name = {}`
@@ -220,16 +296,21 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       .snippet
       .var_decl_stmt(var_name, ast::Expression::ObjectExpression(TakeIn::dummy(self.alloc)));
 
-    let exports_len = self.ctx.linking_info.canonical_exports().count();
+    let live_exports = self
+      .ctx
+      .linking_info
+      .canonical_exports()
+      .filter(|(_, resolved_export)| self.is_live_namespace_export(resolved_export.symbol_ref))
+      .collect::<Vec<_>>();
 
     let export_all_externals_rec_ids = &self.ctx.linking_info.star_exports_from_external_modules;
 
     let mut re_export_external_stmts: Option<_> = None;
     if !export_all_externals_rec_ids.is_empty() {
       // construct `__reExport(exports, foo_ns)`
-      let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
       match self.ctx.options.format {
         OutputFormat::Esm => {
+          let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
           let stmts = export_all_externals_rec_ids.iter().copied().flat_map(|idx| {
             let rec = &self.ctx.module.import_records[idx];
             let importee_namespace_name = self.canonical_name_for(rec.namespace_ref);
@@ -239,7 +320,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
             };
             // Insert `import * as ns from 'ext'`external module in esm format
             // Insert `__reExport(exports, ns)`
-            let importee_name = &module.name;
+            let importee_name = &module.rendered_specifier;
             vec![
               self.snippet.import_star_stmt(importee_name, importee_namespace_name),
               self.snippet.builder.statement_expression(
@@ -254,7 +335,8 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           });
           re_export_external_stmts = Some(stmts.collect::<Vec<_>>());
         }
-        OutputFormat::Cjs | OutputFormat::Iife => {
+        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
+          let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
           let stmts = export_all_externals_rec_ids.iter().copied().map(|idx| {
             // Insert `__reExport(exports, require('ext'))`
             let importer_namespace_name =
@@ -277,11 +359,13 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           });
           re_export_external_stmts = Some(stmts.collect());
         }
+        // `System.register` chunks re-export external modules in the setters of them.
+        OutputFormat::System => {}
         OutputFormat::App => unreachable!(),
       }
     };
 
-    if exports_len == 0 {
+    if live_exports.is_empty() {
       let mut ret = vec![decl_stmt];
       ret.extend(re_export_external_stmts.unwrap_or_default());
       return ret;
@@ -289,9 +373,9 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     // construct `{ prop_name: () => returned, ... }`
     let mut arg_obj_expr = ast::ObjectExpression::dummy(self.alloc);
-    arg_obj_expr.properties.reserve_exact(exports_len);
+    arg_obj_expr.properties.reserve_exact(live_exports.len());
 
-    self.ctx.linking_info.canonical_exports().for_each(|(export, resolved_export)| {
+    live_exports.into_iter().for_each(|(export, resolved_export)| {
       // prop_name: () => returned
       let prop_name = export;
       let returned = self.finalized_expr_for_symbol_ref(resolved_export.symbol_ref, false);
@@ -329,4 +413,56 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     ret
   }
+
+  /// `ns_name.prop_name = returned;` for exports that aren't getters of the namespace object. These statements are put
+  /// at the end of the module, where the exported bindings have been initialized.
+  fn generate_snapshot_of_module_namespace_object(&self) -> Vec<ast::Statement<'ast>> {
+    let var_name = self.canonical_name_for(self.ctx.module.namespace_object_ref);
+    self
+      .ctx
+      .linking_info
+      .canonical_exports()
+      .filter(|(_, resolved_export)| !self.is_live_namespace_export(resolved_export.symbol_ref))
+      .map(|(export, resolved_export)| {
+        let ns_ref = self.snippet.id_ref_expr(var_name, SPAN);
+        let left = if is_validate_identifier_name(export) {
+          ast::AssignmentTarget::StaticMemberExpression(
+            self.snippet.builder.alloc_static_member_expression(
+              SPAN,
+              ns_ref,
+              self.snippet.id_name(export, SPAN),
+              false,
+            ),
+          )
+        } else {
+          ast::AssignmentTarget::ComputedMemberExpression(
+            self.snippet.builder.alloc_computed_member_expression(
+              SPAN,
+              ns_ref,
+              self.snippet.string_literal_expr(export, SPAN),
+              false,
+            ),
+          )
+        };
+        ast::Statement::ExpressionStatement(
+          ast::ExpressionStatement {
+            expression: ast::Expression::AssignmentExpression(
+              ast::AssignmentExpression {
+                left,
+                right: self.finalized_expr_for_symbol_ref(resolved_export.symbol_ref, false),
+                ..TakeIn::dummy(self.alloc)
+              }
+              .into_in(self.alloc),
+            ),
+            ..TakeIn::dummy(self.alloc)
+          }
+          .into_in(self.alloc),
+        )
+      })
+      .collect()
+  }
+
+  fn is_live_namespace_export(&self, symbol_ref: SymbolRef) -> bool {
+    is_live_namespace_export(self.ctx.options, self.ctx.modules, self.ctx.symbols, symbol_ref)
+  }
 }
@@ -0,0 +1,162 @@
+use oxc::{
+  allocator,
+  ast::ast::{self, AssignmentTarget, Expression, SimpleAssignmentTarget},
+  span::SPAN,
+  syntax::operator::{BinaryOperator, UpdateOperator},
+};
+use rolldown_common::{ChunkKind, SymbolRef};
+use rolldown_ecmascript::TakeIn;
+use rolldown_rstr::Rstr;
+
+use super::ScopeHoistingFinalizer;
+
+/// Rewrites specific to the `System.register` output, which keeps exports live by calling `exports(name, value)` of
+/// the loader whenever an exported binding is written.
+impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
+  /// Returns the canonical ref of the binding written by `expr` and the names it's exported as from the chunk, if
+  /// `expr` is an assignment or an update of an exported binding.
+  ///
+  /// This has to be called before `expr` is walked, because renaming the target drops its `ReferenceId`.
+  pub fn system_exported_write(
+    &self,
+    expr: &Expression<'ast>,
+  ) -> Option<(SymbolRef, Vec<&'me Rstr>)> {
+    let id_ref = match expr {
+      Expression::AssignmentExpression(assign_expr) => match &assign_expr.left {
+        AssignmentTarget::AssignmentTargetIdentifier(id_ref) => id_ref,
+        _ => return None,
+      },
+      Expression::UpdateExpression(update_expr) => match &update_expr.argument {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(id_ref) => id_ref,
+        _ => return None,
+      },
+      _ => return None,
+    };
+
+    let symbol_id = self.scope.symbol_id_for(id_ref.reference_id.get()?)?;
+    let symbol_ref: SymbolRef = (self.ctx.id, symbol_id).into();
+    if !self.ctx.module.mutated_exports.contains(&symbol_ref) {
+      return None;
+    }
+
+    // Only the entry chunk has exports, since `System.register` output doesn't support code splitting.
+    let chunk_idx = self.ctx.chunk_graph.module_to_chunk[self.ctx.id]?;
+    let ChunkKind::EntryPoint { module: entry, .. } = self.ctx.chunk_graph.chunks[chunk_idx].kind
+    else {
+      return None;
+    };
+    let canonical_ref = self.ctx.symbols.par_canonical_ref_for(symbol_ref);
+    let names = self.ctx.linking_infos[entry]
+      .canonical_exports()
+      .filter(|(_, export)| {
+        self.ctx.symbols.par_canonical_ref_for(export.symbol_ref) == canonical_ref
+      })
+      .map(|(name, _)| name)
+      .collect::<Vec<_>>();
+
+    (!names.is_empty()).then_some((canonical_ref, names))
+  }
+
+  /// - `a = 1` and `++a` become `exports("a", a = 1)` and `exports("a", ++a)`.
+  /// - `a++` becomes `(exports("a", a + 1), a++)`, so the value of the expression is still the old one.
+  ///
+  /// A binding exported under several names is reported once for each name, like `exports("b", exports("a", ...))`.
+  pub fn wrap_with_system_export_calls(
+    &self,
+    expr: &mut Expression<'ast>,
+    canonical_ref: SymbolRef,
+    names: &[&Rstr],
+  ) {
+    let postfix_update_operator = match expr {
+      Expression::UpdateExpression(update_expr) if !update_expr.prefix => {
+        Some(update_expr.operator)
+      }
+      _ => None,
+    };
+
+    let report_export_updates = |value: Expression<'ast>| {
+      names.iter().fold(value, |value, name| {
+        self.snippet.alloc_call_expr_with_2arg_expr_expr(
+          "exports",
+          self.snippet.string_literal_expr(name, SPAN),
+          value,
+        )
+      })
+    };
+
+    let original = expr.take_in(self.alloc);
+    *expr = if let Some(operator) = postfix_update_operator {
+      let binary_operator = match operator {
+        UpdateOperator::Increment => BinaryOperator::Addition,
+        UpdateOperator::Decrement => BinaryOperator::Subtraction,
+      };
+      let updated_value = self.snippet.builder.expression_binary(
+        SPAN,
+        self.snippet.id_ref_expr(self.canonical_name_for(canonical_ref), SPAN),
+        binary_operator,
+        self.snippet.number_expr(1.0, "1"),
+      );
+      self.snippet.seq2_in_paren_expr(report_export_updates(updated_value), original)
+    } else {
+      report_export_updates(original)
+    };
+  }
+
+  /// Top-level declarations of the module are hoisted out of `execute` into the function passed to `System.register`,
+  /// so the chunk could report exported functions before `execute` runs, like the loader expects:
+  ///
+  /// - `let a = 1` and `class A {}` become `a = 1` and `A = class {}`, and the names are declared by a `var a, A;`
+  ///   moved to the top of the module.
+  /// - Function declarations are moved below it.
+  ///
+  /// After this, the top-level variable and function declarations of the module are exactly the ones to render before
+  /// `execute`.
+  pub fn hoist_system_declarations(&self, program: &mut ast::Program<'ast>) {
+    let old_body = program.body.take_in(self.alloc);
+    let mut hoisted_names = vec![];
+    let mut fn_stmts = allocator::Vec::new_in(self.alloc);
+    let mut stmts = allocator::Vec::new_in(self.alloc);
+    old_body.into_iter().for_each(|mut stmt| match &mut stmt {
+      ast::Statement::VariableDeclaration(_) | ast::Statement::ClassDeclaration(_) => {
+        if let Some(converted) =
+          self.convert_decl_to_assignment(stmt.to_declaration_mut(), &mut hoisted_names)
+        {
+          stmts.push(converted);
+        }
+      }
+      ast::Statement::FunctionDeclaration(_) => {
+        fn_stmts.push(stmt);
+      }
+      _ => {
+        stmts.push(stmt);
+      }
+    });
+    if !hoisted_names.is_empty() {
+      program.body.push(self.hoisted_names_decl_stmt(hoisted_names));
+    }
+    program.body.extend(fn_stmts);
+    program.body.extend(stmts);
+  }
+
+  /// `import.meta` becomes `module.meta`, which is the `import.meta` object the loader provides to the chunk.
+  /// `import.meta.ROLLUP_FILE_URL_*` is left for `replace_file_url_placeholders`.
+  ///
+  /// Returns `true` if the expression is `import.meta` or such a placeholder, which don't need to be walked.
+  pub fn try_rewrite_system_import_meta(&self, expr: &mut Expression<'ast>) -> bool {
+    match expr {
+      Expression::MetaProperty(meta) if is_import_meta(meta) => {
+        *expr = self.snippet.literal_prop_access_member_expr_expr("module", "meta");
+        true
+      }
+      Expression::StaticMemberExpression(member_expr) => {
+        matches!(&member_expr.object, Expression::MetaProperty(meta) if is_import_meta(meta))
+          && member_expr.property.name.starts_with("ROLLUP_FILE_URL_")
+      }
+      _ => false,
+    }
+  }
+}
+
+fn is_import_meta(meta: &ast::MetaProperty) -> bool {
+  meta.meta.name == "import" && meta.property.name == "meta"
+}
@@ -5,12 +5,14 @@ pub mod task_context;
 mod task_result;
 
 pub use module_loader::ModuleLoader;
+use rolldown_common::ModuleIdx;
 use rolldown_error::BuildDiagnostic;
 
 use self::{runtime_module_task::RuntimeModuleTaskResult, task_result::NormalModuleTaskResult};
 pub enum Msg {
   NormalModuleDone(NormalModuleTaskResult),
   RuntimeNormalModuleDone(RuntimeModuleTaskResult),
-  BuildErrors(Vec<BuildDiagnostic>),
+  /// Errors of the normal module task of the module.
+  BuildErrors(ModuleIdx, Vec<BuildDiagnostic>),
   Panics(anyhow::Error),
 }
@@ -3,6 +3,7 @@ use super::runtime_module_task::RuntimeModuleTask;
 use super::task_context::TaskContextMeta;
 use super::task_result::NormalModuleTaskResult;
 use super::Msg;
+use crate::ast_scanner::scan_cache::SharedScanCache;
 use crate::module_loader::runtime_module_task::RuntimeModuleTaskResult;
 use crate::module_loader::task_context::TaskContext;
 use crate::runtime::{RuntimeModuleBrief, RUNTIME_MODULE_ID};
@@ -15,15 +16,18 @@ use oxc::span::Span;
 use rolldown_common::side_effects::DeterminedSideEffects;
 use rolldown_common::{
   EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx, ImporterRecord, Module,
-  ModuleIdx, ModuleTable, OutputFormat, ResolvedId,
+  ModuleId, ModuleIdx, ModuleTable, OutputFormat, ResolvedId, SharedFileEmitter,
 };
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
 use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
+use rolldown_resolver::ResolveError;
 use rolldown_utils::rustc_hash::FxHashSetExt;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
 use std::sync::Arc;
 
+use crate::utils::resolve_id::resolve_id;
 use crate::{SharedOptions, SharedResolver};
 
 pub struct IntermediateNormalModules {
@@ -52,10 +56,15 @@ impl IntermediateNormalModules {
 pub struct ModuleLoader {
   options: SharedOptions,
   shared_context: Arc<TaskContext>,
+  file_emitter: SharedFileEmitter,
   rx: tokio::sync::mpsc::Receiver<Msg>,
   visited: FxHashMap<ArcStr, ModuleIdx>,
   runtime_id: ModuleIdx,
   remaining: u32,
+  /// Modules whose tasks are spawned but whose results are not handled yet, in the order of their indices. Results
+  /// are handled in this order, no matter which task finishes first, so the indices of the modules they import are
+  /// the same in every build.
+  unhandled_tasks: VecDeque<ModuleIdx>,
   intermediate_normal_modules: IntermediateNormalModules,
   symbols: Symbols,
 }
@@ -68,7 +77,8 @@ pub struct ModuleLoaderOutput {
   // Entries that user defined + dynamic import entries
   pub entry_points: Vec<EntryPoint>,
   pub runtime: RuntimeModuleBrief,
-  pub warnings: Vec<BuildDiagnostic>,
+  /// Warnings reported while loading each module, grouped by the module they belong to.
+  pub module_warnings: FxHashMap<ModuleId, Vec<BuildDiagnostic>>,
 }
 
 impl ModuleLoader {
@@ -77,6 +87,8 @@ impl ModuleLoader {
     plugin_driver: SharedPluginDriver,
     fs: OsFileSystem,
     resolver: SharedResolver,
+    file_emitter: SharedFileEmitter,
+    scan_cache: SharedScanCache,
   ) -> anyhow::Result<Self> {
     // 1024 should be enough for most cases
     // over 1024 pending tasks are insane
@@ -106,6 +118,7 @@ impl ModuleLoader {
       fs,
       plugin_driver,
       meta,
+      scan_cache,
     });
 
     let mut intermediate_normal_modules = IntermediateNormalModules::new();
@@ -128,12 +141,14 @@ impl ModuleLoader {
 
     Ok(Self {
       shared_context: common_data,
+      file_emitter,
       rx,
       options,
       visited: FxHashMap::from_iter([(RUNTIME_MODULE_ID.into(), runtime_id)]),
       runtime_id,
       // runtime module is always there
       remaining: 1,
+      unhandled_tasks: VecDeque::new(),
       intermediate_normal_modules,
       symbols,
     })
@@ -147,6 +162,9 @@ impl ModuleLoader {
     match self.visited.entry(resolved_id.id.clone()) {
       std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
       std::collections::hash_map::Entry::Vacant(not_visited) => {
+        if let Some(on_module_added) = &self.options.on_module_added {
+          on_module_added.call(&ModuleId::new(ArcStr::clone(&resolved_id.id)));
+        }
         if resolved_id.is_external {
           let idx = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
           not_visited.insert(idx);
@@ -160,14 +178,16 @@ impl ModuleLoader {
               _ => DeterminedSideEffects::NoTreeshake,
             },
           };
-          let ext =
+          let mut ext =
             ExternalModule::new(idx, ArcStr::clone(&resolved_id.id), external_module_side_effects);
+          ext.meta = resolved_id.meta;
           self.intermediate_normal_modules.modules[idx] = Some(ext.into());
           idx
         } else {
           let idx = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
           not_visited.insert(idx);
           self.remaining += 1;
+          self.unhandled_tasks.push_back(idx);
 
           let task = ModuleTask::new(Arc::clone(&self.shared_context), idx, resolved_id, owner);
           #[cfg(target_family = "wasm")]
@@ -186,6 +206,68 @@ impl ModuleLoader {
     }
   }
 
+  /// Spawns tasks for the chunks emitted by plugins since the last call and adds them to `emitted_entry_points`.
+  async fn load_emitted_chunks(
+    &mut self,
+    emitted_entry_points: &mut Vec<EntryPoint>,
+    user_defined_entry_ids: &mut FxHashSet<ModuleIdx>,
+    errors: &mut Vec<BuildDiagnostic>,
+  ) -> anyhow::Result<()> {
+    for (reference_id, chunk) in self.file_emitter.take_unloaded_chunks() {
+      let resolved_id = resolve_id(
+        &self.shared_context.resolver,
+        &self.shared_context.plugin_driver,
+        &chunk.id,
+        None,
+        true,
+        ImportKind::Import,
+        None,
+        Arc::default(),
+      )
+      .await?;
+      let resolved_id = match resolved_id {
+        Ok(resolved_id) if resolved_id.is_external => {
+          errors.push(BuildDiagnostic::entry_cannot_be_external(resolved_id.id.to_string()));
+          continue;
+        }
+        Ok(resolved_id) => resolved_id,
+        Err(e @ ResolveError::PackagePathNotExported(..)) => {
+          errors.push(BuildDiagnostic::unresolved_entry(&chunk.id, Some(e)));
+          continue;
+        }
+        Err(ResolveError::NotFound(..)) => {
+          errors.push(BuildDiagnostic::unresolved_entry(&chunk.id, None));
+          continue;
+        }
+        Err(e) => return Err(e.into()),
+      };
+
+      self.file_emitter.set_chunk_module_id(&reference_id, ModuleId::new(resolved_id.id.clone()));
+      let id = self.try_spawn_new_task(resolved_id, None);
+      // Emitting a module that's already an entry doesn't create another chunk.
+      if user_defined_entry_ids.insert(id) {
+        emitted_entry_points.push(EntryPoint {
+          name: chunk.name.map(ArcStr::from),
+          id,
+          kind: EntryPointKind::UserDefined,
+        });
+      }
+    }
+    Ok(())
+  }
+
+  /// Gracefully shutdown all working threads, only receive and do not spawn.
+  // Expect cast to u32, since we are not going to have more than 2^32 tasks, or the
+  // `remaining` will overflow
+  #[allow(clippy::cast_possible_truncation)]
+  async fn wait_for_remaining_tasks(&mut self) {
+    while self.remaining > 0 {
+      let mut task = Vec::with_capacity(self.remaining as usize);
+      let received = self.rx.recv_many(&mut task, self.remaining as usize).await;
+      self.remaining -= received as u32;
+    }
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn fetch_all_modules(
     mut self,
@@ -196,7 +278,7 @@ impl ModuleLoader {
     }
 
     let mut errors = vec![];
-    let mut all_warnings: Vec<BuildDiagnostic> = vec![];
+    let mut module_warnings: FxHashMap<ModuleId, Vec<BuildDiagnostic>> = FxHashMap::default();
 
     let entries_count = user_defined_entries.len() + /* runtime */ 1;
     self.intermediate_normal_modules.modules.reserve(entries_count);
@@ -219,57 +301,44 @@ impl ModuleLoader {
 
     let mut dynamic_import_entry_ids = FxHashSet::default();
 
+    // Chunks emitted by plugins, such as in `buildStart` or `transform`, are loaded as user defined entries.
+    let mut emitted_entry_points = vec![];
+    self
+      .load_emitted_chunks(&mut emitted_entry_points, &mut user_defined_entry_ids, &mut errors)
+      .await?;
+
     let mut runtime_brief: Option<RuntimeModuleBrief> = None;
 
+    let scan_deadline =
+      self.options.scan_timeout.map(|timeout| (tokio::time::Instant::now() + timeout, timeout));
+    let mut scanned_modules_count = 0;
+
+    // Results of tasks that finished before the tasks of modules with smaller indices.
+    let mut finished_tasks: FxHashMap<ModuleIdx, Msg> = FxHashMap::default();
+
     while self.remaining > 0 {
-      let Some(msg) = self.rx.recv().await else {
+      let msg = match scan_deadline {
+        Some((deadline, timeout)) => tokio::select! {
+          biased;
+          () = tokio::time::sleep_until(deadline) => {
+            // Remaining tasks aren't waited for, since the timeout is meant to abort builds where a hook never returns.
+            errors.push(BuildDiagnostic::scan_timeout(timeout, scanned_modules_count));
+            return Ok(Err(errors));
+          }
+          msg = self.rx.recv() => msg,
+        },
+        None => self.rx.recv().await,
+      };
+      let Some(msg) = msg else {
         break;
       };
       match msg {
         Msg::NormalModuleDone(task_result) => {
-          let NormalModuleTaskResult {
-            module_idx,
-            resolved_deps,
-            mut module,
-            raw_import_records,
-            warnings,
-            ecma_related,
-          } = task_result;
-          all_warnings.extend(warnings);
-
-          let import_records: IndexVec<ImportRecordIdx, rolldown_common::ImportRecord> =
-            raw_import_records
-              .into_iter()
-              .zip(resolved_deps)
-              .map(|(raw_rec, info)| {
-                let ecma_module = module.as_ecma().unwrap();
-                let owner = ModuleTaskOwner::new(
-                  ecma_module.source.clone(),
-                  ecma_module.stable_id.as_str().into(),
-                  Span::new(raw_rec.module_request_start, raw_rec.module_request_end()),
-                );
-                let id = self.try_spawn_new_task(info, Some(owner));
-                // Dynamic imported module will be considered as an entry
-                self.intermediate_normal_modules.importers[id].push(ImporterRecord {
-                  kind: raw_rec.kind,
-                  importer_path: module.id().to_string().into(),
-                });
-                if matches!(raw_rec.kind, ImportKind::DynamicImport)
-                  && !user_defined_entry_ids.contains(&id)
-                {
-                  dynamic_import_entry_ids.insert(id);
-                }
-                raw_rec.into_import_record(id)
-              })
-              .collect::<IndexVec<ImportRecordIdx, _>>();
-
-          module.set_import_records(import_records);
-          if let Some((ast, ast_symbol)) = ecma_related {
-            let ast_idx = self.intermediate_normal_modules.index_ecma_ast.push((ast, module.idx()));
-            module.set_ecma_ast_idx(ast_idx);
-            self.symbols.add_ast_symbols(module_idx, ast_symbol);
-          }
-          self.intermediate_normal_modules.modules[module_idx] = Some(module);
+          scanned_modules_count += 1;
+          finished_tasks.insert(task_result.module_idx, Msg::NormalModuleDone(task_result));
+        }
+        Msg::BuildErrors(module_idx, e) => {
+          finished_tasks.insert(module_idx, Msg::BuildErrors(module_idx, e));
         }
         Msg::RuntimeNormalModuleDone(task_result) => {
           let RuntimeModuleTaskResult { ast_symbols, mut module, runtime, ast } = task_result;
@@ -280,27 +349,84 @@ impl ModuleLoader {
           self.symbols.add_ast_symbols(self.runtime_id, ast_symbols);
           runtime_brief = Some(runtime);
         }
-        Msg::BuildErrors(e) => {
-          errors.extend(e);
-        }
-        // Expect cast to u32, since we are not going to have more than 2^32 tasks, or the
-        // `remaining` will overflow
-        #[allow(clippy::cast_possible_truncation)]
         Msg::Panics(err) => {
           // `self.remaining -1` for the panic task it self
           self.remaining -= 1;
-          // gracefully shutdown all working thread, only receive and do not spawn
-          while self.remaining > 0 {
-            let mut task = Vec::with_capacity(self.remaining as usize);
-            let received = self.rx.recv_many(&mut task, self.remaining as usize).await;
-            self.remaining -= received as u32;
-          }
+          self.wait_for_remaining_tasks().await;
           return Err(err);
         }
       }
       self.remaining -= 1;
+
+      while let Some(msg) =
+        self.unhandled_tasks.front().and_then(|module_idx| finished_tasks.remove(module_idx))
+      {
+        self.unhandled_tasks.pop_front();
+        match msg {
+          Msg::NormalModuleDone(task_result) => {
+            let NormalModuleTaskResult {
+              module_idx,
+              resolved_deps,
+              mut module,
+              raw_import_records,
+              warnings,
+              ecma_related,
+            } = task_result;
+            if !warnings.is_empty() {
+              module_warnings.entry(ModuleId::new(module.id())).or_default().extend(warnings);
+            }
+
+            let import_records: IndexVec<ImportRecordIdx, rolldown_common::ImportRecord> =
+              raw_import_records
+                .into_iter()
+                .zip(resolved_deps)
+                .map(|(raw_rec, info)| {
+                  let ecma_module = module.as_ecma().unwrap();
+                  let owner = ModuleTaskOwner::new(
+                    ecma_module.source.clone(),
+                    ecma_module.stable_id.as_str().into(),
+                    Span::new(raw_rec.module_request_start, raw_rec.module_request_end()),
+                  );
+                  let id = self.try_spawn_new_task(info, Some(owner));
+                  // Dynamic imported module will be considered as an entry
+                  self.intermediate_normal_modules.importers[id].push(ImporterRecord {
+                    kind: raw_rec.kind,
+                    importer_path: module.id().to_string().into(),
+                  });
+                  if matches!(raw_rec.kind, ImportKind::DynamicImport)
+                    && !user_defined_entry_ids.contains(&id)
+                  {
+                    dynamic_import_entry_ids.insert(id);
+                  }
+                  raw_rec.into_import_record(id)
+                })
+                .collect::<IndexVec<ImportRecordIdx, _>>();
+
+            module.set_import_records(import_records);
+            if let Some((ast, ast_symbol)) = ecma_related {
+              let ast_idx =
+                self.intermediate_normal_modules.index_ecma_ast.push((ast, module.idx()));
+              module.set_ecma_ast_idx(ast_idx);
+              self.symbols.add_ast_symbols(module_idx, ast_symbol);
+            }
+            self.intermediate_normal_modules.modules[module_idx] = Some(module);
+          }
+          Msg::BuildErrors(_, e) => {
+            errors.extend(e);
+          }
+          Msg::RuntimeNormalModuleDone(_) | Msg::Panics(_) => {
+            unreachable!("Only results of normal module tasks are deferred")
+          }
+        }
+      }
+
+      self
+        .load_emitted_chunks(&mut emitted_entry_points, &mut user_defined_entry_ids, &mut errors)
+        .await?;
     }
 
+    self.file_emitter.set_module_loading_finished(true);
+
     if !errors.is_empty() {
       return Ok(Err(errors));
     }
@@ -327,8 +453,16 @@ impl ModuleLoader {
       })
       .collect();
 
-    // IIFE format should inline dynamic imports, so here not put dynamic imports to entries
-    if !matches!(self.options.format, OutputFormat::Iife) {
+    // Plugins emit chunks concurrently, so they are sorted to keep the order of chunks stable.
+    emitted_entry_points.sort_by_key(|entry| modules[entry.id].stable_id());
+    entry_points.extend(emitted_entry_points);
+    dynamic_import_entry_ids.retain(|id| !user_defined_entry_ids.contains(id));
+
+    // IIFE, UMD, AMD and SystemJS formats should inline dynamic imports, so here not put dynamic imports to entries
+    if !matches!(
+      self.options.format,
+      OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd | OutputFormat::System
+    ) {
       let mut dynamic_import_entry_ids = dynamic_import_entry_ids.into_iter().collect::<Vec<_>>();
       dynamic_import_entry_ids.sort_unstable_by_key(|id| modules[*id].stable_id());
 
@@ -345,7 +479,7 @@ impl ModuleLoader {
       index_ecma_ast: self.intermediate_normal_modules.index_ecma_ast,
       entry_points,
       runtime: runtime_brief.expect("Failed to find runtime module. This should not happen"),
-      warnings: all_warnings,
+      module_warnings,
     }))
   }
 }
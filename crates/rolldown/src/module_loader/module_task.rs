@@ -54,11 +54,12 @@ impl ModuleTask {
     match self.run_inner().await {
       Ok(()) => {
         if !self.errors.is_empty() {
-          self.ctx.tx.send(Msg::BuildErrors(self.errors)).await.expect("Send should not fail");
+          // The main thread stops receiving once scanning times out, so sending may fail.
+          let _ = self.ctx.tx.send(Msg::BuildErrors(self.module_idx, self.errors)).await;
         }
       }
       Err(err) => {
-        self.ctx.tx.send(Msg::Panics(err)).await.expect("Send should not fail");
+        let _ = self.ctx.tx.send(Msg::Panics(err)).await;
       }
     }
   }
@@ -67,14 +68,16 @@ impl ModuleTask {
     let mut hook_side_effects = self.resolved_id.side_effects.take();
     let mut sourcemap_chain = vec![];
     let mut warnings = vec![];
+    let mut is_handled_by_plugin = false;
 
     // Run plugin load to get content first, if it is None using read fs as fallback.
     let (source, mut module_type) = match load_source(
       &self.ctx.plugin_driver,
-      &self.resolved_id,
+      &mut self.resolved_id,
       &self.ctx.fs,
       &mut sourcemap_chain,
       &mut hook_side_effects,
+      &mut is_handled_by_plugin,
       &self.ctx.options,
     )
     .await
@@ -103,10 +106,21 @@ impl ModuleTask {
           source,
           &mut sourcemap_chain,
           &mut hook_side_effects,
+          &mut is_handled_by_plugin,
           &mut module_type,
         )
-        .await?;
-        source.into()
+        .await;
+        match source {
+          Ok(source) => source.into(),
+          // Errors created by `PluginContext::error` are reported like other build errors.
+          Err(err) => match err.downcast::<BuildDiagnostic>() {
+            Ok(diagnostic) => {
+              self.errors.push(diagnostic);
+              return Ok(());
+            }
+            Err(err) => return Err(err),
+          },
+        }
       }
       StrOrBytes::Bytes(_) => source,
     };
@@ -129,12 +143,14 @@ impl ModuleTask {
         resolved_id: &self.resolved_id,
         options: &self.ctx.options,
         warnings: &mut warnings,
+        errors: &mut self.errors,
         module_type: module_type.clone(),
         resolver: &self.ctx.resolver,
         is_user_defined_entry: self.is_user_defined_entry,
         replace_global_define_config: self.ctx.meta.replace_global_define_config.clone(),
+        scan_cache: &self.ctx.scan_cache,
       },
-      CreateModuleArgs { source, sourcemap_chain, hook_side_effects },
+      CreateModuleArgs { source, sourcemap_chain, hook_side_effects, is_handled_by_plugin },
     )
     .await?;
 
@@ -146,6 +162,13 @@ impl ModuleTask {
       }
     };
 
+    // The module was created, but some of its diagnostics fail the build. The main thread counts one message per task,
+    // so the warnings of the module are reported along with the errors instead of sending the module.
+    if !self.errors.is_empty() {
+      self.errors.extend(warnings);
+      return Ok(());
+    }
+
     if let Module::Ecma(module) = &module {
       self.ctx.plugin_driver.module_parsed(Arc::new(module.to_module_info())).await?;
     }
@@ -1,9 +1,11 @@
+use std::sync::Arc;
+
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
 use oxc::span::SourceType;
 use rolldown_common::{
   side_effects::DeterminedSideEffects, AstScopes, EcmaModule, ExportsKind, ModuleDefFormat,
-  ModuleId, ModuleIdx, ModuleType, SymbolRef,
+  ModuleId, ModuleIdx, ModuleMeta, ModuleType, SymbolRef,
 };
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, DiagnosableResult, UnhandleableResult};
@@ -67,12 +69,25 @@ impl RuntimeModuleTask {
       stmt_infos,
       star_exports,
       default_export_ref,
+      has_default_export,
       imports,
       repr_name,
       import_records: _,
+      import_patterns,
       exports_kind: _,
       warnings: _,
       has_eval,
+      has_top_level_await,
+      top_level_await_span,
+      legal_comments,
+      es_module_interop_default,
+      cjs_named_exports,
+      dynamic_import_uses,
+      mutated_exports,
+      undefined_default_export: _,
+      import_meta_usage,
+      import_reassignments: _,
+      unused_imports: _,
     } = scan_result;
 
     let module = EcmaModule {
@@ -88,7 +103,8 @@ impl RuntimeModuleTask {
       imports,
       star_exports,
       default_export_ref,
-      scope: ast_scope,
+      has_default_export,
+      scope: Arc::new(ast_scope),
       exports_kind: ExportsKind::Esm,
       namespace_object_ref,
       def_format: ModuleDefFormat::EsmMjs,
@@ -96,6 +112,7 @@ impl RuntimeModuleTask {
       exec_order: u32::MAX,
       is_user_defined_entry: false,
       import_records: IndexVec::default(),
+      import_patterns,
       is_included: false,
       sourcemap_chain: vec![],
       // The internal runtime module `importers/imported` should be skip.
@@ -105,7 +122,18 @@ impl RuntimeModuleTask {
       dynamically_imported_ids: vec![],
       side_effects: DeterminedSideEffects::Analyzed(false),
       module_type: ModuleType::Js,
+      source_type: ast.source_type,
       has_eval,
+      has_top_level_await,
+      top_level_await_span,
+      legal_comments,
+      es_module_interop_default,
+      cjs_named_exports,
+      dynamic_import_uses,
+      mutated_exports,
+      import_meta_usage,
+      is_handled_by_plugin: false,
+      meta: ModuleMeta::default(),
     };
 
     if let Err(_err) = self.tx.try_send(Msg::RuntimeNormalModuleDone(RuntimeModuleTaskResult {
@@ -2,7 +2,7 @@ use oxc::minifier::ReplaceGlobalDefinesConfig;
 use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 
-use crate::{SharedOptions, SharedResolver};
+use crate::{ast_scanner::scan_cache::SharedScanCache, SharedOptions, SharedResolver};
 
 use super::Msg;
 
@@ -14,6 +14,7 @@ pub struct TaskContext {
   pub fs: OsFileSystem,
   pub plugin_driver: SharedPluginDriver,
   pub meta: TaskContextMeta,
+  pub scan_cache: SharedScanCache,
 }
 
 pub struct TaskContextMeta {
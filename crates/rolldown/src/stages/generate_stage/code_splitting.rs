@@ -78,12 +78,69 @@ impl<'a> GenerateStage<'a> {
     });
   }
 
+  /// Calls `manual_chunks` for each included module and creates a common chunk for each returned name. Entry modules
+  /// and the runtime module are left to the default code splitting, and so are all modules of the formats that only
+  /// output a single chunk.
+  ///
+  /// Only these chunks are common chunks with a name before `generate_chunk_name_and_preliminary_filenames`.
+  fn assign_modules_to_manual_chunks(
+    &self,
+    chunks: &mut IndexChunks,
+    module_to_chunk: &mut IndexVec<ModuleIdx, Option<ChunkIdx>>,
+    module_to_bits: &IndexVec<ModuleIdx, BitSet>,
+    entry_module_to_entry_chunk: &FxHashMap<ModuleIdx, ChunkIdx>,
+  ) {
+    let Some(manual_chunks) = &self.options.manual_chunks else {
+      return;
+    };
+    if matches!(
+      self.options.format,
+      OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd | OutputFormat::System
+    ) {
+      return;
+    }
+
+    let module_infos = self.link_output.module_table.to_module_infos();
+    let entries_len: u32 =
+      self.link_output.entries.len().try_into().expect("Too many entries, u32 overflowed.");
+    let mut name_to_chunk: FxHashMap<String, ChunkIdx> = FxHashMap::default();
+    for module in self.link_output.module_table.modules.iter().filter_map(Module::as_ecma) {
+      if !module.is_included
+        || module.idx == self.link_output.runtime.id()
+        || entry_module_to_entry_chunk.contains_key(&module.idx)
+      {
+        continue;
+      }
+      let Some(name) = manual_chunks.call(&module.id, &module_infos[module.idx.index()]) else {
+        continue;
+      };
+      let chunk_id = *name_to_chunk.entry(name).or_insert_with_key(|name| {
+        chunks.push(Chunk::new(
+          Some(name.as_str().into()),
+          BitSet::new(entries_len),
+          vec![],
+          ChunkKind::Common,
+        ))
+      });
+      // The chunk is loaded by every entry any of its modules belongs to.
+      chunks[chunk_id].bits.union(&module_to_bits[module.idx]);
+      chunks[chunk_id].modules.push(module.idx);
+      module_to_chunk[module.idx] = Some(chunk_id);
+    }
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn generate_chunks(&self) -> ChunkGraph {
-    if matches!(self.options.format, OutputFormat::Iife) {
+    if matches!(
+      self.options.format,
+      OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd | OutputFormat::System
+    ) {
       let user_defined_entry_count =
         self.link_output.entries.iter().filter(|entry| entry.kind.is_user_defined()).count();
-      debug_assert!(user_defined_entry_count == 1, "IIFE format only supports one entry point");
+      debug_assert!(
+        user_defined_entry_count == 1,
+        "IIFE, UMD, AMD and SystemJS formats only support one entry point"
+      );
     }
     let entries_len: u32 =
       self.link_output.entries.len().try_into().expect("Too many entries, u32 overflowed.");
@@ -132,10 +189,18 @@ impl<'a> GenerateStage<'a> {
       self.link_output.module_table.modules.len()
     ];
 
+    // Modules grouped by `manual_chunks` are put into the named chunks, no matter which entries they belong to.
+    self.assign_modules_to_manual_chunks(
+      &mut chunks,
+      &mut module_to_chunk,
+      &module_to_bits,
+      &entry_module_to_entry_chunk,
+    );
+
     // 1. Assign modules to corresponding chunks
     // 2. Create shared chunks to store modules that belong to multiple chunks.
     for normal_module in self.link_output.module_table.modules.iter().filter_map(Module::as_ecma) {
-      if !normal_module.is_included {
+      if !normal_module.is_included || module_to_chunk[normal_module.idx].is_some() {
         continue;
       }
 
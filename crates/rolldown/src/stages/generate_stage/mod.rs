@@ -6,7 +6,10 @@ use oxc::{ast::VisitMut, index::IndexVec};
 use rolldown_ecmascript::AstSnippet;
 use rustc_hash::FxHashMap;
 
-use rolldown_common::{ChunkIdx, ChunkKind, FileNameRenderOptions, Module, PreliminaryFilename};
+use rolldown_common::{
+  ChunkIdx, ChunkKind, FileNameRenderOptions, Module, OutputFileInfo, OutputFileKind,
+  PreliminaryFilename, SharedFileEmitter,
+};
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::{
   path_buf_ext::PathBufExt,
@@ -36,11 +39,14 @@ mod code_splitting;
 mod compute_cross_chunk_links;
 mod minify_assets;
 mod render_chunk_to_assets;
+mod validate_chunk_exports;
+mod validate_manual_chunks;
 
 pub struct GenerateStage<'a> {
   link_output: &'a mut LinkStageOutput,
   options: &'a SharedOptions,
   plugin_driver: &'a SharedPluginDriver,
+  file_emitter: &'a SharedFileEmitter,
 }
 
 impl<'a> GenerateStage<'a> {
@@ -48,20 +54,27 @@ impl<'a> GenerateStage<'a> {
     link_output: &'a mut LinkStageOutput,
     options: &'a SharedOptions,
     plugin_driver: &'a SharedPluginDriver,
+    file_emitter: &'a SharedFileEmitter,
   ) -> Self {
-    Self { link_output, options, plugin_driver }
+    Self { link_output, options, plugin_driver, file_emitter }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> Result<BundleOutput> {
     let mut chunk_graph = self.generate_chunks();
 
+    self.validate_manual_chunks(&chunk_graph);
+
     self.generate_chunk_name_and_preliminary_filenames(&mut chunk_graph)?;
 
+    self.set_emitted_chunk_preliminary_filenames(&chunk_graph);
+
     self.compute_cross_chunk_links(&mut chunk_graph);
 
+    self.validate_chunk_default_exports(&chunk_graph);
+
     chunk_graph.chunks.iter_mut().par_bridge().for_each(|chunk| {
-      deconflict_chunk_symbols(chunk, self.link_output, &self.options.format);
+      deconflict_chunk_symbols(chunk, self.link_output, self.options);
     });
 
     let ast_table_iter = self.link_output.ast_table.iter_mut();
@@ -114,6 +127,25 @@ impl<'a> GenerateStage<'a> {
     self.render_chunk_to_assets(&mut chunk_graph).await
   }
 
+  /// Let plugins get the file names of emitted chunks while rendering. Hash placeholders in them are replaced like
+  /// the ones in imports of other chunks.
+  fn set_emitted_chunk_preliminary_filenames(&self, chunk_graph: &ChunkGraph) {
+    let modules = &self.link_output.module_table.modules;
+    let filename_by_entry_id = chunk_graph
+      .chunks
+      .iter()
+      .filter_map(|chunk| match chunk.kind {
+        ChunkKind::EntryPoint { module, .. } => {
+          Some((modules[module].id(), chunk.preliminary_filename.as_ref()?.as_str()))
+        }
+        ChunkKind::Common => None,
+      })
+      .collect::<FxHashMap<_, _>>();
+    self.file_emitter.set_chunk_file_names(|module_id| {
+      filename_by_entry_id.get(module_id.as_str()).map(ToString::to_string)
+    });
+  }
+
   // Notices:
   // - Should generate filenames that are stable cross builds and os.
   // #[tracing::instrument(level = "debug", skip_all)]
@@ -151,6 +183,10 @@ impl<'a> GenerateStage<'a> {
             }
           }
           ChunkKind::Common => {
+            // Chunks of `manual_chunks` are named by the user. Unlike the names of entries, they may be deduplicated.
+            if let Some(name) = &chunk.name {
+              return ChunkNameInfo { name: name.clone(), explicit: false };
+            }
             // - rollup use the first entered/last executed module as the `[name]` of common chunks.
             // - esbuild always use 'chunk' as the `[name]`. However we try to make the name more meaningful here.
             let first_executed_non_runtime_module =
@@ -201,7 +237,18 @@ impl<'a> GenerateStage<'a> {
       };
       used_name_map.insert(chunk_name.clone(), 1);
 
-      let filename_template = chunk.filename_template(self.options);
+      let custom_filename_template = self.options.file_name.as_ref().map(|file_name| {
+        let kind = if matches!(chunk.kind, ChunkKind::EntryPoint { is_user_defined: true, .. }) {
+          OutputFileKind::Entry
+        } else {
+          OutputFileKind::Chunk
+        };
+        file_name.call(&OutputFileInfo { name: &chunk_name, kind })
+      });
+      let filename_template = match &custom_filename_template {
+        Some(template) => template,
+        None => chunk.filename_template(self.options),
+      };
       let css_filename_template = chunk.css_filename_template(self.options);
       let extracted_hash_pattern = extract_hash_pattern(filename_template.template());
       let extracted_css_hash_pattern = extract_hash_pattern(css_filename_template.template());
@@ -215,13 +262,13 @@ impl<'a> GenerateStage<'a> {
       let preliminary = filename_template.render(&FileNameRenderOptions {
         name: Some(&chunk_name),
         hash: hash_placeholder.as_deref(),
-        ..Default::default()
+        ext: Some("js"),
       });
 
       let css_preliminary = css_filename_template.render(&FileNameRenderOptions {
         name: Some(&chunk_name),
         hash: hash_placeholder.as_deref(),
-        ..Default::default()
+        ext: Some("css"),
       });
 
       chunk.name = Some(chunk_name);
@@ -12,7 +12,7 @@ use crate::{
   types::generator::{GenerateContext, Generator},
   utils::{
     augment_chunk_hash::augment_chunk_hash, chunk::finalize_chunks::finalize_assets,
-    render_chunks::render_chunks,
+    file_url_placeholder::replace_file_url_placeholders, render_chunks::render_chunks,
   },
   BundleOutput,
 };
@@ -26,10 +26,22 @@ impl<'a> GenerateStage<'a> {
     chunk_graph: &mut ChunkGraph,
   ) -> anyhow::Result<BundleOutput> {
     let mut errors = std::mem::take(&mut self.link_output.errors);
-    let mut warnings = std::mem::take(&mut self.link_output.warnings);
+    let mut warnings = std::mem::take(&mut self.link_output.module_warnings)
+      .into_values()
+      .flatten()
+      .collect::<Vec<_>>();
+    warnings.append(&mut self.link_output.warnings);
     let (mut preliminary_assets, index_chunk_to_assets) =
       self.render_preliminary_assets(chunk_graph, &mut errors, &mut warnings).await?;
 
+    replace_file_url_placeholders(
+      &mut preliminary_assets,
+      self.options,
+      self.file_emitter,
+      self.plugin_driver,
+    )
+    .await?;
+
     render_chunks(self.plugin_driver, &mut preliminary_assets).await?;
 
     augment_chunk_hash(self.plugin_driver, &mut preliminary_assets).await?;
@@ -85,20 +97,23 @@ impl<'a> GenerateStage<'a> {
           map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
 
           match self.options.sourcemap {
-            SourceMapType::File => {
+            Some(SourceMapType::File | SourceMapType::Hidden) => {
               let source = map.to_json_string();
               outputs.push(Output::Asset(Box::new(OutputAsset {
                 filename: map_filename.clone(),
                 source: source.into(),
                 name: None,
               })));
-              code.push_str(&format!("\n//# sourceMappingURL={map_filename}"));
+              // Hidden sourcemaps are emitted for tools like error trackers, but browsers shouldn't load them.
+              if matches!(self.options.sourcemap, Some(SourceMapType::File)) {
+                code.push_str(&format!("\n//# sourceMappingURL={map_filename}"));
+              }
             }
-            SourceMapType::Inline => {
+            Some(SourceMapType::Inline) => {
               let data_url = map.to_data_url();
               code.push_str(&format!("\n//# sourceMappingURL={data_url}"));
             }
-            SourceMapType::Hidden => {}
+            None => {}
           }
         }
         let sourcemap_filename =
@@ -0,0 +1,57 @@
+use rolldown_common::{Module, SymbolRef};
+use rolldown_error::BuildDiagnostic;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::GenerateStage;
+use crate::{chunk_graph::ChunkGraph, utils::chunk::render_chunk_exports::get_export_items};
+
+impl<'a> GenerateStage<'a> {
+  /// Chunks that aggregate code from multiple entries might expose `default` exports of different
+  /// modules. Make sure they never end up sharing the same exported name in a chunk, which would
+  /// produce a chunk with duplicate exports.
+  pub fn validate_chunk_default_exports(&mut self, chunk_graph: &ChunkGraph) {
+    let default_export_refs = self
+      .link_output
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_ecma)
+      .map(|module| self.link_output.symbols.par_canonical_ref_for(module.default_export_ref))
+      .collect::<FxHashSet<_>>();
+
+    for chunk in &chunk_graph.chunks {
+      let mut exported: FxHashMap<_, Vec<SymbolRef>> = FxHashMap::default();
+      for (exported_name, export_ref) in get_export_items(chunk, self.link_output) {
+        let canonical_ref = self.link_output.symbols.par_canonical_ref_for(export_ref);
+        let refs = exported.entry(exported_name).or_default();
+        if !refs.contains(&canonical_ref) {
+          refs.push(canonical_ref);
+        }
+      }
+
+      let mut conflicts = exported
+        .into_iter()
+        .filter(|(name, refs)| {
+          refs.len() > 1
+            && (name.as_str() == "default"
+              || refs.iter().any(|symbol_ref| default_export_refs.contains(symbol_ref)))
+        })
+        .collect::<Vec<_>>();
+      conflicts.sort_unstable_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+
+      for (exported_name, refs) in conflicts {
+        let modules = refs
+          .iter()
+          .map(|symbol_ref| {
+            self.link_output.module_table.modules[symbol_ref.owner].id().to_string()
+          })
+          .collect();
+        self.link_output.errors.push(BuildDiagnostic::conflicting_default_export(
+          chunk.name.as_ref().map_or_else(String::new, ToString::to_string),
+          exported_name.to_string(),
+          modules,
+        ));
+      }
+    }
+  }
+}
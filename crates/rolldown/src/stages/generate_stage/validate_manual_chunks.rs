@@ -0,0 +1,73 @@
+use oxc::index::{index_vec, IndexVec};
+use rolldown_common::{ChunkIdx, ChunkKind, ModuleIdx};
+use rolldown_error::BuildDiagnostic;
+use rustc_hash::FxHashSet;
+
+use super::GenerateStage;
+use crate::chunk_graph::ChunkGraph;
+
+impl<'a> GenerateStage<'a> {
+  /// Modules of a chunk are executed together, after the chunks it imports. Grouping modules with `manual_chunks` is
+  /// impossible if a manual chunk imports a chunk that imports the manual chunk in turn, since some of the modules
+  /// couldn't be executed in `exec_order` any more.
+  ///
+  /// The default code splitting never produces such chunks, so only manual chunks are checked.
+  pub fn validate_manual_chunks(&mut self, chunk_graph: &ChunkGraph) {
+    let is_manual_chunk = |chunk_idx: ChunkIdx| {
+      let chunk = &chunk_graph.chunks[chunk_idx];
+      matches!(chunk.kind, ChunkKind::Common) && chunk.name.is_some()
+    };
+    if !chunk_graph.chunks.indices().any(is_manual_chunk) {
+      return;
+    }
+
+    // For each chunk, the chunks it imports and a pair of modules causing the import.
+    let mut imported_chunks: IndexVec<ChunkIdx, Vec<(ChunkIdx, ModuleIdx, ModuleIdx)>> =
+      index_vec![vec![]; chunk_graph.chunks.len()];
+    let modules = &self.link_output.module_table.modules;
+    for chunk_idx in &chunk_graph.sorted_chunk_idx_vec {
+      for importer in &chunk_graph.chunks[*chunk_idx].modules {
+        for importee in &self.link_output.metas[*importer].dependencies {
+          let Some(importee_chunk_idx) = chunk_graph.module_to_chunk[*importee] else {
+            continue;
+          };
+          let imported = &mut imported_chunks[*chunk_idx];
+          if importee_chunk_idx != *chunk_idx
+            && !imported.iter().any(|(idx, _, _)| *idx == importee_chunk_idx)
+          {
+            imported.push((importee_chunk_idx, *importer, *importee));
+          }
+        }
+      }
+    }
+
+    let mut errors = vec![];
+    for chunk_idx in
+      chunk_graph.sorted_chunk_idx_vec.iter().copied().filter(|idx| is_manual_chunk(*idx))
+    {
+      let cycle = imported_chunks[chunk_idx].iter().find(|(importee_chunk_idx, _, _)| {
+        // Check if the manual chunk is reachable from the imported chunk.
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![*importee_chunk_idx];
+        while let Some(idx) = stack.pop() {
+          if idx == chunk_idx {
+            return true;
+          }
+          if visited.insert(idx) {
+            stack.extend(imported_chunks[idx].iter().map(|(idx, _, _)| *idx));
+          }
+        }
+        false
+      });
+      if let Some((_, importer, importee)) = cycle {
+        let chunk_name = chunk_graph.chunks[chunk_idx].name.as_ref().map(ToString::to_string);
+        errors.push(BuildDiagnostic::invalid_manual_chunk(
+          chunk_name.unwrap_or_default(),
+          modules[*importer].id().to_string(),
+          modules[*importee].id().to_string(),
+        ));
+      }
+    }
+    self.link_output.errors.extend(errors);
+  }
+}
@@ -361,7 +361,15 @@ impl<'a> BindImportsAndExportsContext<'a> {
       };
       tracing::trace!("Got match result {:?}", ret);
       match ret {
-        MatchImportKind::_Ignore | MatchImportKind::Cycle => {}
+        MatchImportKind::_Ignore => {}
+        MatchImportKind::Cycle => {
+          self.errors.push(BuildDiagnostic::circular_reexport(
+            module.stable_id.to_string(),
+            module.source.clone(),
+            named_import.imported.to_string(),
+            named_import.span_imported,
+          ));
+        }
         MatchImportKind::Ambiguous { symbol_ref, potentially_ambiguous_symbol_refs } => {
           let importee = self.normal_modules[rec.resolved_module].stable_id().to_string();
 
@@ -1,16 +1,17 @@
 use std::{ptr::addr_of, sync::Mutex};
 
-use oxc::index::IndexVec;
+use oxc::{index::IndexVec, span::Span};
 use rolldown_common::{
-  EntryPoint, ExportsKind, ImportKind, ImportRecordMeta, Module, ModuleIdx, ModuleTable,
-  OutputFormat, StmtInfo, SymbolRef, WrapKind,
+  DynamicImportUse, EntryPoint, ExportsKind, ImportKind, ImportRecordMeta, Module, ModuleId,
+  ModuleIdx, ModuleTable, OutputFormat, StmtInfo, SymbolRef, WrapKind,
 };
 use rolldown_error::BuildDiagnostic;
+use rolldown_rstr::Rstr;
 use rolldown_utils::{
   ecma_script::legitimize_identifier_name,
   rayon::{ParallelBridge, ParallelIterator},
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   runtime::RuntimeModuleBrief,
@@ -19,9 +20,11 @@ use crate::{
     linking_metadata::{LinkingMetadata, LinkingMetadataVec},
     symbols::Symbols,
   },
+  utils::namespace_object::is_live_namespace_export,
   SharedOptions,
 };
 
+pub use self::sort_modules::{compute_execution_order, ModuleCycle};
 use self::wrapping::create_wrapper;
 
 use super::scan_stage::ScanStageOutput;
@@ -29,6 +32,8 @@ use super::scan_stage::ScanStageOutput;
 mod bind_imports_and_exports;
 mod sort_modules;
 pub(crate) mod tree_shaking;
+mod validate_import_attributes;
+mod validate_top_level_await;
 mod wrapping;
 
 #[derive(Debug)]
@@ -41,10 +46,81 @@ pub struct LinkStageOutput {
   pub symbols: Symbols,
   pub runtime: RuntimeModuleBrief,
   pub warnings: Vec<BuildDiagnostic>,
+  pub module_warnings: FxHashMap<ModuleId, Vec<BuildDiagnostic>>,
   pub errors: Vec<BuildDiagnostic>,
   pub used_symbol_refs: FxHashSet<SymbolRef>,
 }
 
+impl LinkStageOutput {
+  /// Warnings reported while scanning the module `id`.
+  pub fn module_warnings(&self, id: &ModuleId) -> &[BuildDiagnostic] {
+    self.module_warnings.get(id).map(Vec::as_slice).unwrap_or_default()
+  }
+
+  /// The namespace object of the module `id`, which is what `import * as ns from '...'` refers to. It's the same
+  /// `SymbolRef` as the one created while scanning. Returns `None` for external or unknown modules.
+  pub fn module_namespace_ref(&self, id: &ModuleId) -> Option<SymbolRef> {
+    self
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_ecma)
+      .find(|module| module.id == *id)
+      .map(|module| module.namespace_object_ref)
+  }
+
+  /// The module that the import at `span` of the module `id` resolves to. `span` is the span of the whole import
+  /// statement, `export ... from` statement, `import(...)` or `require(...)` call, as recorded while scanning.
+  pub fn resolved_module_at(&self, id: &ModuleId, span: Span) -> Option<ModuleId> {
+    let importer = self
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_ecma)
+      .find(|module| module.id == *id)?;
+    let rec = &importer.import_records[*importer.imports.get(&span)?];
+    Some(ModuleId::new(self.module_table.modules[rec.resolved_module].id()))
+  }
+
+  /// Union of the exports of `id` read through all `import('...')` sites importing it. Returns `None` if any site
+  /// could use every export, or if `id` isn't dynamically imported at all.
+  pub fn dynamic_import_used_exports(&self, id: &ModuleId) -> Option<FxHashSet<Rstr>> {
+    let target = self.module_table.modules.iter().find(|module| module.id() == id.as_str())?.idx();
+    let mut used_exports = None::<FxHashSet<Rstr>>;
+    for importer in self.module_table.modules.iter().filter_map(Module::as_ecma) {
+      for (rec_id, rec) in importer.import_records.iter_enumerated() {
+        if !matches!(rec.kind, ImportKind::DynamicImport) || rec.resolved_module != target {
+          continue;
+        }
+        match importer.dynamic_import_uses.get(&rec_id)? {
+          DynamicImportUse::All => return None,
+          DynamicImportUse::Partial(names) => {
+            used_exports.get_or_insert_with(FxHashSet::default).extend(names.iter().cloned());
+          }
+        }
+      }
+    }
+    used_exports
+  }
+
+  /// Whether `id` is the module providing runtime helpers, which doesn't correspond to any user code.
+  pub fn is_runtime_module(&self, id: &ModuleId) -> bool {
+    self.module_table.modules[self.runtime.id()].id() == id.as_str()
+  }
+
+  /// Modules using top-level await, which require the output to be able to run asynchronously.
+  pub fn top_level_await_modules(&self) -> Vec<ModuleId> {
+    self
+      .module_table
+      .modules
+      .iter()
+      .filter_map(|module| module.as_ecma())
+      .filter(|module| module.has_top_level_await)
+      .map(|module| module.id.clone())
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct LinkStage<'a> {
   pub module_table: ModuleTable,
@@ -54,14 +130,28 @@ pub struct LinkStage<'a> {
   pub sorted_modules: Vec<ModuleIdx>,
   pub metas: LinkingMetadataVec,
   pub warnings: Vec<BuildDiagnostic>,
+  pub module_warnings: FxHashMap<ModuleId, Vec<BuildDiagnostic>>,
   pub errors: Vec<BuildDiagnostic>,
   pub ast_table: IndexEcmaAst,
   pub options: &'a SharedOptions,
+  /// Formats of the outputs generated from the linked module graph. Usually it's only `options.format`, but the graph
+  /// is linked once for all outputs of [crate::Bundler::generate_outputs], so it has to work for each of them.
+  pub output_formats: Vec<&'a OutputFormat>,
   pub used_symbol_refs: FxHashSet<SymbolRef>,
 }
 
 impl<'a> LinkStage<'a> {
   pub fn new(scan_stage_output: ScanStageOutput, options: &'a SharedOptions) -> Self {
+    Self::with_output_formats(scan_stage_output, options, vec![&options.format])
+  }
+
+  pub fn with_output_formats(
+    scan_stage_output: ScanStageOutput,
+    options: &'a SharedOptions,
+    output_formats: Vec<&'a OutputFormat>,
+  ) -> Self {
+    let inlines_dynamic_imports =
+      output_formats.iter().any(|format| format_inlines_dynamic_imports(format));
     Self {
       sorted_modules: Vec::new(),
       metas: scan_stage_output
@@ -72,17 +162,10 @@ impl<'a> LinkStage<'a> {
           dependencies: module
             .import_records()
             .iter()
-            .filter_map(|rec| match options.format {
-              OutputFormat::Cjs | OutputFormat::App | OutputFormat::Esm => {
-                if matches!(rec.kind, ImportKind::DynamicImport) {
-                  None
-                } else {
-                  Some(rec.resolved_module)
-                }
-              }
-              // IIFE format will inline dynamic imported modules
-              OutputFormat::Iife => Some(rec.resolved_module),
-            })
+            // The importer doesn't execute modules it only resolves
+            .filter(|rec| !matches!(rec.kind, ImportKind::ResolveOnly))
+            .filter(|rec| inlines_dynamic_imports || !matches!(rec.kind, ImportKind::DynamicImport))
+            .map(|rec| rec.resolved_module)
             .collect(),
           star_exports_from_external_modules: module.as_ecma().map_or(vec![], |inner| {
             inner
@@ -97,9 +180,11 @@ impl<'a> LinkStage<'a> {
       symbols: scan_stage_output.symbols,
       runtime: scan_stage_output.runtime,
       warnings: scan_stage_output.warnings,
+      module_warnings: scan_stage_output.module_warnings,
       errors: scan_stage_output.errors,
       ast_table: scan_stage_output.index_ecma_ast,
       options,
+      output_formats,
       used_symbol_refs: FxHashSet::default(),
     }
   }
@@ -107,6 +192,9 @@ impl<'a> LinkStage<'a> {
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn link(mut self) -> LinkStageOutput {
     self.sort_modules();
+    self.validate_import_attributes();
+    self.warn_unhandled_import_attributes();
+    self.validate_top_level_await();
 
     self.determine_module_exports_kind();
     self.wrap_modules();
@@ -124,16 +212,25 @@ impl<'a> LinkStage<'a> {
       symbols: self.symbols,
       runtime: self.runtime,
       warnings: self.warnings,
+      module_warnings: self.module_warnings,
       errors: self.errors,
       ast_table: self.ast_table,
       used_symbol_refs: self.used_symbol_refs,
     }
   }
 
+  /// Whether any output has a format matching `predicate`.
+  fn has_output_format(&self, predicate: impl Fn(&OutputFormat) -> bool) -> bool {
+    self.output_formats.iter().any(|format| predicate(format))
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   fn determine_module_exports_kind(&mut self) {
     // Maximize the compatibility with commonjs
     let compat_mode = true;
+    let inlines_dynamic_imports = self.has_output_format(format_inlines_dynamic_imports);
+    let has_esm_output = self.has_output_format(|format| matches!(format, OutputFormat::Esm));
+    let has_cjs_output = self.has_output_format(|format| matches!(format, OutputFormat::Cjs));
     let entry_ids_set = self.entries.iter().map(|e| e.id).collect::<FxHashSet<_>>();
     self.module_table.modules.iter().filter_map(Module::as_ecma).for_each(|importer| {
       importer.import_records.iter().for_each(|rec| {
@@ -193,8 +290,8 @@ impl<'a> LinkStage<'a> {
             }
           },
           ImportKind::DynamicImport => {
-            if matches!(self.options.format, OutputFormat::Iife) {
-              // For iife, then import() is just a require() that
+            if inlines_dynamic_imports {
+              // For iife, umd, amd and system, then import() is just a require() that
               // returns a promise, so the imported file must also be wrapped
               match importee.exports_kind {
                 ExportsKind::Esm => {
@@ -223,18 +320,17 @@ impl<'a> LinkStage<'a> {
               }
             }
           }
+          ImportKind::ResolveOnly => {}
         }
       });
 
       let is_entry = entry_ids_set.contains(&importer.idx);
-      if matches!(importer.exports_kind, ExportsKind::CommonJs)
-        && (!is_entry || matches!(self.options.format, OutputFormat::Esm))
-      {
+      if matches!(importer.exports_kind, ExportsKind::CommonJs) && (!is_entry || has_esm_output) {
         self.metas[importer.idx].wrap_kind = WrapKind::Cjs;
       }
 
       // TODO: should have a better place to put this
-      if is_entry && matches!(self.options.format, OutputFormat::Cjs) {
+      if is_entry && has_cjs_output {
         importer.star_exports.iter().for_each(|rec_idx| {
           let rec = &importer.import_records[*rec_idx];
           match &self.module_table.modules[rec.resolved_module] {
@@ -259,6 +355,8 @@ impl<'a> LinkStage<'a> {
 
   #[tracing::instrument(level = "debug", skip_all)]
   fn reference_needed_symbols(&mut self) {
+    let has_cjs_output = self.has_output_format(|format| matches!(format, OutputFormat::Cjs));
+    let inlines_dynamic_imports = self.has_output_format(format_inlines_dynamic_imports);
     let symbols = Mutex::new(&mut self.symbols);
     self.module_table.modules.iter().par_bridge().filter_map(Module::as_ecma).for_each(
       |importer| {
@@ -284,8 +382,10 @@ impl<'a> LinkStage<'a> {
                         format!("import_{}", legitimize_identifier_name(&importee.name)).into();
                     } else {
                       // import ... from 'external' or export ... from 'external'
-                      let cjs_format = matches!(self.options.format, OutputFormat::Cjs);
-                      if cjs_format && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT) {
+                      if has_cjs_output
+                        && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT)
+                        && self.options.interop.requires_to_esm()
+                      {
                         stmt_info
                           .referenced_symbols
                           .push(self.runtime.resolve_symbol("__toESM").into());
@@ -310,9 +410,15 @@ impl<'a> LinkStage<'a> {
                           stmt_info
                             .referenced_symbols
                             .push(importee_linking_info.wrapper_ref.unwrap().into());
-                          stmt_info
-                            .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__toESM").into());
+                          if importee.requires_to_esm(
+                            self.options.interop,
+                            importer.def_format,
+                            false,
+                          ) {
+                            stmt_info
+                              .referenced_symbols
+                              .push(self.runtime.resolve_symbol("__toESM").into());
+                          }
                           stmt_info
                             .referenced_symbols
                             .push(self.runtime.resolve_symbol("__reExport").into());
@@ -326,9 +432,19 @@ impl<'a> LinkStage<'a> {
                             .referenced_symbols
                             .push(importee_linking_info.wrapper_ref.unwrap().into());
                           // dbg!(&importee_linking_info.wrapper_ref);
-                          stmt_info
-                            .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__toESM").into());
+                          let uses_default_export = rec.meta.intersects(
+                            ImportRecordMeta::CONTAINS_IMPORT_DEFAULT
+                              | ImportRecordMeta::CONTAINS_IMPORT_STAR,
+                          );
+                          if importee.requires_to_esm(
+                            self.options.interop,
+                            importer.def_format,
+                            uses_default_export,
+                          ) {
+                            stmt_info
+                              .referenced_symbols
+                              .push(self.runtime.resolve_symbol("__toESM").into());
+                          }
                           declared_symbol_for_stmt_pairs.push((stmt_idx, rec.namespace_ref));
                           symbols.lock().unwrap().get_mut(rec.namespace_ref).name =
                             format!("import_{}", &importee.repr_name).into();
@@ -375,7 +491,7 @@ impl<'a> LinkStage<'a> {
                     }
                   },
                   ImportKind::DynamicImport => {
-                    if matches!(self.options.format, OutputFormat::Iife) {
+                    if inlines_dynamic_imports {
                       match importee_linking_info.wrap_kind {
                         WrapKind::None => {}
                         WrapKind::Cjs => {
@@ -383,9 +499,15 @@ impl<'a> LinkStage<'a> {
                           stmt_info
                             .referenced_symbols
                             .push(importee_linking_info.wrapper_ref.unwrap().into());
-                          stmt_info
-                            .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__toESM").into());
+                          if importee.requires_to_esm(
+                            self.options.interop,
+                            importer.def_format,
+                            true,
+                          ) {
+                            stmt_info
+                              .referenced_symbols
+                              .push(self.runtime.resolve_symbol("__toESM").into());
+                          }
                         }
                         WrapKind::Esm => {
                           // `(init_foo(), foo_exports)`
@@ -397,6 +519,7 @@ impl<'a> LinkStage<'a> {
                       }
                     }
                   }
+                  ImportKind::ResolveOnly => {}
                 }
               }
             }
@@ -410,6 +533,31 @@ impl<'a> LinkStage<'a> {
   }
 
   fn create_exports_for_ecma_modules(&mut self) {
+    // Modules whose namespace object has at least one getter, which is defined with the `__export` helper
+    let has_live_namespace_exports = self
+      .metas
+      .iter()
+      .map(|meta| {
+        meta.canonical_exports().any(|(_, export)| {
+          is_live_namespace_export(
+            self.options,
+            &self.module_table.modules,
+            &self.symbols,
+            export.symbol_ref,
+          )
+        })
+      })
+      .collect::<IndexVec<ModuleIdx, _>>();
+    let has_esm_output = self.has_output_format(|format| matches!(format, OutputFormat::Esm));
+    let re_exports_externals_with_helper = self.has_output_format(|format| match format {
+      OutputFormat::Esm
+      | OutputFormat::Cjs
+      | OutputFormat::Iife
+      | OutputFormat::Umd
+      | OutputFormat::Amd => true,
+      OutputFormat::System => false,
+      OutputFormat::App => unreachable!(),
+    });
     self.module_table.modules.iter_mut().filter_map(|m| m.as_ecma_mut()).for_each(|ecma_module| {
       let linking_info = &mut self.metas[ecma_module.idx];
 
@@ -426,6 +574,7 @@ impl<'a> LinkStage<'a> {
           declared_symbols: vec![*symbol_ref],
           referenced_symbols: vec![],
           side_effect: false,
+          is_unreachable: false,
           is_included: false,
           import_records: Vec::new(),
           debug_label: None,
@@ -443,22 +592,24 @@ impl<'a> LinkStage<'a> {
         let meta = &self.metas[ecma_module.idx];
         let mut referenced_symbols = vec![];
         let mut declared_symbols = vec![];
-        if !meta.is_canonical_exports_empty() {
+        if has_live_namespace_exports[ecma_module.idx] {
           referenced_symbols.push(self.runtime.resolve_symbol("__export").into());
+        }
+        if !meta.is_canonical_exports_empty() {
           referenced_symbols
             .extend(meta.canonical_exports().map(|(_, export)| export.symbol_ref.into()));
         }
         if !meta.star_exports_from_external_modules.is_empty() {
-          referenced_symbols.push(self.runtime.resolve_symbol("__reExport").into());
-          match self.options.format {
-            OutputFormat::Esm => {
-              meta.star_exports_from_external_modules.iter().copied().for_each(|rec_idx| {
-                referenced_symbols.push(ecma_module.import_records[rec_idx].namespace_ref.into());
-                declared_symbols.push(ecma_module.import_records[rec_idx].namespace_ref);
-              });
-            }
-            OutputFormat::Cjs | OutputFormat::Iife => {}
-            OutputFormat::App => unreachable!(),
+          // `System.register` chunks re-export external modules in the setters of them.
+          if re_exports_externals_with_helper {
+            referenced_symbols.push(self.runtime.resolve_symbol("__reExport").into());
+          }
+          // ESM chunks import the namespaces of the external modules to re-export them.
+          if has_esm_output {
+            meta.star_exports_from_external_modules.iter().copied().for_each(|rec_idx| {
+              referenced_symbols.push(ecma_module.import_records[rec_idx].namespace_ref.into());
+              declared_symbols.push(ecma_module.import_records[rec_idx].namespace_ref);
+            });
           }
         };
         // Create a StmtInfo to represent the statement that declares and constructs the Module Namespace Object.
@@ -469,6 +620,7 @@ impl<'a> LinkStage<'a> {
           declared_symbols,
           referenced_symbols,
           side_effect: false,
+          is_unreachable: false,
           is_included: false,
           import_records: Vec::new(),
           debug_label: None,
@@ -479,6 +631,14 @@ impl<'a> LinkStage<'a> {
   }
 }
 
+/// IIFE, UMD, AMD and SystemJS formats will inline dynamic imported modules
+fn format_inlines_dynamic_imports(format: &OutputFormat) -> bool {
+  matches!(
+    format,
+    OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd | OutputFormat::System
+  )
+}
+
 pub fn init_entry_point_stmt_info(meta: &mut LinkingMetadata) {
   let mut referenced_symbols = vec![];
 
@@ -1,6 +1,6 @@
 use std::iter;
 
-use rolldown_common::{Module, ModuleIdx};
+use rolldown_common::{ImportKind, IndexModules, Module, ModuleIdx};
 use rolldown_error::BuildDiagnostic;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -12,114 +12,373 @@ enum Status {
   WaitForExit(ModuleIdx),
 }
 
-impl<'a> LinkStage<'a> {
-  /// Some notes about the module execution order:
-  /// - We assume user-defined entries are always executed orderly.
-  /// - Async entries is sorted by `Module#debug_id` of entry module to ensure deterministic output.
-  /// - `require(...)` is treated as implicit static `import`, which required modules are executed before the module that requires them.
-  /// - Since import statements are hoisted, `require(...)` is always placed after static `import` statements.
-  /// - Order of `require(...)` is determined by who shows up first while scanning ast. For such code
-  ///
-  /// ```js
-  /// () => require('b')
-  /// require('c')
-  /// import 'a';
-  /// ```
-  ///
-  /// The execution order is `a -> b -> c`.
-  /// - We only ensure execution order is relative correct, which means imported/required modules are executed before the module that imports/require them.
-  #[tracing::instrument(level = "debug", skip_all)]
-  pub fn sort_modules(&mut self) {
-    // The runtime module should always be the first module to be executed
-    let mut execution_stack = self
-      .entries
-      .iter()
-      .rev()
-      .map(|entry| Status::ToBeExecuted(entry.id))
-      .chain(iter::once(Status::ToBeExecuted(self.runtime.id())))
-      .collect::<Vec<_>>();
+/// A chain of modules importing each other. The last module is the one that closes the loop.
+pub type ModuleCycle = Box<[ModuleIdx]>;
+
+/// Some notes about the module execution order:
+/// - We assume user-defined entries are always executed orderly. Each entry is executed with all of its static
+///   dependencies before the next one starts, so modules only reachable from a later entry never execute before an
+///   earlier entry. Dependencies shared between entries are executed with the first entry that reaches them.
+/// - Async entries is sorted by `Module#debug_id` of entry module to ensure deterministic output.
+/// - `require(...)` is treated as implicit static `import`, which required modules are executed before the module that requires them.
+/// - Since import statements are hoisted, `require(...)` is always placed after static `import` statements.
+/// - Order of `require(...)` is determined by who shows up first while scanning ast. For such code
+///
+/// ```js
+/// () => require('b')
+/// require('c')
+/// import 'a';
+/// ```
+///
+/// The execution order is `a -> b -> c`.
+/// - We only ensure execution order is relative correct, which means imported/required modules are executed before the module that imports/require them.
+///
+/// Returns every executed module, including external ones, in execution order, along with the circular
+/// dependencies found along the way.
+pub fn compute_execution_order(
+  modules: &IndexModules,
+  entries: &[ModuleIdx],
+  runtime: ModuleIdx,
+) -> (Vec<ModuleIdx>, Vec<ModuleCycle>) {
+  compute_execution_order_with_pinned_cycles(modules, entries, runtime, &[])
+}
 
-    let mut stack_indexes_of_executing_id = FxHashMap::default();
-    let mut executed_ids = FxHashSet::default();
-    executed_ids.shrink_to(self.module_table.modules.len());
-
-    let mut sorted_modules = Vec::with_capacity(self.module_table.modules.len());
-    let mut next_exec_order = 0;
-    let mut circular_dependencies = FxHashSet::default();
-    while let Some(status) = execution_stack.pop() {
-      match status {
-        Status::ToBeExecuted(id) => {
-          if executed_ids.contains(&id) {
-            if let Some(index) = stack_indexes_of_executing_id.get(&id).copied() {
-              // Executing
-              let cycles = execution_stack[index..]
-                .iter()
-                .filter_map(|action| match action {
-                  // Only modules with `Status::WaitForExit` are on the execution chain
-                  Status::ToBeExecuted(_) => None,
-                  Status::WaitForExit(id) => Some(*id),
-                })
-                .chain(iter::once(id))
-                .collect::<Box<[_]>>();
-              circular_dependencies.insert(cycles);
-            } else {
-              // It's already executed in other import chain, no need to execute again
+/// Same as [compute_execution_order], but each of `pinned_cycles` is executed as one group in the given order.
+/// The group is entered as soon as any of its modules is reached, and it's executed once the static dependencies of
+/// all of its modules that are outside the group have been executed.
+fn compute_execution_order_with_pinned_cycles(
+  modules: &IndexModules,
+  entries: &[ModuleIdx],
+  runtime: ModuleIdx,
+  pinned_cycles: &[Vec<ModuleIdx>],
+) -> (Vec<ModuleIdx>, Vec<ModuleCycle>) {
+  let mut pinned_cycle_of_module = FxHashMap::default();
+  for (index, cycle) in pinned_cycles.iter().enumerate() {
+    for id in cycle {
+      pinned_cycle_of_module.entry(*id).or_insert(index);
+    }
+  }
+
+  // The runtime module should always be the first module to be executed
+  let mut execution_stack = entries
+    .iter()
+    .rev()
+    .map(|id| Status::ToBeExecuted(*id))
+    .chain(iter::once(Status::ToBeExecuted(runtime)))
+    .collect::<Vec<_>>();
+
+  let mut stack_indexes_of_executing_id = FxHashMap::default();
+  let mut executed_ids = FxHashSet::default();
+  executed_ids.shrink_to(modules.len());
+
+  let mut sorted_modules = Vec::with_capacity(modules.len());
+  let mut seen_cycles = FxHashSet::default();
+  let mut cycles = vec![];
+  while let Some(status) = execution_stack.pop() {
+    match status {
+      Status::ToBeExecuted(id) => {
+        if executed_ids.contains(&id) {
+          if let Some(index) = stack_indexes_of_executing_id.get(&id).copied() {
+            // Executing
+            let cycle = execution_stack[index..]
+              .iter()
+              .filter_map(|action| match action {
+                // Only modules with `Status::WaitForExit` are on the execution chain
+                Status::ToBeExecuted(_) => None,
+                Status::WaitForExit(id) => Some(*id),
+              })
+              .chain(iter::once(id))
+              .collect::<ModuleCycle>();
+            if seen_cycles.insert(cycle.clone()) {
+              cycles.push(cycle);
             }
           } else {
-            executed_ids.insert(id);
-            execution_stack.push(Status::WaitForExit(id));
+            // It's already executed in other import chain, no need to execute again
+          }
+        } else {
+          let group = match pinned_cycle_of_module.get(&id) {
+            Some(index) => pinned_cycles[*index].as_slice(),
+            None => std::slice::from_ref(&id),
+          };
+          // Modules of the group exit in the given order, after all of their dependencies outside the group.
+          for id in group.iter().rev() {
+            executed_ids.insert(*id);
+            execution_stack.push(Status::WaitForExit(*id));
             debug_assert!(
-              !stack_indexes_of_executing_id.contains_key(&id),
+              !stack_indexes_of_executing_id.contains_key(id),
               "A module should not be executing the same module twice"
             );
-            stack_indexes_of_executing_id.insert(id, execution_stack.len() - 1);
+            stack_indexes_of_executing_id.insert(*id, execution_stack.len() - 1);
+          }
+
+          execution_stack.extend(
+            group
+              .iter()
+              .flat_map(|id| modules[*id].import_records().iter())
+              .filter(|rec| rec.kind.is_static() && !group.contains(&rec.resolved_module))
+              .map(|rec| rec.resolved_module)
+              .collect::<Vec<_>>()
+              .into_iter()
+              .rev()
+              .map(Status::ToBeExecuted),
+          );
+        }
+      }
+      Status::WaitForExit(id) => {
+        sorted_modules.push(id);
+        debug_assert!(stack_indexes_of_executing_id.contains_key(&id));
+        stack_indexes_of_executing_id.remove(&id);
+      }
+    }
+  }
+
+  (sorted_modules, cycles)
+}
+
+/// Returns the elementary cycles of the modules reachable from `entries` that are only closed through at least one
+/// dynamic `import(...)`. Such cycles don't affect the execution order, so [compute_execution_order] doesn't report
+/// them. Each cycle starts and ends with its module of the smallest index.
+pub fn compute_dynamic_import_cycles(
+  modules: &IndexModules,
+  entries: &[ModuleIdx],
+) -> Vec<ModuleCycle> {
+  let graph = ImportGraph::new(modules, entries);
+  let mut cycles = vec![];
+  for component in graph.strongly_connected_components() {
+    graph.collect_dynamic_import_cycles(&component, &mut cycles);
+  }
+  cycles
+}
+
+/// The import records of the modules reachable from some entries, without records that don't import the module like
+/// `ImportKind::ResolveOnly`. Multiple records of a module importing the same module are merged into one edge, which
+/// is only dynamic if all of the records are dynamic imports.
+struct ImportGraph {
+  /// Modules in the order they are reached from the entries.
+  modules: Vec<ModuleIdx>,
+  /// The importees of each module in source order, and whether they are only imported dynamically.
+  edges: FxHashMap<ModuleIdx, Vec<(ModuleIdx, bool)>>,
+}
+
+impl ImportGraph {
+  fn new(modules: &IndexModules, entries: &[ModuleIdx]) -> Self {
+    let mut reached = FxHashSet::default();
+    let mut graph = Self {
+      modules: entries.iter().copied().filter(|id| reached.insert(*id)).collect(),
+      edges: FxHashMap::default(),
+    };
+    let mut next = 0;
+    while let Some(&id) = graph.modules.get(next) {
+      next += 1;
+      let mut edges: Vec<(ModuleIdx, bool)> = vec![];
+      for rec in modules[id].import_records() {
+        if matches!(rec.kind, ImportKind::ResolveOnly) {
+          continue;
+        }
+        let is_dynamic = matches!(rec.kind, ImportKind::DynamicImport);
+        let importee = rec.resolved_module;
+        if let Some(edge) = edges.iter_mut().find(|(id, _)| *id == importee) {
+          edge.1 &= is_dynamic;
+        } else {
+          edges.push((importee, is_dynamic));
+        }
+        if reached.insert(importee) {
+          graph.modules.push(importee);
+        }
+      }
+      graph.edges.insert(id, edges);
+    }
+    graph
+  }
+
+  /// Returns the strongly connected components found by Tarjan's algorithm, each sorted by module index, ordered by
+  /// their smallest module.
+  fn strongly_connected_components(&self) -> Vec<Vec<ModuleIdx>> {
+    // The order each module is visited in and the smallest order of the modules reachable from it in the same walk.
+    let mut visit_orders: FxHashMap<ModuleIdx, (usize, usize)> = FxHashMap::default();
+    let mut stack = vec![];
+    let mut on_stack = FxHashSet::default();
+    let mut components = vec![];
+    for &root in &self.modules {
+      if visit_orders.contains_key(&root) {
+        continue;
+      }
+      // Modules being visited, each with the index of its next edge to follow.
+      let mut walk = vec![(root, 0)];
+      let order = visit_orders.len();
+      visit_orders.insert(root, (order, order));
+      stack.push(root);
+      on_stack.insert(root);
+      while let Some(&(id, next_edge)) = walk.last() {
+        if let Some(&(importee, _)) = self.edges[&id].get(next_edge) {
+          walk.last_mut().unwrap().1 += 1;
+          if let Some(&(importee_order, _)) = visit_orders.get(&importee) {
+            if on_stack.contains(&importee) {
+              let low_link = &mut visit_orders.get_mut(&id).unwrap().1;
+              *low_link = (*low_link).min(importee_order);
+            }
+          } else {
+            let order = visit_orders.len();
+            visit_orders.insert(importee, (order, order));
+            stack.push(importee);
+            on_stack.insert(importee);
+            walk.push((importee, 0));
+          }
+          continue;
+        }
+
+        walk.pop();
+        let (order, low_link) = visit_orders[&id];
+        if let Some(&(parent, _)) = walk.last() {
+          let parent_low_link = &mut visit_orders.get_mut(&parent).unwrap().1;
+          *parent_low_link = (*parent_low_link).min(low_link);
+        }
+        if order == low_link {
+          let mut component = vec![];
+          while let Some(member) = stack.pop() {
+            on_stack.remove(&member);
+            component.push(member);
+            if member == id {
+              break;
+            }
+          }
+          component.sort_unstable();
+          components.push(component);
+        }
+      }
+    }
+    components.sort_unstable_by_key(|component| component[0]);
+    components
+  }
 
-            if let Module::Ecma(module) = &self.module_table.modules[id] {
-              execution_stack.extend(
-                module
-                  .import_records
+  /// Collects the elementary cycles within `component` that are closed through a dynamic import, using Johnson's
+  /// algorithm. Cycles through a module are searched among the modules of larger indices only, so each cycle is
+  /// found once, from its smallest module.
+  fn collect_dynamic_import_cycles(&self, component: &[ModuleIdx], cycles: &mut Vec<ModuleCycle>) {
+    let members = component.iter().copied().collect::<FxHashSet<_>>();
+    for &start in component {
+      let is_searched = |id: ModuleIdx| id > start && members.contains(&id);
+      // Modules that can't lead back to `start` through the modules off the current path, until one of the modules
+      // they are blocked by is unblocked.
+      let mut blocked = FxHashSet::from_iter([start]);
+      let mut blocked_by: FxHashMap<ModuleIdx, Vec<ModuleIdx>> = FxHashMap::default();
+      // Modules on the current path, each with the index of its next edge to follow, whether the path reached it
+      // through a dynamic import and whether a cycle was found through it.
+      let mut path = vec![(start, 0, false, false)];
+      while let Some(&(id, next_edge, _, found_cycle)) = path.last() {
+        if let Some(&(importee, is_dynamic)) = self.edges[&id].get(next_edge) {
+          path.last_mut().unwrap().1 += 1;
+          if importee == start {
+            path.last_mut().unwrap().3 = true;
+            if is_dynamic || path.iter().any(|(_, _, is_dynamic, _)| *is_dynamic) {
+              cycles.push(
+                path
                   .iter()
-                  .filter(|rec| rec.kind.is_static())
-                  .map(|rec| rec.resolved_module)
-                  .rev()
-                  .map(Status::ToBeExecuted),
+                  .map(|(id, _, _, _)| *id)
+                  .chain(iter::once(start))
+                  .collect::<ModuleCycle>(),
               );
             }
+          } else if is_searched(importee) && blocked.insert(importee) {
+            path.push((importee, 0, is_dynamic, false));
           }
+          continue;
         }
-        Status::WaitForExit(id) => {
-          executed_ids.insert(id);
-          match &mut self.module_table.modules[id] {
-            Module::Ecma(module) => {
-              debug_assert!(module.exec_order == u32::MAX);
-              module.exec_order = next_exec_order;
-              sorted_modules.push(id);
+
+        path.pop();
+        if found_cycle {
+          let mut to_unblock = vec![id];
+          while let Some(id) = to_unblock.pop() {
+            if blocked.remove(&id) {
+              to_unblock.extend(blocked_by.remove(&id).unwrap_or_default());
             }
-            Module::External(module) => {
-              debug_assert!(module.exec_order == u32::MAX);
-              module.exec_order = next_exec_order;
+          }
+          if let Some(parent) = path.last_mut() {
+            parent.3 = true;
+          }
+        } else {
+          for &(importee, _) in &self.edges[&id] {
+            if is_searched(importee) {
+              let blocking = blocked_by.entry(importee).or_default();
+              if !blocking.contains(&id) {
+                blocking.push(id);
+              }
             }
           }
-          next_exec_order += 1;
-          debug_assert!(stack_indexes_of_executing_id.contains_key(&id));
-          stack_indexes_of_executing_id.remove(&id);
         }
       }
     }
+  }
+}
 
-    if !circular_dependencies.is_empty() {
-      let cycles = circular_dependencies.into_iter().collect::<Vec<_>>();
-      for cycle in cycles {
-        let paths = cycle
+impl<'a> LinkStage<'a> {
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn sort_modules(&mut self) {
+    let entries = self.entries.iter().map(|entry| entry.id).collect::<Vec<_>>();
+    let (mut execution_order, cycles) =
+      compute_execution_order(&self.module_table.modules, &entries, self.runtime.id());
+
+    let mut cycle_paths = cycles
+      .iter()
+      .map(|cycle| {
+        cycle
           .iter()
           .copied()
           .filter_map(|id| self.module_table.modules[id].as_ecma())
           .map(|module| module.id.to_string())
-          .collect::<Vec<_>>();
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    // Report cycles in a stable order, regardless of the order of the module graph traversal.
+    cycle_paths.sort_unstable();
+    let mut pinned_cycles = vec![];
+    for paths in cycle_paths {
+      if let Some(preferred_order) = self.find_configured_cycle_order(&paths) {
+        pinned_cycles.push(preferred_order);
+        continue;
+      }
+      if self.options.fail_on_circular_dependency {
+        self.errors.push(BuildDiagnostic::circular_dependency(paths));
+      } else {
         self.warnings.push(BuildDiagnostic::circular_dependency(paths).with_severity_warning());
       }
     }
 
+    if !pinned_cycles.is_empty() {
+      // Cycles are found while sorting, so the modules are sorted again now that the pinned ones are known.
+      (execution_order, _) = compute_execution_order_with_pinned_cycles(
+        &self.module_table.modules,
+        &entries,
+        self.runtime.id(),
+        &pinned_cycles,
+      );
+    }
+
+    if self.options.experimental.is_dynamic_import_cycle_detection_enabled() {
+      for cycle in compute_dynamic_import_cycles(&self.module_table.modules, &entries) {
+        let paths = cycle
+          .iter()
+          .map(|id| self.module_table.modules[*id].id().to_string())
+          .collect::<Vec<_>>();
+        self.warnings.push(BuildDiagnostic::dynamic_import_cycle(paths).with_severity_info());
+      }
+    }
+
+    let mut sorted_modules = Vec::with_capacity(execution_order.len());
+    for (exec_order, id) in (0u32..).zip(execution_order) {
+      match &mut self.module_table.modules[id] {
+        Module::Ecma(module) => {
+          debug_assert!(module.exec_order == u32::MAX);
+          module.exec_order = exec_order;
+          sorted_modules.push(id);
+        }
+        Module::External(module) => {
+          debug_assert!(module.exec_order == u32::MAX);
+          module.exec_order = exec_order;
+        }
+      }
+    }
+
     self.sorted_modules = sorted_modules;
     debug_assert_eq!(
       self.sorted_modules.first().copied(),
@@ -127,4 +386,187 @@ impl<'a> LinkStage<'a> {
       "runtime module should always be the first module in the sorted modules"
     );
   }
+
+  /// Returns the modules of the cycle in the order configured by `cycle_order`, if the cycle consists of exactly
+  /// the modules of one of its items.
+  fn find_configured_cycle_order(&self, cycle_paths: &[String]) -> Option<Vec<ModuleIdx>> {
+    let cycle_paths = cycle_paths.iter().map(String::as_str).collect::<FxHashSet<_>>();
+    let preferred_order = self.options.cycle_order.iter().find(|ids| {
+      ids.len() == cycle_paths.len() && ids.iter().all(|id| cycle_paths.contains(id.as_str()))
+    })?;
+    preferred_order
+      .iter()
+      .map(|id| self.module_table.modules.iter().find(|m| m.id() == id.as_str()).map(Module::idx))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc::{
+    index::{Idx, IndexVec},
+    semantic::SymbolId,
+  };
+  use rolldown_common::{
+    side_effects::DeterminedSideEffects, ExternalModule, ImportKind, IndexModules, Module,
+    ModuleIdx, RawImportRecord,
+  };
+
+  use super::{
+    compute_dynamic_import_cycles, compute_execution_order,
+    compute_execution_order_with_pinned_cycles,
+  };
+
+  /// Builds a module table where `graph[i]` lists the `(kind, importee)` pairs of module `i` in source order.
+  fn modules(graph: &[&[(ImportKind, usize)]]) -> IndexModules {
+    graph
+      .iter()
+      .enumerate()
+      .map(|(idx, imports)| {
+        let idx = ModuleIdx::from_usize(idx);
+        let mut module = ExternalModule::new(
+          idx,
+          format!("{idx:?}").into(),
+          DeterminedSideEffects::Analyzed(true),
+        );
+        module.import_records = imports
+          .iter()
+          .map(|(kind, importee)| {
+            RawImportRecord::new("".into(), *kind, (idx, SymbolId::from_usize(0)).into(), 0)
+              .into_import_record(ModuleIdx::from_usize(*importee))
+          })
+          .collect::<IndexVec<_, _>>();
+        Module::external(module)
+      })
+      .collect()
+  }
+
+  fn ids(ids: &[usize]) -> Vec<ModuleIdx> {
+    ids.iter().copied().map(ModuleIdx::from_usize).collect()
+  }
+
+  #[test]
+  fn runtime_and_dependencies_execute_first() {
+    use ImportKind::Import;
+    // 0: runtime, 1: entry -> [2, 3], 2 -> [3], 3: leaf
+    let modules = modules(&[&[], &[(Import, 2), (Import, 3)], &[(Import, 3)], &[]]);
+    let (order, cycles) = compute_execution_order(&modules, &ids(&[1]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 3, 2, 1]));
+    assert!(cycles.is_empty());
+  }
+
+  #[test]
+  fn entries_execute_in_order_and_skip_dynamic_imports() {
+    use ImportKind::{DynamicImport, Import, Require};
+    // 0: runtime, 1: entry -> [import(4), require(3)], 2: entry -> [3], 3: leaf, 4: only dynamically imported
+    let modules = modules(&[&[], &[(DynamicImport, 4), (Require, 3)], &[(Import, 3)], &[], &[]]);
+    let (order, _) = compute_execution_order(&modules, &ids(&[1, 2]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 3, 1, 2]));
+
+    let (order, _) = compute_execution_order(&modules, &ids(&[2, 1, 4]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 3, 2, 1, 4]));
+  }
+
+  #[test]
+  fn later_entries_never_execute_before_earlier_entries() {
+    use ImportKind::Import;
+    // 0: runtime, 1: entry -> [4], 2: entry -> [5, 4], 3: entry -> [4, 6], 4: shared leaf, 5 and 6: leaves
+    let modules = modules(&[
+      &[],
+      &[(Import, 4)],
+      &[(Import, 5), (Import, 4)],
+      &[(Import, 4), (Import, 6)],
+      &[],
+      &[],
+      &[],
+    ]);
+    let (order, _) = compute_execution_order(&modules, &ids(&[1, 2, 3]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 4, 1, 5, 2, 6, 3]));
+
+    let (order, _) = compute_execution_order(&modules, &ids(&[3, 2, 1]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 4, 6, 3, 5, 2, 1]));
+  }
+
+  #[test]
+  fn resolve_only_records_are_not_executed() {
+    use ImportKind::{Import, ResolveOnly};
+    // 0: runtime, 1: entry -> [resolve(3), 2], 2: leaf, 3: only resolved
+    let modules = modules(&[&[], &[(ResolveOnly, 3), (Import, 2)], &[], &[]]);
+    let (order, cycles) = compute_execution_order(&modules, &ids(&[1]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 2, 1]));
+    assert!(cycles.is_empty());
+  }
+
+  #[test]
+  fn report_each_cycle_once() {
+    use ImportKind::Import;
+    // 0: runtime, 1: entry -> [2, 3], 2 -> [3], 3 -> [2]
+    let modules = modules(&[&[], &[(Import, 2), (Import, 3)], &[(Import, 3)], &[(Import, 2)]]);
+    let (order, cycles) = compute_execution_order(&modules, &ids(&[1]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 3, 2, 1]));
+    assert_eq!(cycles.iter().map(|cycle| cycle.to_vec()).collect::<Vec<_>>(), [ids(&[2, 3, 2])]);
+  }
+
+  #[test]
+  fn pinned_cycles_execute_after_their_dependencies() {
+    use ImportKind::Import;
+    // 0: runtime, 1: entry -> [2, 3], 2 -> [1], 3: leaf only imported by 1
+    let modules = modules(&[&[], &[(Import, 2), (Import, 3)], &[(Import, 1)], &[]]);
+    let (order, _) = compute_execution_order(&modules, &ids(&[1]), ModuleIdx::from_usize(0));
+    assert_eq!(order, ids(&[0, 2, 3, 1]));
+
+    // Executing 1 before 2 must not execute it before its dependency 3 outside the cycle.
+    let (order, _) = compute_execution_order_with_pinned_cycles(
+      &modules,
+      &ids(&[1]),
+      ModuleIdx::from_usize(0),
+      &[ids(&[1, 2])],
+    );
+    assert_eq!(order, ids(&[0, 3, 1, 2]));
+  }
+
+  #[test]
+  fn report_cycles_closed_through_dynamic_imports() {
+    use ImportKind::{DynamicImport, Import};
+    // 0: runtime, 1: entry -> [2, 3], 2 -> [import(1)], 3 -> [4], 4 -> [3]
+    let modules = modules(&[
+      &[],
+      &[(Import, 2), (Import, 3)],
+      &[(DynamicImport, 1)],
+      &[(Import, 4)],
+      &[(Import, 3)],
+    ]);
+    let (_, cycles) = compute_execution_order(&modules, &ids(&[1]), ModuleIdx::from_usize(0));
+    assert_eq!(cycles.iter().map(|cycle| cycle.to_vec()).collect::<Vec<_>>(), [ids(&[3, 4, 3])]);
+
+    let cycles = compute_dynamic_import_cycles(&modules, &ids(&[1]));
+    assert_eq!(cycles.iter().map(|cycle| cycle.to_vec()).collect::<Vec<_>>(), [ids(&[1, 2, 1])]);
+  }
+
+  #[test]
+  fn report_dynamic_import_cycles_through_shared_modules() {
+    use ImportKind::{DynamicImport, Import};
+    // 0: runtime, 1: entry -> [2], 2 -> [3, 4], 3 -> [5], 4 -> [5], 5 -> [import(2)]
+    let modules = modules(&[
+      &[],
+      &[(Import, 2)],
+      &[(Import, 3), (Import, 4)],
+      &[(Import, 5)],
+      &[(Import, 5)],
+      &[(DynamicImport, 2)],
+    ]);
+    let cycles = compute_dynamic_import_cycles(&modules, &ids(&[1]));
+    assert_eq!(
+      cycles.iter().map(|cycle| cycle.to_vec()).collect::<Vec<_>>(),
+      [ids(&[2, 3, 5, 2]), ids(&[2, 4, 5, 2])]
+    );
+  }
+
+  #[test]
+  fn resolve_only_records_dont_close_dynamic_import_cycles() {
+    use ImportKind::{DynamicImport, ResolveOnly};
+    // 0: runtime, 1: entry -> [import(2)], 2 -> [resolve(1)]
+    let modules = modules(&[&[], &[(DynamicImport, 2)], &[(ResolveOnly, 1)]]);
+    assert!(compute_dynamic_import_cycles(&modules, &ids(&[1])).is_empty());
+  }
 }
@@ -3,8 +3,8 @@ use crate::types::symbols::Symbols;
 use oxc::index::IndexVec;
 use rolldown_common::side_effects::DeterminedSideEffects;
 use rolldown_common::{
-  EcmaModule, IndexModules, Module, ModuleIdx, ModuleType, StmtInfoIdx, SymbolOrMemberExprRef,
-  SymbolRef,
+  EcmaModule, ImportKind, IndexModules, Module, ModuleIdx, ModuleType, StmtInfoIdx,
+  SymbolOrMemberExprRef, SymbolRef,
 };
 use rolldown_utils::rayon::{ParallelBridge, ParallelIterator};
 use rustc_hash::FxHashSet;
@@ -229,12 +229,13 @@ impl LinkStage<'_> {
         DeterminedSideEffects::Analyzed(_) => match module {
           Module::Ecma(module) => {
             DeterminedSideEffects::Analyzed(module.import_records.iter().any(|import_record| {
-              determine_side_effects_for_module(
-                cache,
-                import_record.resolved_module,
-                normal_modules,
-              )
-              .has_side_effects()
+              !matches!(import_record.kind, ImportKind::ResolveOnly)
+                && determine_side_effects_for_module(
+                  cache,
+                  import_record.resolved_module,
+                  normal_modules,
+                )
+                .has_side_effects()
             }))
           }
           Module::External(module) => module.side_effects,
@@ -0,0 +1,91 @@
+use rolldown_common::{ImportKind, ModuleIdx, ModuleType};
+use rolldown_error::BuildDiagnostic;
+use rustc_hash::FxHashMap;
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// A module is only loaded once, so `import data from './data.json' with { type: 'json' }` and
+  /// `export { data } from './data.json'` can't both be honored. Warn when the attributes used for the same module
+  /// differ, whether it's imported or re-exported.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn validate_import_attributes(&mut self) {
+    let mut first_seen: FxHashMap<ModuleIdx, (ModuleIdx, Vec<(String, String)>)> =
+      FxHashMap::default();
+
+    for importer_idx in self.sorted_modules.iter().copied() {
+      let Some(importer) = self.module_table.modules[importer_idx].as_ecma() else {
+        continue;
+      };
+      for rec in &importer.import_records {
+        if !matches!(rec.kind, ImportKind::Import | ImportKind::DynamicImport) {
+          continue;
+        }
+        let mut attributes = rec
+          .attributes
+          .iter()
+          .map(|(key, value)| (key.to_string(), value.to_string()))
+          .collect::<Vec<_>>();
+        attributes.sort_unstable();
+
+        match first_seen.get(&rec.resolved_module) {
+          None => {
+            first_seen.insert(rec.resolved_module, (importer_idx, attributes));
+          }
+          Some((existing_importer, existing_attributes)) => {
+            if *existing_attributes != attributes {
+              self.warnings.push(
+                BuildDiagnostic::inconsistent_import_attributes(
+                  self.module_table.modules[rec.resolved_module].id().to_string(),
+                  importer.id.to_string(),
+                  attributes,
+                  self.module_table.modules[*existing_importer].id().to_string(),
+                  existing_attributes.clone(),
+                )
+                .with_severity_warning(),
+              );
+            }
+          }
+        }
+      }
+    }
+  }
+
+  /// Rolldown itself only understands `type: 'json'` on JSON modules. Other attributes are only honored if a plugin
+  /// loaded or transformed the imported module, so warn about the ones that are silently ignored.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn warn_unhandled_import_attributes(&mut self) {
+    for importer_idx in self.sorted_modules.iter().copied() {
+      let Some(importer) = self.module_table.modules[importer_idx].as_ecma() else {
+        continue;
+      };
+      for rec in &importer.import_records {
+        if !matches!(rec.kind, ImportKind::Import | ImportKind::DynamicImport) {
+          continue;
+        }
+        let Some(importee) = self.module_table.modules[rec.resolved_module].as_ecma() else {
+          continue;
+        };
+        if importee.is_handled_by_plugin {
+          continue;
+        }
+        for (key, value) in &rec.attributes {
+          let is_native_json = key.as_str() == "type"
+            && value.as_str() == "json"
+            && matches!(importee.module_type, ModuleType::Json);
+          if !is_native_json {
+            self.warnings.push(
+              BuildDiagnostic::unhandled_import_attribute(
+                importee.id.to_string(),
+                importer.id.to_string(),
+                key.to_string(),
+                value.to_string(),
+              )
+              .with_severity_warning(),
+            );
+          }
+        }
+      }
+    }
+  }
+}
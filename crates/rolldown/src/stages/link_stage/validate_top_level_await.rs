@@ -0,0 +1,36 @@
+use rolldown_common::OutputFormat;
+use rolldown_error::BuildDiagnostic;
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// CommonJS, IIFE, UMD and AMD chunks are evaluated synchronously, so there is no way to wait for a module using
+  /// top-level await before running the modules depending on it.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn validate_top_level_await(&mut self) {
+    let Some(format) = self.output_formats.iter().find_map(|format| match format {
+      OutputFormat::Cjs => Some("cjs"),
+      OutputFormat::Iife => Some("iife"),
+      OutputFormat::Umd => Some("umd"),
+      OutputFormat::Amd => Some("amd"),
+      // `System.register` chunks run the modules in an async `execute` function instead.
+      OutputFormat::Esm | OutputFormat::App | OutputFormat::System => None,
+    }) else {
+      return;
+    };
+
+    for module_idx in self.sorted_modules.iter().copied() {
+      let Some(module) = self.module_table.modules[module_idx].as_ecma() else {
+        continue;
+      };
+      if let Some(span) = module.top_level_await_span {
+        self.errors.push(BuildDiagnostic::unsupported_top_level_await(
+          module.id.to_string(),
+          module.source.clone(),
+          span,
+          format,
+        ));
+      }
+    }
+  }
+}
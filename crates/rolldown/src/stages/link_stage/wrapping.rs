@@ -157,6 +157,7 @@ pub fn create_wrapper(
         declared_symbols: vec![wrapper_ref],
         referenced_symbols: vec![runtime.resolve_symbol("__commonJSMin").into()],
         side_effect: false,
+        is_unreachable: false,
         is_included: false,
         import_records: Vec::new(),
         debug_label: None,
@@ -182,6 +183,7 @@ pub fn create_wrapper(
         declared_symbols: vec![wrapper_ref],
         referenced_symbols: vec![runtime.resolve_symbol("__esmMin").into()],
         side_effect: false,
+        is_unreachable: false,
         is_included: false,
         import_records: Vec::new(),
         debug_label: None,
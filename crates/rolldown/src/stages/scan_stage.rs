@@ -1,15 +1,23 @@
-use std::sync::Arc;
+use std::{
+  collections::BTreeMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use anyhow::Result;
 use arcstr::ArcStr;
 use futures::future::join_all;
-use rolldown_common::{EntryPoint, ImportKind, ModuleTable, ResolvedId};
+use rolldown_common::{
+  EntryPoint, ImportKind, Module, ModuleId, ModuleTable, ResolvedId, SharedFileEmitter,
+};
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_resolver::ResolveError;
+use rustc_hash::FxHashMap;
 
 use crate::{
+  ast_scanner::scan_cache::SharedScanCache,
   module_loader::{module_loader::ModuleLoaderOutput, ModuleLoader},
   runtime::RuntimeModuleBrief,
   type_alias::IndexEcmaAst,
@@ -23,6 +31,8 @@ pub struct ScanStage {
   plugin_driver: SharedPluginDriver,
   fs: OsFileSystem,
   resolver: SharedResolver,
+  file_emitter: SharedFileEmitter,
+  scan_cache: SharedScanCache,
 }
 
 #[derive(Debug)]
@@ -33,17 +43,29 @@ pub struct ScanStageOutput {
   pub symbols: Symbols,
   pub runtime: RuntimeModuleBrief,
   pub warnings: Vec<BuildDiagnostic>,
+  /// Warnings belonging to a specific module, such as reassigning a `const` variable. They are kept apart from
+  /// `warnings` so that tools like IDE integrations could show them next to the module.
+  pub module_warnings: FxHashMap<ModuleId, Vec<BuildDiagnostic>>,
   pub errors: Vec<BuildDiagnostic>,
 }
 
+impl ScanStageOutput {
+  /// Warnings reported while scanning the module `id`.
+  pub fn module_warnings(&self, id: &ModuleId) -> &[BuildDiagnostic] {
+    self.module_warnings.get(id).map(Vec::as_slice).unwrap_or_default()
+  }
+}
+
 impl ScanStage {
   pub fn new(
     options: SharedOptions,
     plugin_driver: SharedPluginDriver,
     fs: OsFileSystem,
     resolver: SharedResolver,
+    file_emitter: SharedFileEmitter,
+    scan_cache: SharedScanCache,
   ) -> Self {
-    Self { options, plugin_driver, fs, resolver }
+    Self { options, plugin_driver, fs, resolver, file_emitter, scan_cache }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -57,6 +79,8 @@ impl ScanStage {
       Arc::clone(&self.plugin_driver),
       self.fs,
       Arc::clone(&self.resolver),
+      Arc::clone(&self.file_emitter),
+      Arc::clone(&self.scan_cache),
     )?;
 
     let user_entries = match self.resolve_user_defined_entries().await? {
@@ -67,11 +91,11 @@ impl ScanStage {
     };
 
     let ModuleLoaderOutput {
-      module_table,
+      mut module_table,
       entry_points,
       symbols,
       runtime,
-      warnings,
+      module_warnings,
       index_ecma_ast,
     } = match module_loader.fetch_all_modules(user_entries).await? {
       Ok(output) => output,
@@ -80,17 +104,60 @@ impl ScanStage {
       }
     };
 
+    self.scan_cache.retain(module_table.modules.iter().map(Module::id));
+
+    for module in module_table.modules.iter_mut().filter_map(Module::as_ecma_mut) {
+      self.plugin_driver.rename_exports(&module.id, &mut module.named_exports).await?;
+    }
+
+    let warnings = if self.resolver.resolves_symlinks() {
+      vec![]
+    } else {
+      self.find_duplicate_module_instances(&module_table)
+    };
+
     Ok(Ok(ScanStageOutput {
       module_table,
       entry_points,
       symbols,
       runtime,
       warnings,
+      module_warnings,
       index_ecma_ast,
       errors: vec![],
     }))
   }
 
+  /// With `resolve.symlinks` disabled, a module reached through a symlink and through its real path ends up as two
+  /// modules, which breaks packages relying on having a single instance, such as React.
+  fn find_duplicate_module_instances(&self, module_table: &ModuleTable) -> Vec<BuildDiagnostic> {
+    fn canonicalize(fs: &impl FileSystem, path: &Path) -> Option<PathBuf> {
+      fs.canonicalize(path).ok()
+    }
+
+    let mut ids_by_real_path = BTreeMap::<PathBuf, Vec<String>>::new();
+    for module in module_table.modules.iter().filter_map(Module::as_ecma) {
+      let path = Path::new(module.id.as_str());
+      // Virtual modules don't have a real path
+      if !path.is_absolute() {
+        continue;
+      }
+      if let Some(real_path) = canonicalize(&self.fs, path) {
+        ids_by_real_path.entry(real_path).or_default().push(module.id.to_string());
+      }
+    }
+
+    ids_by_real_path
+      .into_iter()
+      .filter(|(_, ids)| ids.len() > 1)
+      .map(|(real_path, mut ids)| {
+        ids.sort_unstable();
+        BuildDiagnostic::duplicate_module_instance(real_path.to_string_lossy().into_owned(), ids)
+          .with_severity_warning()
+      })
+      .collect()
+  }
+
   /// Resolve `InputOptions.input`
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -1,11 +1,11 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use futures::future::join_all;
 use oxc::index::IndexVec;
 use oxc::minifier::ReplaceGlobalDefinesConfig;
 use rolldown_common::{
-  side_effects::HookSideEffects, ImportKind, ImportRecordIdx, Module, ModuleDefFormat, ModuleIdx,
-  ModuleType, RawImportRecord, ResolvedId, StrOrBytes,
+  side_effects::HookSideEffects, ImportKind, ImportRecordIdx, ImportRecordMeta, Module,
+  ModuleDefFormat, ModuleIdx, ModuleMeta, ModuleType, RawImportRecord, ResolvedId, StrOrBytes,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
@@ -13,7 +13,10 @@ use rolldown_plugin::SharedPluginDriver;
 use rolldown_resolver::ResolveError;
 use rolldown_sourcemap::SourceMap;
 
-use crate::{runtime::RUNTIME_MODULE_ID, utils::resolve_id, SharedOptions, SharedResolver};
+use crate::{
+  ast_scanner::scan_cache::ScanCache, runtime::RUNTIME_MODULE_ID, utils::resolve_id, SharedOptions,
+  SharedResolver,
+};
 
 use super::ast_symbols::AstSymbols;
 
@@ -24,9 +27,13 @@ pub struct CreateModuleContext<'a> {
   pub options: &'a SharedOptions,
   pub module_type: ModuleType,
   pub warnings: &'a mut Vec<BuildDiagnostic>,
+  /// Errors that fail the build, but still let the module be created, so the other diagnostics of the module are
+  /// reported as well.
+  pub errors: &'a mut Vec<BuildDiagnostic>,
   pub resolver: &'a SharedResolver,
   pub is_user_defined_entry: bool,
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
+  pub scan_cache: &'a ScanCache,
 }
 
 impl<'a> CreateModuleContext<'a> {
@@ -46,8 +53,10 @@ impl<'a> CreateModuleContext<'a> {
           ignored: false,
           module_def_format: ModuleDefFormat::Unknown,
           is_external: true,
+          is_absolute_external: false,
           package_json: None,
           side_effects: None,
+          meta: ModuleMeta::default(),
         }));
       }
     }
@@ -59,8 +68,10 @@ impl<'a> CreateModuleContext<'a> {
         ignored: false,
         module_def_format: ModuleDefFormat::EsmMjs,
         is_external: false,
+        is_absolute_external: false,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       }));
     }
 
@@ -78,7 +89,16 @@ impl<'a> CreateModuleContext<'a> {
 
     match resolved_id {
       Ok(mut resolved_id) => {
-        if !resolved_id.is_external {
+        if resolved_id.is_external {
+          // The absolute path of an external module in the input is unlikely to be valid for the output, so it's
+          // imported by the relative specifier it's imported with, unless the plugin asked for the absolute path.
+          if !resolved_id.is_absolute_external
+            && specifier.starts_with('.')
+            && Path::new(resolved_id.id.as_str()).is_absolute()
+          {
+            resolved_id.id = specifier.into();
+          }
+        } else {
           // Check external with resolved path
           if let Some(is_external) = bundle_options.external.as_ref() {
             resolved_id.is_external = is_external(specifier, Some(importer), true).await?;
@@ -102,7 +122,34 @@ impl<'a> CreateModuleContext<'a> {
       let plugin_driver = Arc::clone(self.plugin_driver);
       let importer = &self.resolved_id.id;
       let kind = item.kind;
+      let is_ignored_by_comment = item.meta.contains(ImportRecordMeta::IS_IGNORED_BY_COMMENT);
+      let is_non_literal_request = item.meta.contains(ImportRecordMeta::IS_NON_LITERAL_REQUEST);
       async move {
+        if is_non_literal_request {
+          let resolved_id =
+            resolve_id::resolve_non_literal_dynamic_import(&plugin_driver, &specifier, importer)
+              .await?;
+          if let Some(resolved_id) = resolved_id {
+            return Ok((specifier, idx, Ok(resolved_id)));
+          }
+        }
+        // Unresolved non-literal requests are kept as written too.
+        if is_ignored_by_comment || is_non_literal_request {
+          return Ok((
+            specifier.clone(),
+            idx,
+            Ok(ResolvedId {
+              id: specifier.to_string().into(),
+              ignored: false,
+              module_def_format: ModuleDefFormat::Unknown,
+              is_external: true,
+              is_absolute_external: false,
+              package_json: None,
+              side_effects: None,
+              meta: ModuleMeta::default(),
+            }),
+          ));
+        }
         Self::resolve_id(&bundle_options, &resolver, &plugin_driver, importer, &specifier, kind)
           .await
           .map(|id| (specifier, idx, id))
@@ -135,8 +182,10 @@ impl<'a> CreateModuleContext<'a> {
               ignored: false,
               module_def_format: ModuleDefFormat::Unknown,
               is_external: true,
+              is_absolute_external: false,
               package_json: None,
               side_effects: None,
+              meta: ModuleMeta::default(),
             });
           }
           _ => {
@@ -162,6 +211,8 @@ pub struct CreateModuleArgs {
   pub source: StrOrBytes,
   pub sourcemap_chain: Vec<SourceMap>,
   pub hook_side_effects: Option<HookSideEffects>,
+  /// Whether a `load` hook provided the source or a `transform` hook changed it.
+  pub is_handled_by_plugin: bool,
 }
 
 pub struct CreateModuleReturn {
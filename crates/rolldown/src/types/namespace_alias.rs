@@ -1,7 +1,7 @@
 use rolldown_common::SymbolRef;
 use rolldown_rstr::Rstr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NamespaceAlias {
   pub property_name: Rstr,
   pub namespace_ref: SymbolRef,
@@ -6,7 +6,7 @@ use rustc_hash::FxHashMap;
 
 use super::{ast_symbols::AstSymbols, namespace_alias::NamespaceAlias};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Symbol {
   /// For case `import {a} from 'foo.cjs';console.log(a)`, the symbol `a` reference to `module.exports.a` of `foo.cjs`.
   /// So we will transform the code into `console.log(foo_ns.a)`. `foo_ns` is the namespace symbol of `foo.cjs and `a` is the property name.
@@ -20,7 +20,7 @@ pub struct Symbol {
 }
 
 // Information about symbols for all modules
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Symbols {
   inner: IndexVec<ModuleIdx, IndexVec<SymbolId, Symbol>>,
 }
@@ -74,7 +74,7 @@ pub fn collect_render_chunk_imports<'a>(
           Specifier::Star => {
             has_importee_imported = true;
             render_import_stmts.push(RenderImportStmt {
-              path: importee.name.as_str().into(),
+              path: importee.rendered_specifier.as_str().into(),
               is_external: true,
               specifiers: RenderImportDeclarationSpecifier::ImportStarSpecifier(alias),
             });
@@ -93,7 +93,7 @@ pub fn collect_render_chunk_imports<'a>(
       || (importee.side_effects.has_side_effects() && !has_importee_imported)
     {
       render_import_stmts.push(RenderImportStmt {
-        path: importee.name.as_str().into(),
+        path: importee.rendered_specifier.as_str().into(),
         is_external: true,
         specifiers: RenderImportDeclarationSpecifier::ImportSpecifier(specifiers),
       });
@@ -1,17 +1,23 @@
 use std::borrow::Cow;
 
 use crate::{stages::link_stage::LinkStageOutput, utils::renamer::Renamer};
-use rolldown_common::{Chunk, ChunkKind, OutputFormat};
+use rolldown_common::{Chunk, ChunkKind, NormalizedBundlerOptions};
 use rolldown_rstr::ToRstr;
 
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn deconflict_chunk_symbols(
   chunk: &mut Chunk,
   link_output: &LinkStageOutput,
-  format: &OutputFormat,
+  options: &NormalizedBundlerOptions,
 ) {
-  let mut renamer =
-    Renamer::new(&link_output.symbols, link_output.module_table.modules.len(), format);
+  // Exports are rendered with explicit names in these formats, so they still get their public names after mangling.
+  let mangle = options.mangle && options.format.requires_scope_hoisting();
+  let mut renamer = Renamer::new(
+    &link_output.symbols,
+    link_output.module_table.modules.len(),
+    &options.format,
+    mangle,
+  );
 
   chunk
     .modules
@@ -24,6 +30,15 @@ pub fn deconflict_chunk_symbols(
       renamer.reserve(Cow::Owned(name.to_rstr()));
     });
 
+  // Bindings imported from external modules aren't mangled, so they keep the names of the imported exports.
+  if mangle {
+    chunk
+      .imports_from_external_modules
+      .iter()
+      .flat_map(|(_, items)| items.iter())
+      .for_each(|item| renamer.add_top_level_symbol_with_original_name(item.imported_as));
+  }
+
   // Though, those symbols in `imports_from_other_chunks` doesn't belong to this chunk, but in the final output, they still behave
   // like declared in this chunk. This is because we need to generate import statements in this chunk to import symbols from other
   // statements. Those `import {...} from './other-chunk.js'` will declared these outside symbols in this chunk, so symbols that
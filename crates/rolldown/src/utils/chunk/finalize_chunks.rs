@@ -10,7 +10,7 @@ use rolldown_utils::{
   rayon::{IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator},
   xxhash::xxhash_base64_url,
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
@@ -47,6 +47,36 @@ pub fn finalize_assets(
     .collect::<Vec<_>>()
     .into();
 
+  // An asset's filename changes with the filenames of the assets it imports, so its hash has to cover all the assets
+  // reachable from it, not only the ones it imports directly. The direct dependencies are hashed as before, so assets
+  // without indirect dependencies keep their hashes. The indirect ones follow, sorted by the execution order of their
+  // chunks, so the hash doesn't depend on the order the placeholders appear in the content.
+  let index_transitive_dependencies: IndexVec<AssetIdx, Vec<AssetIdx>> = index_asset_dependencies
+    .as_vec()
+    .par_iter()
+    .enumerate()
+    .map(|(asset_idx, direct_dependencies)| {
+      let mut visited = FxHashSet::from_iter([AssetIdx::from_usize(asset_idx)]);
+      visited.extend(direct_dependencies.iter().copied());
+      let mut stack = direct_dependencies
+        .iter()
+        .flat_map(|dep_idx| index_asset_dependencies[*dep_idx].iter().copied())
+        .collect_vec();
+      let mut indirect_dependencies = vec![];
+      while let Some(dep_idx) = stack.pop() {
+        if visited.insert(dep_idx) {
+          indirect_dependencies.push(dep_idx);
+          stack.extend(index_asset_dependencies[dep_idx].iter().copied());
+        }
+      }
+      indirect_dependencies.sort_by_key(|dep_idx| {
+        (chunk_graph.chunks[preliminary_assets[*dep_idx].origin_chunk].exec_order, *dep_idx)
+      });
+      direct_dependencies.iter().copied().chain(indirect_dependencies).collect_vec()
+    })
+    .collect::<Vec<_>>()
+    .into();
+
   let index_standalone_content_hashes: IndexVec<AssetIdx, String> = preliminary_assets
     .as_vec()
     .par_iter()
@@ -69,7 +99,7 @@ pub fn finalize_assets(
       // hash itself's preliminary filename to prevent different chunks that have the same content from having the same hash
       preliminary_assets[asset_idx].preliminary_filename.hash(&mut hasher);
 
-      let dependencies = &index_asset_dependencies[asset_idx];
+      let dependencies = &index_transitive_dependencies[asset_idx];
       dependencies.iter().copied().for_each(|dep_id| {
         index_standalone_content_hashes[dep_id].hash(&mut hasher);
       });
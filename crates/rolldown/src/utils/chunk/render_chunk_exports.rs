@@ -1,12 +1,14 @@
 use crate::{stages::link_stage::LinkStageOutput, types::generator::GenerateContext};
 use std::borrow::Cow;
 
+use oxc::ast::ast::Statement;
 use rolldown_common::{
   Chunk, ChunkKind, ExportsKind, NormalizedBundlerOptions, OutputExports, OutputFormat, SymbolRef,
   WrapKind,
 };
 use rolldown_rstr::Rstr;
 use rolldown_utils::ecma_script::is_validate_identifier_name;
+use rustc_hash::FxHashSet;
 
 #[allow(clippy::too_many_lines)]
 pub fn render_chunk_exports(
@@ -47,7 +49,7 @@ pub fn render_chunk_exports(
       s.push_str(&format!("export {{ {} }};", rendered_items.join(", "),));
       Some(s)
     }
-    OutputFormat::Cjs | OutputFormat::Iife => {
+    OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
       let mut s = String::new();
       match chunk.kind {
         ChunkKind::EntryPoint { module, .. } => {
@@ -146,10 +148,56 @@ pub fn render_chunk_exports(
 
       Some(s)
     }
+    OutputFormat::System => {
+      // Exported functions are reported before `execute` by `render_system`. Later updates of the exports are reported
+      // by the `exports(name, value)` calls inserted by the finalizer.
+      let hoisted_functions = hoisted_function_names(chunk, link_output);
+      let rendered_items = export_items
+        .into_iter()
+        .filter_map(|(exported_name, export_ref)| {
+          let canonical_ref = link_output.symbols.par_canonical_ref_for(export_ref);
+          let symbol = link_output.symbols.get(canonical_ref);
+          let value = if let Some(ns_alias) = &symbol.namespace_alias {
+            let canonical_ns_name = &chunk.canonical_names[&ns_alias.namespace_ref];
+            format!("{canonical_ns_name}.{}", ns_alias.property_name)
+          } else {
+            let canonical_name = &chunk.canonical_names[&canonical_ref];
+            if hoisted_functions.contains(canonical_name.as_str()) {
+              return None;
+            }
+            canonical_name.to_string()
+          };
+          Some(if is_validate_identifier_name(&exported_name) {
+            format!("{exported_name}: {value}")
+          } else {
+            format!("'{exported_name}': {value}")
+          })
+        })
+        .collect::<Vec<_>>();
+      (!rendered_items.is_empty()).then(|| format!("exports({{ {} }});", rendered_items.join(", ")))
+    }
     OutputFormat::App => None,
   }
 }
 
+/// Names of the functions declared at the top level of the modules of the chunk, which `System.register` chunks hoist
+/// out of `execute`.
+pub fn hoisted_function_names<'a>(
+  chunk: &Chunk,
+  link_output: &'a LinkStageOutput,
+) -> FxHashSet<&'a str> {
+  chunk
+    .modules
+    .iter()
+    .filter_map(|idx| link_output.module_table.modules[*idx].as_ecma())
+    .flat_map(|module| link_output.ast_table[module.ecma_ast_idx()].0.program().body.iter())
+    .filter_map(|stmt| match stmt {
+      Statement::FunctionDeclaration(func) => func.id.as_ref().map(|id| id.name.as_str()),
+      _ => None,
+    })
+    .collect()
+}
+
 pub fn get_export_items(chunk: &Chunk, graph: &LinkStageOutput) -> Vec<(Rstr, SymbolRef)> {
   match chunk.kind {
     ChunkKind::EntryPoint { module, .. } => {
@@ -178,7 +226,7 @@ pub fn get_chunk_export_names(
   graph: &LinkStageOutput,
   options: &NormalizedBundlerOptions,
 ) -> Vec<String> {
-  if matches!(options.format, OutputFormat::Esm) {
+  if matches!(options.format, OutputFormat::Esm | OutputFormat::System) {
     if let ChunkKind::EntryPoint { module: entry_id, .. } = &chunk.kind {
       let entry_meta = &graph.metas[*entry_id];
       if matches!(entry_meta.wrap_kind, WrapKind::Cjs) {
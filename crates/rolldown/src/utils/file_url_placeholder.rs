@@ -0,0 +1,85 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use rolldown_common::{AssetMeta, FileEmitter, OutputFormat};
+use rolldown_plugin::{HookResolveFileUrlArgs, SharedPluginDriver};
+use sugar_path::SugarPath;
+
+use crate::{type_alias::IndexPreliminaryAssets, SharedOptions};
+
+static FILE_URL_PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"import\.meta\.ROLLUP_FILE_URL_([0-9a-zA-Z_$]+)").expect("failed to compile regex")
+});
+
+/// Replace `import.meta.ROLLUP_FILE_URL_<reference id>` in rendered chunks with the url of the emitted file. Plugins
+/// may render the url with the `resolve_file_url` hook, otherwise it's resolved relative to the url of the chunk.
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn replace_file_url_placeholders(
+  assets: &mut IndexPreliminaryAssets,
+  options: &SharedOptions,
+  file_emitter: &FileEmitter,
+  plugin_driver: &SharedPluginDriver,
+) -> anyhow::Result<()> {
+  let dir = options.cwd.join(&options.dir);
+  for asset in assets.iter_mut() {
+    let AssetMeta::Ecma(ecma_meta) = &asset.meta else {
+      continue;
+    };
+    let chunk_filename = ecma_meta.rendered_chunk.filename.as_str();
+    let chunk_dir = dir.join(chunk_filename);
+    let chunk_dir = chunk_dir.parent().unwrap_or(&dir);
+
+    // The hook is async, so the urls are resolved before the placeholders are replaced.
+    let mut content = String::with_capacity(asset.content.len());
+    let mut last_end = 0;
+    for caps in FILE_URL_PLACEHOLDER_REGEX.captures_iter(&asset.content) {
+      let placeholder = caps.get(0).expect("the whole match always exists");
+      let reference_id = &caps[1];
+      let file_name = file_emitter.try_get_file_name(reference_id).map_err(|err| {
+        anyhow::format_err!(
+          "Failed to replace `import.meta.ROLLUP_FILE_URL_*` in {chunk_filename}: {err}"
+        )
+      })?;
+      let relative_path = dir.join(&file_name).relative(chunk_dir);
+      let relative_path = relative_path.to_slash_lossy();
+      let args = HookResolveFileUrlArgs {
+        reference_id,
+        file_name: &file_name,
+        chunk_id: chunk_filename,
+        relative_path: &relative_path,
+        format: &options.format,
+      };
+      let url = match plugin_driver.resolve_file_url(&args).await? {
+        Some(url) => url,
+        None => render_file_url(&relative_path, &options.format),
+      };
+      content.push_str(&asset.content[last_end..placeholder.start()]);
+      content.push_str(&url);
+      last_end = placeholder.end();
+    }
+    if last_end > 0 {
+      content.push_str(&asset.content[last_end..]);
+      asset.content = content;
+    }
+  }
+  Ok(())
+}
+
+fn render_file_url(relative_path: &str, format: &OutputFormat) -> String {
+  match format {
+    OutputFormat::Esm | OutputFormat::App => {
+      format!("new URL(\"{relative_path}\", import.meta.url).href")
+    }
+    OutputFormat::System => format!("new URL(\"{relative_path}\", module.meta.url).href"),
+    OutputFormat::Cjs => {
+      format!("require(\"url\").pathToFileURL(__dirname + \"/{relative_path}\").href")
+    }
+    OutputFormat::Iife | OutputFormat::Amd => format!(
+      "new URL(\"{relative_path}\", document.currentScript && document.currentScript.src || document.baseURI).href"
+    ),
+    // UMD bundles may run in Node.js or in browsers, so the environment is checked at runtime.
+    OutputFormat::Umd => format!(
+      "(typeof document === \"undefined\" ? require(\"url\").pathToFileURL(__dirname + \"/{relative_path}\").href : new URL(\"{relative_path}\", document.currentScript && document.currentScript.src || document.baseURI).href)"
+    ),
+  }
+}
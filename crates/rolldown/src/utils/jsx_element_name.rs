@@ -0,0 +1,43 @@
+use oxc::ast::ast::{JSXElementName, JSXIdentifier, JSXMemberExpressionObject};
+
+/// Like Babel, names starting with a lowercase letter are intrinsic elements such as `<div />`.
+fn is_intrinsic(name: &str) -> bool {
+  name.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+/// The binding that `<Foo />` or `<foo.Bar />` refers to, which is `Foo` or `foo`. Intrinsic elements and namespaced
+/// names like `<svg:rect />` don't refer to any binding.
+pub fn jsx_element_name_reference<'a>(name: &'a JSXElementName) -> Option<&'a str> {
+  match name {
+    JSXElementName::Identifier(ident) if !is_intrinsic(&ident.name) => Some(ident.name.as_str()),
+    JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_) => None,
+    JSXElementName::MemberExpression(expr) => {
+      let mut object = &expr.object;
+      loop {
+        match object {
+          JSXMemberExpressionObject::Identifier(ident) => return Some(ident.name.as_str()),
+          JSXMemberExpressionObject::MemberExpression(expr) => object = &expr.object,
+        }
+      }
+    }
+  }
+}
+
+/// Mutable version of [jsx_element_name_reference], for renaming the referenced binding.
+pub fn jsx_element_name_reference_mut<'a, 'ast>(
+  name: &'a mut JSXElementName<'ast>,
+) -> Option<&'a mut JSXIdentifier<'ast>> {
+  fn object_identifier<'a, 'ast>(
+    object: &'a mut JSXMemberExpressionObject<'ast>,
+  ) -> &'a mut JSXIdentifier<'ast> {
+    match object {
+      JSXMemberExpressionObject::Identifier(ident) => ident,
+      JSXMemberExpressionObject::MemberExpression(expr) => object_identifier(&mut expr.object),
+    }
+  }
+  match name {
+    JSXElementName::Identifier(ident) if !is_intrinsic(&ident.name) => Some(&mut **ident),
+    JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_) => None,
+    JSXElementName::MemberExpression(expr) => Some(object_identifier(&mut expr.object)),
+  }
+}
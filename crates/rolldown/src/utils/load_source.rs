@@ -8,15 +8,18 @@ use sugar_path::SugarPath;
 
 pub async fn load_source(
   plugin_driver: &PluginDriver,
-  resolved_id: &ResolvedId,
+  resolved_id: &mut ResolvedId,
   fs: &dyn rolldown_fs::FileSystem,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
+  is_handled_by_plugin: &mut bool,
   options: &NormalizedBundlerOptions,
 ) -> anyhow::Result<(StrOrBytes, ModuleType)> {
-  let (maybe_source, maybe_module_type) = if let Some(load_hook_output) =
+  let (maybe_source, maybe_module_type) = if let Some((load_hook_output, plugin_name)) =
     plugin_driver.load(&HookLoadArgs { id: &resolved_id.id }).await?
   {
+    *is_handled_by_plugin = true;
+    resolved_id.meta.merge(&plugin_name, load_hook_output.meta);
     sourcemap_chain.extend(load_hook_output.map);
     if let Some(v) = load_hook_output.side_effects {
       *side_effects = Some(v);
@@ -12,18 +12,23 @@ pub mod chunk;
 pub mod ecma_visitors;
 pub mod extract_hash_pattern;
 pub mod extract_meaningful_input_name_from_path;
+pub mod file_url_placeholder;
 pub mod hash_placeholder;
+pub mod jsx_element_name;
 pub mod load_source;
 pub mod make_ast_symbol_and_scope;
+pub mod namespace_object;
 pub mod normalize_options;
 pub mod parse_to_ecma_ast;
 pub mod pre_process_ecma_ast;
 pub mod renamer;
 pub mod render_chunks;
 pub mod render_ecma_module;
+pub mod render_external_specifiers;
 pub mod resolve_id;
 pub mod transform_source;
 pub mod tweak_ast_for_scanning;
+pub mod wrap_module;
 
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn finalize_normal_module(
@@ -0,0 +1,28 @@
+use rolldown_common::{ExportsKind, IndexModules, Module, NamespaceObject, SymbolRef};
+
+use crate::{types::symbols::Symbols, SharedOptions};
+
+/// Whether the property of the module namespace object for an export resolved to `symbol_ref` has to be a getter.
+/// Otherwise, the value is copied into the namespace object once the module finishes executing.
+pub fn is_live_namespace_export(
+  options: &SharedOptions,
+  modules: &IndexModules,
+  symbols: &Symbols,
+  symbol_ref: SymbolRef,
+) -> bool {
+  if matches!(options.namespace_object, NamespaceObject::Live) {
+    return true;
+  }
+  let canonical_ref = symbols.par_canonical_ref_for(symbol_ref);
+  // Exports of CommonJS and external modules are read from objects that might change at any time
+  if symbols.get(canonical_ref).namespace_alias.is_some() {
+    return true;
+  }
+  match &modules[canonical_ref.owner] {
+    Module::Ecma(module) => {
+      !matches!(module.exports_kind, ExportsKind::Esm)
+        || module.mutated_exports.contains(&canonical_ref)
+    }
+    Module::External(_) => true,
+  }
+}
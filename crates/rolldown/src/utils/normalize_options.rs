@@ -1,8 +1,7 @@
 use oxc::minifier::InjectGlobalVariablesConfig;
-use rolldown_common::{
-  InjectImport, ModuleType, NormalizedBundlerOptions, Platform, SourceMapType,
-};
+use rolldown_common::{InjectImport, ModuleType, NormalizedBundlerOptions, Platform};
 use rustc_hash::FxHashMap;
+use sugar_path::SugarPath;
 
 pub struct NormalizeOptionsReturn {
   pub options: NormalizedBundlerOptions,
@@ -75,11 +74,24 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       .unwrap_or_default(),
   );
 
+  let cwd =
+    raw_options.cwd.unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
+
+  let cycle_order = raw_options
+    .cycle_order
+    .unwrap_or_default()
+    .into_iter()
+    .map(|ids| {
+      ids
+        .into_iter()
+        .map(|id| id.absolutize_with(cwd.as_path()).to_string_lossy().into_owned())
+        .collect()
+    })
+    .collect();
+
   let normalized = NormalizedBundlerOptions {
     input: raw_options.input.unwrap_or_default(),
-    cwd: raw_options
-      .cwd
-      .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir")),
+    cwd,
     external: raw_options.external,
     treeshake: raw_options.treeshake,
     platform,
@@ -93,6 +105,8 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       .asset_filenames
       .unwrap_or_else(|| "assets/[name]-[hash][extname]".to_string())
       .into(),
+    file_name: raw_options.file_name,
+    manual_chunks: raw_options.manual_chunks,
     css_entry_filenames: raw_options
       .css_entry_filenames
       .unwrap_or_else(|| "[name].css".to_string())
@@ -106,17 +120,31 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     intro: raw_options.intro,
     outro: raw_options.outro,
     es_module: raw_options.es_module.unwrap_or_default(),
+    empty_chunk: raw_options.empty_chunk.unwrap_or_default(),
+    namespace_object: raw_options.namespace_object.unwrap_or_default(),
     dir: raw_options.dir.unwrap_or_else(|| "dist".to_string()),
     format: raw_options.format.unwrap_or(crate::OutputFormat::Esm),
     exports: raw_options.exports.unwrap_or(crate::OutputExports::Auto),
+    interop: raw_options.interop.unwrap_or_default(),
     globals,
-    sourcemap: raw_options.sourcemap.unwrap_or(SourceMapType::Hidden),
+    amd: raw_options.amd.unwrap_or_default(),
+    sourcemap: raw_options.sourcemap,
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
+    scan_timeout: raw_options.scan_timeout,
+    module_wrapper: raw_options.module_wrapper,
+    cycle_order,
+    fail_on_circular_dependency: raw_options.fail_on_circular_dependency.unwrap_or(false),
+    fail_on_import_reassignment: raw_options.fail_on_import_reassignment.unwrap_or(true),
+    on_module_added: raw_options.on_module_added,
+    watch: raw_options.watch.unwrap_or(false),
     module_types: loaders,
+    jsx_runtime: raw_options.jsx_runtime.unwrap_or_default(),
+    jsx_import_source: raw_options.jsx_import_source.unwrap_or_else(|| "react".to_string()),
     experimental: raw_options.experimental.unwrap_or_default(),
     minify: raw_options.minify.unwrap_or(false),
+    mangle: raw_options.mangle.unwrap_or(false),
     define: raw_options.define.map(|inner| inner.into_iter().collect()).unwrap_or_default(),
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
@@ -1,4 +1,4 @@
-use std::path::Path;
+use sugar_path::SugarPath;
 
 use arcstr::ArcStr;
 use oxc::{
@@ -6,14 +6,15 @@ use oxc::{
   semantic::{ScopeTree, SymbolTable},
   span::SourceType as OxcSourceType,
 };
-use rolldown_common::{ModuleType, NormalizedBundlerOptions, StrOrBytes};
+use rolldown_common::{ModuleId, ModuleType, NormalizedBundlerOptions, StrOrBytes};
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::DiagnosableResult;
 use rolldown_loader_utils::{binary_to_esm, json_to_esm, text_to_esm};
 use rolldown_plugin::{HookTransformAstArgs, PluginDriver};
+use rolldown_sourcemap::SourceMap;
 use rolldown_utils::mime::guess_mime;
 
-use super::pre_process_ecma_ast::pre_process_ecma_ast;
+use super::{pre_process_ecma_ast::pre_process_ecma_ast, wrap_module::wrap_module};
 
 use crate::{runtime::RUNTIME_MODULE_ID, types::oxc_parse_type::OxcParseType};
 
@@ -29,13 +30,16 @@ fn pure_esm_js_oxc_source_type() -> OxcSourceType {
 
 pub fn parse_to_ecma_ast(
   plugin_driver: &PluginDriver,
-  path: &Path,
+  module_id: &ModuleId,
   stable_id: &str,
   options: &NormalizedBundlerOptions,
   module_type: &ModuleType,
   source: StrOrBytes,
+  sourcemap_chain: &mut Vec<SourceMap>,
   replace_global_define_config: Option<&ReplaceGlobalDefinesConfig>,
 ) -> anyhow::Result<DiagnosableResult<(EcmaAst, SymbolTable, ScopeTree)>> {
+  let path = module_id.as_path();
+
   // 1. Transform the source to the type that rolldown supported.
   let (source, parsed_type) = match module_type {
     ModuleType::Js => (source.try_into_string()?, OxcParseType::Js),
@@ -84,6 +88,15 @@ pub fn parse_to_ecma_ast(
     }
   };
 
+  let source = match &options.module_wrapper {
+    Some(module_wrapper) => {
+      let (wrapped, sourcemap) =
+        wrap_module(module_wrapper, module_id, &source, options.sourcemap.is_some());
+      sourcemap_chain.extend(sourcemap);
+      wrapped
+    }
+    None => source,
+  };
   let source = ArcStr::from(source);
   let parse_result = EcmaCompiler::parse(stable_id, &source, oxc_source_type);
 
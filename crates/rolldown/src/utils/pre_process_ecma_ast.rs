@@ -7,9 +7,9 @@ use oxc::minifier::{
 };
 use oxc::semantic::{ScopeTree, SemanticBuilder, SymbolTable};
 use oxc::span::SourceType;
-use oxc::transformer::{TransformOptions, Transformer};
+use oxc::transformer::{ReactJsxRuntime, TransformOptions, Transformer};
 
-use rolldown_common::NormalizedBundlerOptions;
+use rolldown_common::{JsxRuntime, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, WithMutFields};
 
 use crate::types::oxc_parse_type::OxcParseType;
@@ -46,9 +46,22 @@ pub fn pre_process_ecma_ast(
       let mut transformer_options = TransformOptions::default();
       match parse_type {
         OxcParseType::Js => unreachable!("Should not reach here"),
-        OxcParseType::Jsx | OxcParseType::Tsx => {
-          transformer_options.react.jsx_plugin = true;
-        }
+        OxcParseType::Jsx | OxcParseType::Tsx => match bundle_options.jsx_runtime {
+          JsxRuntime::Classic => {
+            transformer_options.react.jsx_plugin = true;
+            transformer_options.react.runtime = ReactJsxRuntime::Classic;
+          }
+          JsxRuntime::Automatic => {
+            transformer_options.react.jsx_plugin = true;
+            transformer_options.react.runtime = ReactJsxRuntime::Automatic;
+            // The injected `import { jsx as _jsx } from "<source>/jsx-runtime"` is scanned like any other import, so
+            // the runtime is resolved, loaded and ordered like modules imported by the user.
+            transformer_options.react.import_source =
+              Some(bundle_options.jsx_import_source.clone());
+          }
+          // JSX is kept in the AST, so the scanner and the finalizer have to deal with it.
+          JsxRuntime::Preserve => {}
+        },
         OxcParseType::Ts => {}
       }
 
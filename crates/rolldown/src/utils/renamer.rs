@@ -31,19 +31,31 @@ pub struct Renamer<'name> {
   used_canonical_names: FxHashMap<Cow<'name, Rstr>, u32>,
   canonical_names: FxHashMap<SymbolRef, Rstr>,
   symbols: &'name Symbols,
+  /// If `true`, symbols are renamed to short names like `a` and `b` instead of their original names.
+  mangle: bool,
+  next_mangled_name_index: u32,
 }
 
 impl<'name> Renamer<'name> {
-  pub fn new(symbols: &'name Symbols, _modules_len: usize, format: &OutputFormat) -> Self {
+  pub fn new(
+    symbols: &'name Symbols,
+    _modules_len: usize,
+    format: &OutputFormat,
+    mangle: bool,
+  ) -> Self {
     // Port from https://github.com/rollup/rollup/blob/master/src/Chunk.ts#L1377-L1394.
     let manual_reserved = match format {
       OutputFormat::Esm | OutputFormat::App => vec![],
       OutputFormat::Cjs => vec!["module", "require", "__filename", "__dirname", "exports"],
-      OutputFormat::Iife => vec!["exports"], // Also for UMD, AMD, but we don't support them yet.
+      OutputFormat::Iife | OutputFormat::Umd => vec!["exports"],
+      OutputFormat::Amd => vec!["exports", "require", "module"],
+      OutputFormat::System => vec!["exports", "module"],
     };
     Self {
       canonical_names: FxHashMap::default(),
       symbols,
+      mangle,
+      next_mangled_name_index: 0,
       used_canonical_names: manual_reserved
         .iter()
         .chain(RESERVED_KEYWORDS.iter())
@@ -58,6 +70,16 @@ impl<'name> Renamer<'name> {
   }
 
   pub fn add_top_level_symbol(&mut self, symbol_ref: SymbolRef) {
+    if self.mangle {
+      self.add_mangled_top_level_symbol(symbol_ref);
+    } else {
+      self.add_top_level_symbol_with_original_name(symbol_ref);
+    }
+  }
+
+  /// Like `add_top_level_symbol`, but the symbol keeps its original name even when mangling, unless it conflicts with
+  /// other names.
+  pub fn add_top_level_symbol_with_original_name(&mut self, symbol_ref: SymbolRef) {
     let canonical_ref = self.symbols.par_canonical_ref_for(symbol_ref);
     let original_name: Cow<'_, Rstr> =
       Cow::Owned(self.symbols.get_original_name(canonical_ref).to_rstr());
@@ -85,6 +107,22 @@ impl<'name> Renamer<'name> {
     }
   }
 
+  fn add_mangled_top_level_symbol(&mut self, symbol_ref: SymbolRef) {
+    let canonical_ref = self.symbols.par_canonical_ref_for(symbol_ref);
+    if let Entry::Vacant(vacant) = self.canonical_names.entry(canonical_ref) {
+      let mangled_name = loop {
+        let candidate_name = Cow::Owned(mangled_name(self.next_mangled_name_index));
+        self.next_mangled_name_index += 1;
+        if let Entry::Vacant(vac) = self.used_canonical_names.entry(candidate_name) {
+          let mangled_name = vac.key().clone().into_owned();
+          vac.insert(0);
+          break mangled_name;
+        }
+      };
+      vacant.insert(mangled_name);
+    }
+  }
+
   pub fn create_conflictless_top_level_name(&mut self, hint: &str) -> String {
     let hint: Cow<Rstr> = Cow::Owned(Rstr::new(hint));
     let mut conflictless_name = hint.clone();
@@ -117,14 +155,37 @@ impl<'name> Renamer<'name> {
       scope_id: ScopeId,
       stack: &mut Vec<Cow<FxHashMap<Cow<'name, Rstr>, u32>>>,
       canonical_names: &mut FxHashMap<SymbolRef, Rstr>,
+      mangle: bool,
     ) {
       let bindings = module.scope.get_bindings(scope_id);
       let mut used_canonical_names_for_this_scope = FxHashMap::default();
       used_canonical_names_for_this_scope.shrink_to(bindings.len());
+      // Names of sibling scopes don't conflict, so each scope starts from the shortest name again.
+      let mut next_mangled_name_index = 0;
       bindings.iter().for_each(|(binding_name, symbol_id)| {
-        used_canonical_names_for_this_scope.insert(Cow::Owned(binding_name.to_rstr()), 0);
         let binding_ref: SymbolRef = (module.idx, *symbol_id).into();
 
+        if mangle {
+          if let Entry::Vacant(slot) = canonical_names.entry(binding_ref) {
+            let candidate_name = loop {
+              let candidate_name = Cow::Owned(mangled_name(next_mangled_name_index));
+              next_mangled_name_index += 1;
+              let is_used = used_canonical_names_for_this_scope.contains_key(&candidate_name)
+                || stack
+                  .iter()
+                  .any(|used_canonical_names| used_canonical_names.contains_key(&candidate_name));
+              if !is_used {
+                break candidate_name;
+              }
+            };
+            used_canonical_names_for_this_scope.insert(candidate_name.clone(), 0);
+            slot.insert(candidate_name.into_owned());
+          }
+          return;
+        }
+
+        used_canonical_names_for_this_scope.insert(Cow::Owned(binding_name.to_rstr()), 0);
+
         let mut count = 1;
         let mut candidate_name = Cow::Owned(binding_name.to_rstr());
         match canonical_names.entry(binding_ref) {
@@ -151,7 +212,7 @@ impl<'name> Renamer<'name> {
       stack.push(Cow::Owned(used_canonical_names_for_this_scope));
       let child_scopes = module.scope.get_child_ids(scope_id).cloned().unwrap_or_default();
       child_scopes.into_iter().for_each(|scope_id| {
-        rename_symbols_of_nested_scopes(module, scope_id, stack, canonical_names);
+        rename_symbols_of_nested_scopes(module, scope_id, stack, canonical_names, mangle);
       });
       stack.pop();
     }
@@ -170,6 +231,7 @@ impl<'name> Renamer<'name> {
               *child_scope_id,
               &mut stack,
               &mut canonical_names,
+              self.mangle,
             );
             canonical_names
           })
@@ -197,3 +259,38 @@ impl<'name> Renamer<'name> {
     self.canonical_names
   }
 }
+
+/// Returns the `index`-th shortest identifier: `a`, `b`, ..., `$`, `aa`, `ba`, ... Callers skip the ones that are used
+/// or reserved, including keywords like `do` and `if`.
+fn mangled_name(mut index: u32) -> Rstr {
+  const FIRST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$";
+  const REST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_$";
+  #[allow(clippy::cast_possible_truncation)]
+  let first_chars_len = FIRST_CHARS.len() as u32;
+  #[allow(clippy::cast_possible_truncation)]
+  let rest_chars_len = REST_CHARS.len() as u32;
+
+  let mut name = String::new();
+  name.push(FIRST_CHARS[(index % first_chars_len) as usize] as char);
+  index /= first_chars_len;
+  while index > 0 {
+    index -= 1;
+    name.push(REST_CHARS[(index % rest_chars_len) as usize] as char);
+    index /= rest_chars_len;
+  }
+  Rstr::new(&name)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_mangled_name() {
+    assert_eq!(mangled_name(0).as_str(), "a");
+    assert_eq!(mangled_name(53).as_str(), "$");
+    assert_eq!(mangled_name(54).as_str(), "aa");
+    assert_eq!(mangled_name(55).as_str(), "ba");
+    assert_eq!(mangled_name(54 * 65).as_str(), "aaa");
+  }
+}
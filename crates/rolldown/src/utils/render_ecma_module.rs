@@ -1,3 +1,4 @@
+use oxc::ast::ast::Statement;
 use rolldown_common::{EcmaModule, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_sourcemap::{collapse_sourcemaps, lines_count, RawSource, Source, SourceMapSource};
@@ -17,7 +18,7 @@ pub fn render_ecma_module(
       debug_module_id = module.debug_id
     ))));
 
-    let enable_sourcemap = !options.sourcemap.is_hidden() && !module.is_virtual();
+    let enable_sourcemap = options.sourcemap.is_some() && !module.is_virtual();
 
     // Because oxc codegen sourcemap is last of sourcemap chain,
     // If here no extra sourcemap need remapping, we using it as final module sourcemap.
@@ -54,3 +55,24 @@ pub fn render_ecma_module(
     Some(sources)
   }
 }
+
+/// Renders a module of a `System.register` chunk as the declarations hoisted out of `execute` and the rest of it. The
+/// finalizer leaves the hoisted declarations as the only top-level variable and function declarations of the module.
+pub fn render_system_module(
+  module: &EcmaModule,
+  ast: &EcmaAst,
+  source_name: &str,
+  options: &NormalizedBundlerOptions,
+) -> (Option<Vec<Box<dyn Source + Send>>>, Option<Vec<Box<dyn Source + Send>>>) {
+  let render_part = |hoisted: bool| {
+    let mut part = ast.clone_with_another_arena();
+    part.program.with_mut(|fields| {
+      fields.program.body.retain(|stmt| {
+        matches!(stmt, Statement::VariableDeclaration(_) | Statement::FunctionDeclaration(_))
+          == hoisted
+      });
+    });
+    render_ecma_module(module, &part, source_name, options)
+  };
+  (render_part(true), render_part(false))
+}
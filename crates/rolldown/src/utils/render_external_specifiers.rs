@@ -0,0 +1,22 @@
+use anyhow::Result;
+use rolldown_common::{Module, ModuleTable, NormalizedBundlerOptions};
+use rolldown_plugin::{HookRenderExternalSpecifierArgs, SharedPluginDriver};
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn render_external_specifiers(
+  plugin_driver: &SharedPluginDriver,
+  options: &NormalizedBundlerOptions,
+  module_table: &mut ModuleTable,
+) -> Result<()> {
+  for module in module_table.modules.iter_mut() {
+    let Module::External(external) = module else { continue };
+    let args =
+      HookRenderExternalSpecifierArgs { specifier: &external.name, format: &options.format };
+    // Reset specifiers rendered for a previous output when the hook keeps the specifier for this one
+    external.rendered_specifier = match plugin_driver.render_external_specifier(&args).await? {
+      Some(specifier) => specifier.into(),
+      None => external.name.clone(),
+    };
+  }
+  Ok(())
+}
@@ -1 +1,2 @@
 pub use rolldown_plugin::__inner::resolve_id_with_plugins as resolve_id;
+pub use rolldown_plugin::__inner::resolve_non_literal_dynamic_import;
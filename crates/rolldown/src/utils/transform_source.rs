@@ -10,9 +10,10 @@ pub async fn transform_source(
   source: String,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
+  is_handled_by_plugin: &mut bool,
   module_type: &mut ModuleType,
 ) -> Result<String> {
-  plugin_driver
+  let code = plugin_driver
     .transform(
       &HookTransformArgs { id: &resolved_id.id, code: &source, module_type: &ModuleType::Empty },
       sourcemap_chain,
@@ -20,5 +21,9 @@ pub async fn transform_source(
       &source,
       module_type,
     )
-    .await
+    .await?;
+  if code != source {
+    *is_handled_by_plugin = true;
+  }
+  Ok(code)
 }
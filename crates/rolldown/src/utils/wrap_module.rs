@@ -0,0 +1,126 @@
+use rolldown_common::{ModuleId, ModuleWrapper};
+use rolldown_sourcemap::{SourceMap, SourceMapBuilder};
+
+/// Applies `module_wrapper` to `code`. With `sourcemap` enabled, a sourcemap from the wrapped code back to `code` is
+/// returned as well, so it could be pushed to the module's sourcemap chain like the one of a `transform` hook.
+///
+/// The sourcemap is only generated if the wrapper keeps `code` as-is somewhere in its output, which is the case for
+/// wrappers that only add a prefix or a suffix.
+pub fn wrap_module(
+  module_wrapper: &ModuleWrapper,
+  module_id: &ModuleId,
+  code: &str,
+  sourcemap: bool,
+) -> (String, Option<SourceMap>) {
+  let wrapped = module_wrapper.call(module_id, code);
+  if !sourcemap {
+    return (wrapped, None);
+  }
+  let sourcemap = wrapped.find(code).map(|offset| {
+    let (prefix_lines, prefix_col) = line_and_col(&wrapped[..offset]);
+    let mut builder = SourceMapBuilder::default();
+    let source_id = builder.set_source_and_content(module_id.as_str(), code);
+    for (line, col) in token_starts(code) {
+      let dst_col = if line == 0 { prefix_col + col } else { col };
+      builder.add_token(prefix_lines + line, dst_col, line, col, Some(source_id), None);
+    }
+    builder.into_sourcemap()
+  });
+  (wrapped, sourcemap)
+}
+
+fn is_line_terminator(c: char) -> bool {
+  matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+fn is_identifier_part(c: char) -> bool {
+  c.is_alphanumeric() || matches!(c, '_' | '$')
+}
+
+/// The line and the UTF-16 column at the end of `text`.
+fn line_and_col(text: &str) -> (u32, u32) {
+  let mut line = 0;
+  let mut col = 0;
+  let mut chars = text.chars().peekable();
+  while let Some(c) = chars.next() {
+    if is_line_terminator(c) {
+      if c == '\r' && chars.peek() == Some(&'\n') {
+        chars.next();
+      }
+      line += 1;
+      col = 0;
+    } else {
+      #[allow(clippy::cast_possible_truncation)]
+      let len = c.len_utf16() as u32;
+      col += len;
+    }
+  }
+  (line, col)
+}
+
+/// Positions, as line and UTF-16 column, where a node of the AST could start: the start of each line and of each
+/// lexical token. Sourcemaps are looked up by the closest token before a position, so mapping these is enough to
+/// keep the columns of the codegen's mappings exact.
+fn token_starts(code: &str) -> Vec<(u32, u32)> {
+  let mut starts = vec![(0, 0)];
+  let mut line = 0;
+  let mut col = 0;
+  let mut prev = None;
+  let mut chars = code.chars().peekable();
+  while let Some(c) = chars.next() {
+    if is_line_terminator(c) {
+      if c == '\r' && chars.peek() == Some(&'\n') {
+        chars.next();
+      }
+      line += 1;
+      col = 0;
+      prev = None;
+      starts.push((line, 0));
+      continue;
+    }
+    let starts_token =
+      !c.is_whitespace() && !(is_identifier_part(c) && prev.is_some_and(is_identifier_part));
+    if starts_token && col != 0 {
+      starts.push((line, col));
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let len = c.len_utf16() as u32;
+    col += len;
+    prev = Some(c);
+  }
+  starts
+}
+
+#[test]
+fn map_wrapped_code_back_to_the_original() {
+  let module_wrapper =
+    ModuleWrapper::new(Box::new(|_, code| format!("/* before */\nlet a = 1; {code}\n/* after */")));
+  let module_id = ModuleId::new("/src/main.js");
+  let (wrapped, sourcemap) = wrap_module(&module_wrapper, &module_id, "foo(bar);\n  baz", true);
+  assert_eq!(wrapped, "/* before */\nlet a = 1; foo(bar);\n  baz\n/* after */");
+
+  let sourcemap = sourcemap.expect("the wrapper keeps the code as-is");
+  assert_eq!(sourcemap.get_sources().collect::<Vec<_>>(), vec!["/src/main.js"]);
+  assert_eq!(
+    sourcemap.get_source_contents().map(|contents| contents.collect::<Vec<_>>()),
+    Some(vec!["foo(bar);\n  baz"])
+  );
+  let lookup_table = sourcemap.generate_lookup_table();
+  let lookup = |line, col| {
+    let token = sourcemap.lookup_token(&lookup_table, line, col).expect("should be mapped");
+    (token.get_src_line(), token.get_src_col())
+  };
+  // `bar`
+  assert_eq!(lookup(1, 15), (0, 4));
+  // `baz`
+  assert_eq!(lookup(2, 2), (1, 2));
+  assert!(sourcemap.lookup_token(&lookup_table, 0, 5).is_none());
+
+  let (_, sourcemap) = wrap_module(
+    &ModuleWrapper::new(Box::new(|_, code| code.replace("foo", "bar"))),
+    &module_id,
+    "foo();",
+    true,
+  );
+  assert!(sourcemap.is_none());
+}
@@ -0,0 +1,5 @@
+import assert from 'assert'
+import { d, named } from './dist/main.mjs'
+
+assert.equal(d, 'babel default')
+assert.equal(named, 'babel named')
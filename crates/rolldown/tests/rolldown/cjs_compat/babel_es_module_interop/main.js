@@ -0,0 +1,3 @@
+import d, { named } from './babel-cjs'
+
+export { d, named }
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { cjs, dynamic } from './dist/main.mjs'
+
+assert.equal(dynamic, 1)
+assert.deepEqual(cjs, { dynamic: 1 })
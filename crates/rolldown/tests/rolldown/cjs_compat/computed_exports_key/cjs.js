@@ -0,0 +1,3 @@
+const key = ['dyn', 'amic'].join('')
+
+exports[key] = 1
@@ -0,0 +1,3 @@
+import cjs, { dynamic } from './cjs.js'
+
+export { cjs, dynamic }
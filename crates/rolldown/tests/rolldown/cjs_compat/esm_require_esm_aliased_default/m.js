@@ -0,0 +1,3 @@
+const foo = 'foo'
+
+export { foo as default }
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import d from './m'
+
+assert.strictEqual(d, 'foo')
+assert.strictEqual(require('./m').default, 'foo')
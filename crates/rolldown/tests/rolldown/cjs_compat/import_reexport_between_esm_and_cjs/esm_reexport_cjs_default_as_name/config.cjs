@@ -0,0 +1 @@
+module.exports = { port: 8080, host: 'localhost' }
@@ -0,0 +1 @@
+export { default as cfg } from './config.cjs'
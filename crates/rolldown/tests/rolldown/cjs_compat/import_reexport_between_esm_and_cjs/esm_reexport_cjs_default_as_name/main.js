@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { cfg } from './lib.js'
+
+assert.deepStrictEqual(cfg, { port: 8080, host: 'localhost' })
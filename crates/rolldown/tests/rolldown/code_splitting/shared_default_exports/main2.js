@@ -0,0 +1,4 @@
+import foo from './foo/shared'
+import bar from './bar/shared'
+
+export default [bar, foo]
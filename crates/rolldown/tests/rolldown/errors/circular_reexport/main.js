@@ -0,0 +1 @@
+export { a } from './main.js'
@@ -22,7 +22,7 @@ impl Plugin for TestPlugin {
     if args.specifier == "ext" {
       return Ok(Some(HookResolveIdOutput {
         id: "ext".to_string(),
-        external: Some(true),
+        external: Some(true.into()),
         ..Default::default()
       }));
     }
@@ -0,0 +1 @@
+export let a = 0
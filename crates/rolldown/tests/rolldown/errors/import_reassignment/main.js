@@ -0,0 +1,2 @@
+import { a } from './foo.js'
+a = 1
@@ -0,0 +1,100 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{
+  AddonOutputOption, Bundler, BundlerOptions, InputItem, OutputFormat, SourceMapType,
+};
+use rolldown_common::{Output, OutputChunk};
+use rolldown_plugin::{HookAddonArgs, HookInjectionOutputReturn, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+fn addon(kind: &'static str) -> AddonOutputOption {
+  AddonOutputOption::Fn(Box::new(move |chunk| {
+    let content = format!("/* {kind} of {} */", chunk.name);
+    Box::pin(async move { Ok(Some(content)) })
+  }))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn addons_as_functions() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      format: Some(OutputFormat::Iife),
+      name: Some("module".to_string()),
+      banner: Some(addon("banner")),
+      footer: Some(addon("footer")),
+      intro: Some(addon("intro")),
+      outro: Some(addon("outro")),
+      ..Default::default()
+    })
+    .await;
+}
+
+#[derive(Debug)]
+struct ShebangBanner;
+
+impl Plugin for ShebangBanner {
+  fn name(&self) -> Cow<'static, str> {
+    "shebang-banner".into()
+  }
+
+  async fn banner(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookAddonArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    Ok(Some("#!/usr/bin/env node".to_string()))
+  }
+}
+
+fn find_chunk(assets: &[Output]) -> &OutputChunk {
+  assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk.as_ref()),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk")
+}
+
+fn options(banner: Option<AddonOutputOption>) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    banner,
+    sourcemap: Some(SourceMapType::Hidden),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn shebang_banner_keeps_sourcemap_in_sync() {
+  let mut bundler = Bundler::new(options(Some(AddonOutputOption::String(Some(
+    "#!/usr/bin/env node\n/* license */".to_string(),
+  )))));
+  let output = bundler.generate().await.unwrap();
+  let chunk = find_chunk(&output.assets);
+  assert!(chunk.code.starts_with("#!/usr/bin/env node\n/* license */\n"), "{}", chunk.code);
+
+  // The banner takes the first two lines, so the code of `main.js` can't be mapped from them.
+  let map = chunk.map.as_ref().expect("expected a sourcemap");
+  let first_token = map.get_tokens().next().expect("expected mappings");
+  assert!(first_token.get_dst_line() >= 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn shebang_banner_from_plugin_starts_the_file() {
+  let mut bundler = Bundler::with_plugins(options(None), vec![Arc::new(ShebangBanner)]);
+  let output = bundler.generate().await.unwrap();
+  let chunk = find_chunk(&output.assets);
+  assert!(chunk.code.starts_with("#!/usr/bin/env node\n"), "{}", chunk.code);
+}
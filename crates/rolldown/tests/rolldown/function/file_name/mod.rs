@@ -0,0 +1,24 @@
+use rolldown::{BundlerOptions, FileNameFunction, InputItem, OutputFileKind};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn file_name_function_takes_precedence_over_templates() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      entry_filenames: Some("[name].js".to_string()),
+      file_name: Some(FileNameFunction::new(Box::new(|info| match info.kind {
+        OutputFileKind::Entry => "assets/[name]-[hash].mjs".to_string(),
+        OutputFileKind::Chunk => format!("chunks/{}.mjs", info.name),
+        OutputFileKind::Asset => "assets/[name][extname]".to_string(),
+      }))),
+      ..Default::default()
+    })
+    .await;
+}
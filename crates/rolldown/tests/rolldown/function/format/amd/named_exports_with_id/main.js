@@ -0,0 +1,4 @@
+import { join } from 'node:path'
+export * from './foo'
+
+console.log(join('a', 'b'))
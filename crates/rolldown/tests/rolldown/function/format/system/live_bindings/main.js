@@ -0,0 +1,4 @@
+import { join } from 'node:path'
+export { count, increment } from './counter'
+
+console.log(join('a', 'b'))
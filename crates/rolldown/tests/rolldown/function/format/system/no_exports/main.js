@@ -0,0 +1,7 @@
+function greet(name) {
+  return `hello ${name}`
+}
+
+console.log(greet('world'))
+
+export {}
@@ -0,0 +1 @@
+export const util = import('./util.js')
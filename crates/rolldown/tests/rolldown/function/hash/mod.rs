@@ -0,0 +1,43 @@
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+async fn bundle_filenames(dir: &str) -> Vec<String> {
+  let output = IntegrationTest::new(TestMeta { write_to_disk: false, ..Default::default() })
+    .bundle(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!().join(dir)),
+      entry_filenames: Some("[name].[hash].[ext]".to_string()),
+      chunk_filenames: Some("[name].[hash:10].[ext]".to_string()),
+      ..Default::default()
+    })
+    .await;
+
+  let mut filenames = output
+    .assets
+    .iter()
+    .map(|asset| match asset {
+      Output::Chunk(chunk) => chunk.filename.to_string(),
+      Output::Asset(asset) => asset.filename.clone(),
+    })
+    .collect::<Vec<_>>();
+  filenames.sort();
+  filenames
+}
+
+// `main.js` imports `lib.js`, which imports `util.js`. Changing `util.js` changes the filenames of all of them, even
+// though the content of `main.js` only refers to `lib.js`.
+#[tokio::test(flavor = "multi_thread")]
+async fn hash_changes_with_transitive_dependencies() {
+  let before = bundle_filenames("before").await;
+  let after = bundle_filenames("after").await;
+
+  assert_eq!(before.len(), 3);
+  for (before, after) in before.iter().zip(&after) {
+    assert_ne!(before, after);
+  }
+  assert!(before.iter().all(|filename| filename.ends_with(".js") && !filename.contains('[')));
+}
@@ -0,0 +1,3 @@
+import value, { named } from './babel-cjs.js'
+
+export { value, named }
@@ -0,0 +1,3 @@
+import value from './babel-cjs.js'
+
+export { value }
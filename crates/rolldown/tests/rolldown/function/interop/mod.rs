@@ -0,0 +1,58 @@
+use rolldown::{BundlerOptions, InputItem, Interop};
+use rolldown_common::Output;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+async fn bundle(entry: &str, interop: Interop) -> String {
+  let output = IntegrationTest::new(TestMeta { write_to_disk: false, ..Default::default() })
+    .bundle(BundlerOptions {
+      input: Some(vec![InputItem { name: Some("main".to_string()), import: entry.to_string() }]),
+      cwd: Some(abs_file_dir!()),
+      interop: Some(interop),
+      ..Default::default()
+    })
+    .await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  output
+    .assets
+    .into_iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk.code.clone()),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compat_uses_module_exports_of_transpiled_esm_as_namespace() {
+  // `babel-cjs.js` is known to set the `__esModule` marker, so there is nothing left for `__toESM` to check
+  for interop in [Interop::Auto, Interop::Compat] {
+    let code = bundle("./main.js", interop).await;
+    assert!(code.contains("var import_babel_cjs = require_babel_cjs();"), "{code}");
+    assert!(!code.contains("__toESM"), "{code}");
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn default_ignores_es_module_marker() {
+  let code = bundle("./main.js", Interop::Default).await;
+  assert!(code.contains("var import_babel_cjs = __toESM(require_babel_cjs(), 1);"), "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn es_module_uses_module_exports_as_namespace() {
+  let code = bundle("./main.js", Interop::EsModule).await;
+  assert!(code.contains("var import_babel_cjs = require_babel_cjs();"), "{code}");
+  assert!(!code.contains("__toESM"), "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn auto_follows_node_for_mjs_importers() {
+  let code = bundle("./main.mjs", Interop::Auto).await;
+  assert!(code.contains("var import_babel_cjs = __toESM(require_babel_cjs(), 1);"), "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn marker_is_irrelevant_without_default_import() {
+  let code = bundle("./named.js", Interop::Default).await;
+  assert!(code.contains("var import_babel_cjs = require_babel_cjs();"), "{code}");
+}
@@ -0,0 +1,3 @@
+import { named } from './babel-cjs.js'
+
+export { named }
@@ -0,0 +1,2 @@
+const Label = () => <span>button</span>
+export const Button = () => <button><Label /></button>
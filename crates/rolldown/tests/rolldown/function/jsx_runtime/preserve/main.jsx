@@ -0,0 +1,3 @@
+import { Button } from './button.jsx'
+const Label = () => <label>main</label>
+export const App = () => <div><Label /><Button /></div>
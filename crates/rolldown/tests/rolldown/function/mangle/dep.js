@@ -0,0 +1,4 @@
+export function formatMessage(message) {
+  const trimmedMessage = message.trim()
+  return `message: ${trimmedMessage}`
+}
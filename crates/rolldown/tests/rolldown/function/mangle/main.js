@@ -0,0 +1,15 @@
+import { readFileSync } from 'node:fs'
+import { formatMessage } from './dep.js'
+
+function createRegistry() {
+  return new Map()
+}
+
+const unusedRegistry = /*#__PURE__*/ createRegistry()
+
+export const registry = /*#__PURE__*/ createRegistry()
+
+export function readMessage(messagePath) {
+  const messageContent = readFileSync(messagePath, 'utf8')
+  return formatMessage(messageContent)
+}
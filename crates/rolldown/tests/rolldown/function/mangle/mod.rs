@@ -0,0 +1,59 @@
+use rolldown::{BundlerOptions, InputItem, OutputFormat};
+use rolldown_common::Output;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+async fn bundle(format: OutputFormat) -> String {
+  let output = IntegrationTest::new(TestMeta { write_to_disk: false, ..Default::default() })
+    .bundle(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      external: Some(vec!["node:fs".to_string()].into()),
+      format: Some(format),
+      mangle: Some(true),
+      ..Default::default()
+    })
+    .await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  output
+    .assets
+    .into_iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk.code.clone()),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rename_symbols_to_short_names() {
+  let code = bundle(OutputFormat::Esm).await;
+  for name in ["createRegistry", "messagePath", "messageContent", "formatMessage", "trimmedMessage"]
+  {
+    assert!(!code.contains(name), "`{name}` should be mangled:\n{code}");
+  }
+  assert!(code.contains(" as registry"), "{code}");
+  assert!(code.contains(" as readMessage"), "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keep_names_imported_from_external_modules() {
+  let code = bundle(OutputFormat::Esm).await;
+  assert!(code.contains("import { readFileSync } from \"node:fs\";"), "{code}");
+  assert!(code.contains("readFileSync("), "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keep_pure_annotations() {
+  let code = bundle(OutputFormat::Esm).await;
+  assert_eq!(code.matches("__PURE__").count(), 1, "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keep_public_names_of_exports() {
+  let code = bundle(OutputFormat::Cjs).await;
+  assert!(code.contains("Object.defineProperty(exports, 'registry'"), "{code}");
+  assert!(code.contains("Object.defineProperty(exports, 'readMessage'"), "{code}");
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem, ManualChunks};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// `vendor` would import `shared.js` from the entry chunk, which imports `vendor` in turn.
+#[tokio::test(flavor = "multi_thread")]
+async fn reject_chunk_that_breaks_execution_order() {
+  let cwd = abs_file_dir!();
+
+  let output = IntegrationTest::new(TestMeta { write_to_disk: false, ..Default::default() })
+    .bundle(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      manual_chunks: Some(ManualChunks::new(Arc::new(|module_id, _| {
+        module_id.contains("/lib/").then(|| "vendor".to_string())
+      }))),
+      ..Default::default()
+    })
+    .await;
+
+  let kinds = output.errors.iter().map(|error| error.kind().to_string()).collect::<Vec<_>>();
+  assert_eq!(kinds, ["INVALID_MANUAL_CHUNK"]);
+}
@@ -0,0 +1,3 @@
+import { c } from './lib/c.js'
+
+export const shared = c + 1
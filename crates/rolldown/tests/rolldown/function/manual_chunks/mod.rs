@@ -0,0 +1,2 @@
+mod invalid_order;
+mod vendor;
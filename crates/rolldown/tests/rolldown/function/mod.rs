@@ -0,0 +1,10 @@
+mod addons;
+mod file_name;
+mod hash;
+mod interop;
+mod mangle;
+mod manual_chunks;
+mod module_order;
+mod module_wrapper;
+mod on_module_added;
+mod sourcemap;
@@ -0,0 +1,69 @@
+use std::{borrow::Cow, sync::Arc, time::Duration};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, Plugin, PluginContext,
+};
+
+/// Serves `virtual:n`, which imports `virtual:2n+1` and `virtual:2n+2`. Loading the modules for which `is_slow`
+/// returns `true` takes longer, so their tasks finish after the tasks of the modules loaded after them.
+#[derive(Debug)]
+struct VirtualGraph {
+  is_slow: fn(usize) -> bool,
+}
+
+impl Plugin for VirtualGraph {
+  fn name(&self) -> Cow<'static, str> {
+    "virtual-graph".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(Some(HookResolveIdOutput { id: args.specifier.to_string(), ..Default::default() }))
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let n: usize = args.id.trim_start_matches("virtual:").parse()?;
+    if (self.is_slow)(n) {
+      std::thread::sleep(Duration::from_millis(20));
+    }
+    let code = [2 * n + 1, 2 * n + 2]
+      .into_iter()
+      .filter(|child| *child < 15)
+      .map(|child| format!("import 'virtual:{child}';\n"))
+      .collect();
+    Ok(Some(HookLoadOutput { code, ..Default::default() }))
+  }
+}
+
+async fn scanned_module_ids(is_slow: fn(usize) -> bool) -> Vec<String> {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "virtual:0".to_string(),
+      }]),
+      ..Default::default()
+    },
+    vec![Arc::new(VirtualGraph { is_slow })],
+  );
+  let output = bundler.scan().await.unwrap().expect("should not fail in scan");
+  output.module_table.modules.iter().map(|module| module.id().to_string()).collect()
+}
+
+// Modules get the same indices no matter which of them are loaded first.
+#[tokio::test(flavor = "multi_thread")]
+async fn module_indices_do_not_depend_on_task_order() {
+  let even_slow = scanned_module_ids(|n| n % 2 == 0).await;
+  let odd_slow = scanned_module_ids(|n| n % 2 == 1).await;
+
+  assert_eq!(even_slow, odd_slow);
+  // Each module is handled before the modules imported after it, so the indices follow the order of the imports.
+  let virtual_modules =
+    even_slow.into_iter().filter(|id| id.starts_with("virtual:")).collect::<Vec<_>>();
+  assert_eq!(virtual_modules, (0..15).map(|n| format!("virtual:{n}")).collect::<Vec<_>>());
+}
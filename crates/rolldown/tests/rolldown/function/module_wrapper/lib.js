@@ -0,0 +1,4 @@
+export const value = 1
+export default function double(n) {
+  return n * 2
+}
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import double, { value } from './lib.js'
+
+assert.strictEqual(double(value), 2)
+assert.deepStrictEqual(Object.keys(globalThis.__coverage__).sort(), ['lib.js', 'main.js'])
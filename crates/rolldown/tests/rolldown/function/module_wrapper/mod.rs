@@ -0,0 +1,71 @@
+use rolldown::{BundlerOptions, InputItem, ModuleWrapper, SourceMapType};
+use rolldown_common::Output;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use sugar_path::SugarPath;
+
+fn options(sourcemap: Option<SourceMapType>) -> BundlerOptions {
+  let cwd = abs_file_dir!();
+  let root = cwd.clone();
+
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd),
+    external: Some(vec!["node:assert".to_string()].into()),
+    sourcemap,
+    module_wrapper: Some(ModuleWrapper::new(Box::new(move |module_id, code| {
+      let name = module_id.as_path().relative(&root).to_slash_lossy().into_owned();
+      // `__coverage__` is declared in every module, which must not be mistaken for an export or clash across modules.
+      format!(
+        "const __coverage__ = (globalThis.__coverage__ ??= {{}});\n__coverage__[{name:?}] = true;\n{code}"
+      )
+    }))),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn wrap_each_module_before_scanning() {
+  IntegrationTest::new(TestMeta::default()).run(options(None)).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn map_wrapped_modules_back_to_their_code() {
+  let output = IntegrationTest::new(TestMeta { write_to_disk: false, ..Default::default() })
+    .bundle(options(Some(SourceMapType::Hidden)))
+    .await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let chunk = output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk");
+  let map = chunk.map.as_ref().expect("expected a sourcemap");
+
+  // The sources are the modules as they were loaded, without the code injected by the wrapper.
+  let main_idx = map.get_sources().position(|source| source.ends_with("main.js")).expect("main.js");
+  let main_idx = u32::try_from(main_idx).unwrap();
+  assert_eq!(map.get_source_content(main_idx), Some(include_str!("main.js")));
+
+  let line_of = |needle: &str| {
+    let line = chunk.code.lines().position(|line| line.starts_with(needle)).expect(needle);
+    u32::try_from(line).unwrap()
+  };
+
+  // Code of the module is mapped to where it is in the module, not to where it is in the wrapped code.
+  let lookup_table = map.generate_lookup_table();
+  let token = map
+    .lookup_source_view_token(&lookup_table, line_of("assert.strictEqual("), 0)
+    .expect("expected a mapping");
+  assert_eq!(token.get_source_id(), Some(main_idx));
+  assert_eq!((token.get_src_line(), token.get_src_col()), (3, 0));
+
+  // Code injected by the wrapper isn't mapped to anything.
+  let injected = line_of("__coverage__[\"main.js\"]");
+  assert!(map.get_tokens().all(|token| token.get_dst_line() != injected));
+}
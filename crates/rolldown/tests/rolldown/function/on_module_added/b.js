@@ -0,0 +1,5 @@
+import { shared } from './shared.js'
+import { ok } from 'node:assert'
+
+ok(shared)
+export const b = shared + 'b'
@@ -0,0 +1,44 @@
+use std::sync::{Arc, Mutex};
+
+use rolldown::{BundlerOptions, InputItem, OnModuleAdded};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use sugar_path::SugarPath;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fire_once_per_unique_module() {
+  let cwd = abs_file_dir!();
+  let added = Arc::new(Mutex::new(vec![]));
+
+  IntegrationTest::new(TestMeta::default())
+    .run(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd.clone()),
+      external: Some(vec!["node:assert".to_string()].into()),
+      on_module_added: Some(OnModuleAdded::new(Arc::new({
+        let added = Arc::clone(&added);
+        move |module_id| {
+          added.lock().unwrap().push(module_id.to_string());
+        }
+      }))),
+      ..Default::default()
+    })
+    .await;
+
+  let mut added = added
+    .lock()
+    .unwrap()
+    .iter()
+    .map(|id| {
+      if id.as_path().is_absolute() {
+        id.as_path().relative(&cwd).to_slash_lossy().into_owned()
+      } else {
+        id.clone()
+      }
+    })
+    .collect::<Vec<_>>();
+  added.sort();
+  assert_eq!(added, ["a.js", "b.js", "main.js", "node:assert", "shared.js"]);
+}
@@ -0,0 +1,3 @@
+import { greet } from './dep.js'
+
+console.log(greet('world'))
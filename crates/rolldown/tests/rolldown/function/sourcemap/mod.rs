@@ -0,0 +1,61 @@
+use rolldown::{BundlerOptions, InputItem, SourceMapType};
+use rolldown_common::{Output, OutputChunk};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+async fn bundle(sourcemap: SourceMapType) -> Vec<Output> {
+  let output = IntegrationTest::new(TestMeta { write_to_disk: false, ..Default::default() })
+    .bundle(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      sourcemap: Some(sourcemap),
+      ..Default::default()
+    })
+    .await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  output.assets
+}
+
+fn find_chunk(assets: &[Output]) -> &OutputChunk {
+  assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk.as_ref()),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn map_each_module_with_its_content() {
+  let assets = bundle(SourceMapType::File).await;
+  let chunk = find_chunk(&assets);
+  assert!(chunk.code.ends_with("//# sourceMappingURL=main.mjs.map"), "{}", chunk.code);
+
+  let map = chunk.map.as_ref().expect("expected a sourcemap");
+  for (file, content) in [("main.js", include_str!("main.js")), ("dep.js", include_str!("dep.js"))]
+  {
+    let index = map.get_sources().position(|source| source.ends_with(file)).expect(file);
+    let index = u32::try_from(index).unwrap();
+    assert_eq!(map.get_source_content(index), Some(content));
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hidden_sourcemap_is_emitted_without_comment() {
+  let assets = bundle(SourceMapType::Hidden).await;
+  let chunk = find_chunk(&assets);
+  assert!(chunk.map.is_some());
+  assert!(!chunk.code.contains("//# sourceMappingURL="), "{}", chunk.code);
+  assert!(assets.iter().any(|output| output.filename() == "main.mjs.map"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_sourcemap_is_appended_as_data_url() {
+  let assets = bundle(SourceMapType::Inline).await;
+  let chunk = find_chunk(&assets);
+  assert!(chunk.code.contains("//# sourceMappingURL=data:application/json;"), "{}", chunk.code);
+  assert!(!assets.iter().any(|output| output.filename().ends_with(".map")));
+}
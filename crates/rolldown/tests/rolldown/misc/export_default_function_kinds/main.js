@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import generator from './generator'
+import asyncFn from './async'
+import asyncGenerator from './async_generator'
+import namedAsyncGenerator from './named_async_generator'
+
+assert.deepStrictEqual([...generator()], [1])
+assert.strictEqual(await asyncFn(), 2)
+assert.deepStrictEqual((await asyncGenerator().next()).value, 3)
+assert.deepStrictEqual((await namedAsyncGenerator().next()).value, 'named')
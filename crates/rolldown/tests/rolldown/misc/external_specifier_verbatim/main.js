@@ -0,0 +1,2 @@
+import { a } from './vendor/./lib/../a.js'
+console.log(a)
@@ -0,0 +1,5 @@
+/*! shared v1.0.0 | MIT */
+/**
+ * @license lib
+ */
+export const lib = 'lib'
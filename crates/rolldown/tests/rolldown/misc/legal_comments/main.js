@@ -0,0 +1,6 @@
+//! main
+import { lib } from './lib.js'
+import { other } from './other.js'
+import { unused } from './unused.js'
+
+console.log(lib, other)
@@ -0,0 +1,2 @@
+/*! shared v1.0.0 | MIT */
+export const other = 'other'
@@ -0,0 +1,2 @@
+/*! unused | MIT */
+export const unused = 'unused'
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { foo, bar, fooFromImport } from './dist/main.mjs'
+
+assert.equal(foo, 'local')
+assert.equal(fooFromImport, 'local')
+assert.equal(bar, 'star')
@@ -0,0 +1,2 @@
+export const foo = 'star'
+export const bar = 'star'
@@ -0,0 +1,4 @@
+import { foo } from './shadow'
+
+export * from './shadow'
+export const fooFromImport = foo
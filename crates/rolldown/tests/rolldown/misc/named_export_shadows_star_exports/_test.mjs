@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import * as ns from './dist/main.mjs'
+
+assert.equal(ns.foo, 'main')
+assert.equal(ns.onlyA, 'a')
+// `both` is provided by two star exports with different bindings, so it's ambiguous and not exported.
+assert.equal('both' in ns, false)
@@ -0,0 +1,3 @@
+export const foo = 'a'
+export const onlyA = 'a'
+export const both = 'a'
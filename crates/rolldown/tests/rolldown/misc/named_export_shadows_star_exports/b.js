@@ -0,0 +1,2 @@
+export const foo = 'b'
+export const both = 'b'
@@ -0,0 +1,5 @@
+export * from './a'
+export * from './b'
+
+// Both `a.js` and `b.js` export `foo`, but the local export takes precedence over them.
+export const foo = 'main'
@@ -0,0 +1,9 @@
+import /* webpackIgnore: true */ './polyfill.js'
+import { value } from './lib.js'
+
+export const lazy = () => import(/* webpackIgnore: true */ './lazy.js')
+export const ignoredWithOtherOptions = () =>
+  import(/* webpackChunkName: "lazy", webpackIgnore: true */ './lazy.js')
+export const cjs = () => require(/* webpackIgnore: true */ './lib.js')
+export const bundled = () => import(/* webpackIgnore: false */ './lazy.js')
+export { value }
@@ -0,0 +1 @@
+globalThis.polyfilled = true
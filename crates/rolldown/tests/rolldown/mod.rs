@@ -1,3 +1,5 @@
 mod errors;
+mod function;
 mod issues;
 mod plugin;
+mod topics;
@@ -0,0 +1,73 @@
+use std::{
+  borrow::Cow,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginApply, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct RecordBuildStart {
+  apply: PluginApply,
+  called: Arc<AtomicBool>,
+}
+
+impl Plugin for RecordBuildStart {
+  fn name(&self) -> Cow<'static, str> {
+    "record-build-start".into()
+  }
+
+  fn apply(&self) -> PluginApply {
+    self.apply
+  }
+
+  async fn build_start(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.called.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+}
+
+/// Returns whether the `WatchOnly` and the `BuildOnly` plugin ran, in that order.
+async fn run_plugins(watch: bool) -> (bool, bool) {
+  let watch_only_called = Arc::new(AtomicBool::new(false));
+  let build_only_called = Arc::new(AtomicBool::new(false));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      watch: Some(watch),
+      ..Default::default()
+    },
+    vec![
+      Arc::new(RecordBuildStart {
+        apply: PluginApply::WatchOnly,
+        called: Arc::clone(&watch_only_called),
+      }),
+      Arc::new(RecordBuildStart {
+        apply: PluginApply::BuildOnly,
+        called: Arc::clone(&build_only_called),
+      }),
+    ],
+  );
+  bundler.try_build().await.unwrap().unwrap();
+  bundler.close().await.unwrap();
+
+  (watch_only_called.load(Ordering::SeqCst), build_only_called.load(Ordering::SeqCst))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skip_watch_only_plugins_in_build() {
+  assert_eq!(run_plugins(false).await, (false, true));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skip_build_only_plugins_in_watch_mode() {
+  assert_eq!(run_plugins(true).await, (true, false));
+}
@@ -0,0 +1,52 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{Output, RollupRenderedChunk};
+use rolldown_plugin::{HookAugmentChunkHashReturn, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+/// Mixes a value that isn't part of the code, like a build id, into the hash of each chunk.
+#[derive(Debug)]
+struct BuildId(&'static str);
+
+impl Plugin for BuildId {
+  fn name(&self) -> Cow<'static, str> {
+    "build-id".into()
+  }
+
+  async fn augment_chunk_hash(
+    &self,
+    _ctx: &PluginContext,
+    _chunk: &RollupRenderedChunk,
+  ) -> HookAugmentChunkHashReturn {
+    Ok(Some(self.0.to_string()))
+  }
+}
+
+async fn entry_file_name(build_id: &'static str) -> String {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      entry_filenames: Some("[name]-[hash].js".to_string()),
+      ..Default::default()
+    },
+    vec![Arc::new(BuildId(build_id))],
+  );
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [Output::Chunk(chunk)] = output.assets.as_slice() else {
+    panic!("expected a single chunk");
+  };
+  chunk.filename.as_str().to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mix_augmented_hash_into_file_names() {
+  let first = entry_file_name("first").await;
+  assert_eq!(first, entry_file_name("first").await);
+  assert_ne!(first, entry_file_name("second").await);
+}
@@ -0,0 +1,112 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+/// Stands in for a plugin owning a child process, which has to be shut down once.
+#[derive(Debug)]
+struct ShutDownService {
+  events: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl Plugin for ShutDownService {
+  fn name(&self) -> Cow<'static, str> {
+    "shut-down-service".into()
+  }
+
+  async fn close_watcher(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.events.lock().unwrap().push("close_watcher");
+    Ok(())
+  }
+
+  async fn close_bundle(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    // Shutting a service down usually awaits tokio I/O or timers, which need a runtime.
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    self.events.lock().unwrap().push("close_bundle");
+    Ok(())
+  }
+}
+
+fn create_bundler(events: &Arc<Mutex<Vec<&'static str>>>) -> Bundler {
+  Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(ShutDownService { events: Arc::clone(events) })],
+  )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn close_once_even_if_the_build_failed() {
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = create_bundler(&events);
+
+  let output = bundler.generate().await.unwrap();
+  assert!(!output.errors.is_empty());
+
+  bundler.close_watcher().await.unwrap();
+  bundler.close().await.unwrap();
+  bundler.close().await.unwrap();
+  let err = bundler.generate().await.unwrap_err();
+  assert_eq!(err.to_string(), "Bundler is already closed");
+  drop(bundler);
+
+  assert_eq!(*events.lock().unwrap(), ["close_watcher", "close_bundle"]);
+}
+
+async fn wait_for_close_bundle(events: &Arc<Mutex<Vec<&'static str>>>) {
+  tokio::time::timeout(Duration::from_secs(5), async {
+    while events.lock().unwrap().is_empty() {
+      tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+  })
+  .await
+  .expect("`close_bundle` should run after the bundler is dropped");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn close_when_dropped() {
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = create_bundler(&events);
+
+  bundler.generate().await.unwrap();
+  drop(bundler);
+  wait_for_close_bundle(&events).await;
+
+  assert_eq!(*events.lock().unwrap(), ["close_bundle"]);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn close_when_dropped_on_a_current_thread_runtime() {
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = create_bundler(&events);
+
+  bundler.generate().await.unwrap();
+  // Dropping must not block the only worker, which `close_bundle` needs to make progress.
+  drop(bundler);
+  wait_for_close_bundle(&events).await;
+
+  assert_eq!(*events.lock().unwrap(), ["close_bundle"]);
+}
+
+#[test]
+fn drop_outside_a_runtime() {
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = create_bundler(&events);
+
+  tokio::runtime::Runtime::new().unwrap().block_on(bundler.generate()).unwrap();
+  // There is no runtime to run `close_bundle` on, so it's skipped instead of panicking.
+  drop(bundler);
+
+  assert!(events.lock().unwrap().is_empty());
+}
@@ -0,0 +1,3 @@
+import { source } from 'lib';
+
+console.log(source);
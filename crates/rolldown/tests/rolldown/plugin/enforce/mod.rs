@@ -0,0 +1,86 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, HookTransformArgs,
+  HookTransformReturn, Plugin, PluginContext, PluginOrder, TransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Resolves `lib` to `resolved_to` if it's given, and records the modules it transforms.
+#[derive(Debug)]
+struct Recorder {
+  name: &'static str,
+  enforce: Option<PluginOrder>,
+  resolved_to: Option<&'static str>,
+  transformed: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for Recorder {
+  fn name(&self) -> Cow<'static, str> {
+    self.name.into()
+  }
+
+  fn enforce(&self) -> Option<PluginOrder> {
+    self.enforce
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(self.resolved_to.filter(|_| args.specifier == "lib").map(|resolved_to| {
+      HookResolveIdOutput {
+        id: ctx.cwd().join(resolved_to).to_string_lossy().into_owned(),
+        ..Default::default()
+      }
+    }))
+  }
+
+  async fn transform(
+    &self,
+    _ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id.ends_with("main.js") {
+      self.transformed.lock().unwrap().push(self.name.to_string());
+    }
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn run_hooks_of_enforced_plugins_first_or_last() {
+  let transformed = Arc::new(Mutex::new(Vec::new()));
+  let recorder = |name, enforce, resolved_to| {
+    Arc::new(Recorder { name, enforce, resolved_to, transformed: Arc::clone(&transformed) })
+  };
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![
+      recorder("post", Some(PluginOrder::Post), None),
+      recorder("fallback", None, Some("fallback.js")),
+      recorder("alias", Some(PluginOrder::Pre), Some("alias.js")),
+      recorder("normal", None, None),
+    ],
+  );
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  assert_eq!(*transformed.lock().unwrap(), ["alias", "fallback", "normal", "post"]);
+  let Output::Chunk(chunk) = &output.assets[0] else { panic!("expected a chunk") };
+  assert!(chunk.code.contains("\"alias\""), "{}", chunk.code);
+  assert!(!chunk.code.contains("\"fallback\""), "{}", chunk.code);
+}
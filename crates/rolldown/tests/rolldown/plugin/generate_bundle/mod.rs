@@ -0,0 +1,63 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{AssetSource, Output, OutputAsset};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct RewriteBundle;
+
+impl Plugin for RewriteBundle {
+  fn name(&self) -> Cow<'static, str> {
+    "rewrite-bundle".into()
+  }
+
+  async fn generate_bundle(
+    &self,
+    _ctx: &PluginContext,
+    bundle: &mut Vec<Output>,
+    _is_write: bool,
+  ) -> HookNoopReturn {
+    bundle.retain(|output| output.filename() != "other.js");
+    for output in bundle.iter_mut() {
+      if let Output::Chunk(chunk) = output {
+        chunk.code.push_str("// rewritten\n");
+      }
+    }
+    let manifest = format!("{:?}", bundle.iter().map(Output::filename).collect::<Vec<_>>());
+    bundle.push(Output::Asset(Box::new(OutputAsset {
+      name: Some("manifest.json".to_string()),
+      filename: "manifest.json".to_string(),
+      source: AssetSource::String(manifest),
+    })));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mutate_bundle_in_generate_bundle() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![
+        InputItem { name: Some("main".to_string()), import: "./main.js".to_string() },
+        InputItem { name: Some("other".to_string()), import: "./other.js".to_string() },
+      ]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(RewriteBundle)],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [Output::Chunk(main), Output::Asset(manifest)] = output.assets.as_slice() else {
+    panic!(
+      "unexpected bundle: {:?}",
+      output.assets.iter().map(Output::filename).collect::<Vec<_>>()
+    );
+  };
+  assert_eq!(main.filename, "main.js");
+  assert!(main.code.ends_with("// rewritten\n"), "{}", main.code);
+  assert_eq!(manifest.source.as_bytes(), br#"["main.js"]"#);
+}
@@ -0,0 +1,5 @@
+import 'virtual:pure';
+import 'virtual:impure';
+import { value } from 'virtual:mapped';
+
+console.log(value);
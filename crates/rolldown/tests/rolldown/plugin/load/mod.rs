@@ -0,0 +1,129 @@
+use std::{borrow::Cow, sync::Arc, time::Duration};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, SourceMapType};
+use rolldown_common::{side_effects::HookSideEffects, Output};
+use rolldown_error::EventKind;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, Plugin, PluginContext,
+};
+use rolldown_sourcemap::SourceMap;
+use rolldown_testing::abs_file_dir;
+
+/// Resolves `virtual:*` specifiers to modules loaded with generated code.
+#[derive(Debug)]
+struct VirtualModules;
+
+impl Plugin for VirtualModules {
+  fn name(&self) -> Cow<'static, str> {
+    "virtual-modules".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      args
+        .specifier
+        .starts_with("virtual:")
+        .then(|| HookResolveIdOutput { id: format!("\0{}", args.specifier), ..Default::default() }),
+    )
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let output = match args.id.strip_prefix('\0') {
+      Some("virtual:pure") => HookLoadOutput {
+        code: "console.log('pure');".to_string(),
+        side_effects: Some(HookSideEffects::False),
+        ..Default::default()
+      },
+      Some("virtual:impure") => {
+        HookLoadOutput { code: "console.log('impure');".to_string(), ..Default::default() }
+      }
+      Some("virtual:mapped") => HookLoadOutput {
+        code: "export const value = 1;".to_string(),
+        map: Some(
+          SourceMap::from_json_string(
+            r#"{"version":3,"sources":["mapped.ts"],"names":[],"mappings":"AAAA"}"#,
+          )
+          .expect("Should be a valid sourcemap"),
+        ),
+        ..Default::default()
+      },
+      _ => return Ok(None),
+    };
+    Ok(Some(output))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn use_side_effects_and_map_from_load() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      sourcemap: Some(SourceMapType::Hidden),
+      ..Default::default()
+    },
+    vec![Arc::new(VirtualModules)],
+  );
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let chunk = output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk");
+  // The bare import of a module without side effects is dropped.
+  assert!(!chunk.code.contains("\"pure\""), "{}", chunk.code);
+  assert!(chunk.code.contains("\"impure\""), "{}", chunk.code);
+
+  let map = chunk.map.as_ref().expect("expected a sourcemap");
+  assert!(map.get_sources().any(|source| source.ends_with("mapped.ts")));
+}
+
+/// Never finishes loading, like a plugin waiting for a service that doesn't respond.
+#[derive(Debug)]
+struct HangingLoad;
+
+impl Plugin for HangingLoad {
+  fn name(&self) -> Cow<'static, str> {
+    "hanging-load".into()
+  }
+
+  async fn load(&self, _ctx: &PluginContext, _args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    std::future::pending().await
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn abort_scanning_when_load_hangs() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      scan_timeout: Some(Duration::from_millis(100)),
+      ..Default::default()
+    },
+    vec![Arc::new(HangingLoad)],
+  );
+  let output = tokio::time::timeout(Duration::from_secs(10), bundler.generate())
+    .await
+    .expect("scanning should be aborted by `scan_timeout`")
+    .unwrap();
+
+  assert_eq!(output.errors.len(), 1, "{:?}", output.errors);
+  assert!(matches!(output.errors[0].kind(), EventKind::ScanTimeout));
+}
@@ -1 +1,19 @@
+mod apply;
+mod augment_chunk_hash;
+mod close_bundle;
+mod enforce;
+mod generate_bundle;
+mod load;
+mod module_meta;
+mod module_parsed;
 mod plugin_context;
+mod rename_exports;
+mod render_chunk;
+mod render_error;
+mod render_external_specifier;
+mod resolve_dynamic_import;
+mod resolve_file_url;
+mod resolve_id;
+mod transform;
+mod watch_change;
+mod write_bundle;
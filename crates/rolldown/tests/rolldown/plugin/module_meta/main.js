@@ -0,0 +1,3 @@
+import { version } from "vendor";
+
+console.log(version);
@@ -0,0 +1,120 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::ModuleInfo;
+use rolldown_plugin::{
+  HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn,
+  HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use serde_json::json;
+
+/// Resolves `vendor` to `vendor.js`, tagging it as a vendor module.
+#[derive(Debug)]
+struct TagVendor;
+
+impl Plugin for TagVendor {
+  fn name(&self) -> Cow<'static, str> {
+    "tag-vendor".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if args.specifier != "vendor" {
+      return Ok(None);
+    }
+    Ok(Some(HookResolveIdOutput {
+      id: abs_file_dir!().join("vendor.js").to_string_lossy().into_owned(),
+      meta: [("isVendor".to_string(), json!(true)), ("origin".to_string(), json!("resolve"))]
+        .into_iter()
+        .collect(),
+      ..Default::default()
+    }))
+  }
+}
+
+#[derive(Debug)]
+struct LoadVendor;
+
+impl Plugin for LoadVendor {
+  fn name(&self) -> Cow<'static, str> {
+    "load-vendor".into()
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if !args.id.ends_with("vendor.js") {
+      return Ok(None);
+    }
+    Ok(Some(HookLoadOutput {
+      code: std::fs::read_to_string(args.id)?,
+      meta: [("origin".to_string(), json!("load"))].into_iter().collect(),
+      ..Default::default()
+    }))
+  }
+}
+
+#[derive(Debug)]
+struct CollectModuleInfos {
+  infos: Arc<Mutex<Vec<ModuleInfo>>>,
+}
+
+impl Plugin for CollectModuleInfos {
+  fn name(&self) -> Cow<'static, str> {
+    "collect-module-infos".into()
+  }
+
+  async fn build_end(
+    &self,
+    ctx: &PluginContext,
+    _args: Option<&HookBuildEndArgs>,
+  ) -> HookNoopReturn {
+    let cwd = abs_file_dir!();
+    *self.infos.lock().unwrap() = ["main.js", "vendor.js"]
+      .iter()
+      .filter_map(|file| ctx.get_module_info(&cwd.join(file).to_string_lossy()))
+      .collect();
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn merge_meta_of_resolve_id_and_load() {
+  let infos = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![
+      Arc::new(TagVendor),
+      Arc::new(LoadVendor),
+      Arc::new(CollectModuleInfos { infos: Arc::clone(&infos) }),
+    ],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let infos = infos.lock().unwrap();
+  let [main, vendor] = infos.as_slice() else {
+    panic!("unexpected module infos: {infos:#?}");
+  };
+
+  assert!(main.meta.is_empty());
+
+  assert_eq!(vendor.meta.get("isVendor"), Some(&json!(true)));
+  assert_eq!(vendor.meta.plugin_of("isVendor"), Some("tag-vendor"));
+  // The later writer wins.
+  assert_eq!(vendor.meta.get("origin"), Some(&json!("load")));
+  assert_eq!(vendor.meta.plugin_of("origin"), Some("load-vendor"));
+}
@@ -0,0 +1,4 @@
+import { foo } from './cjs.js';
+export * from './reexported.js';
+export const value = foo;
+export default function main() {}
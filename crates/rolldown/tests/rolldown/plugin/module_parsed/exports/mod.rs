@@ -0,0 +1,60 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{ExportsKind, ModuleInfo};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug, Default)]
+struct RecordExports {
+  records: Mutex<Vec<(String, ExportsKind, Vec<String>)>>,
+}
+
+impl Plugin for RecordExports {
+  fn name(&self) -> Cow<'static, str> {
+    "record-exports".into()
+  }
+
+  async fn module_parsed(
+    &self,
+    _ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+  ) -> HookNoopReturn {
+    let name = module_info.id.rsplit(['/', '\\']).next().unwrap().to_string();
+    let exports = module_info.exports.iter().map(ToString::to_string).collect();
+    self.records.lock().unwrap().push((name, module_info.exports_kind, exports));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expose_exports_in_module_info() {
+  let plugin = Arc::new(RecordExports::default());
+
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::<RecordExports>::clone(&plugin)],
+  );
+  bundler.try_build().await.unwrap().unwrap();
+
+  let mut records = plugin.records.lock().unwrap().clone();
+  records.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  assert_eq!(
+    records,
+    vec![
+      ("cjs.js".to_string(), ExportsKind::CommonJs, vec![]),
+      ("entry.js".to_string(), ExportsKind::Esm, vec!["default".to_string(), "value".to_string()]),
+      ("reexported.js".to_string(), ExportsKind::Esm, vec!["reexported".to_string()]),
+    ]
+  );
+}
@@ -0,0 +1 @@
+export const App = (props: { name: string }) => <div>{props.name}</div>
@@ -0,0 +1,3 @@
+import { App } from './app.tsx'
+
+console.log(App)
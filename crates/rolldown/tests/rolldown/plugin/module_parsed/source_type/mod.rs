@@ -0,0 +1,64 @@
+use std::{
+  borrow::Cow,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::ModuleInfo;
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug, Default)]
+struct AssertSourceType {
+  checked: AtomicUsize,
+}
+
+impl Plugin for AssertSourceType {
+  fn name(&self) -> Cow<'static, str> {
+    "assert-source-type".into()
+  }
+
+  async fn module_parsed(
+    &self,
+    _ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+  ) -> HookNoopReturn {
+    let source_type = module_info.source_type;
+    if module_info.id.ends_with("app.tsx") {
+      assert!(source_type.is_typescript());
+      assert!(source_type.is_jsx());
+    } else {
+      assert!(source_type.is_javascript());
+      assert!(!source_type.is_jsx());
+    }
+    assert!(source_type.is_module());
+    self.checked.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expose_source_type_in_module_info() {
+  let cwd = abs_file_dir!();
+  let plugin = Arc::new(AssertSourceType::default());
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        external: Some(vec!["react/jsx-runtime".to_string()].into()),
+        ..Default::default()
+      },
+      vec![Arc::<AssertSourceType>::clone(&plugin)],
+    )
+    .await;
+
+  assert_eq!(plugin.checked.load(Ordering::Relaxed), 2);
+}
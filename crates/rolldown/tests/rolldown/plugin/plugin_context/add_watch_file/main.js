@@ -0,0 +1,3 @@
+import { template } from './template.js';
+
+console.log('main');
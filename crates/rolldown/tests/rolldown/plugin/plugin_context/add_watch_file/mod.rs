@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{HookTransformArgs, HookTransformReturn, Plugin, TransformPluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct WatchTemplate;
+
+impl Plugin for WatchTemplate {
+  fn name(&self) -> Cow<'static, str> {
+    "watch-template".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id.ends_with("template.js") {
+      ctx.inner.add_watch_file("template.html");
+    }
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_files_of_tree_shaken_modules() {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd.clone()),
+      ..Default::default()
+    },
+    vec![Arc::new(WatchTemplate)],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  // `template.js` is tree-shaken, but files it depends on are still watched.
+  let [Output::Chunk(main)] = output.assets.as_slice() else { panic!("expected a single chunk") };
+  assert!(!main.code.contains("<div>"), "{}", main.code);
+
+  let watch_files = bundler.watch_files().iter().map(ToString::to_string).collect::<Vec<_>>();
+  let expected = ["main.js", "template.html", "template.js"]
+    .map(|file| cwd.join(file).to_string_lossy().into_owned());
+  assert_eq!(watch_files, expected);
+}
@@ -0,0 +1 @@
+export const template = '<div></div>';
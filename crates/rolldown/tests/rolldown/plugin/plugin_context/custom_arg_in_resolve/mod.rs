@@ -43,7 +43,7 @@ impl Plugin for TestPluginCaller {
       if custom_resolve_ret.id == "hello, world" {
         Ok(Some(HookResolveIdOutput {
           id: "hello, world".to_string(),
-          external: Some(true),
+          external: Some(true.into()),
           ..Default::default()
         }))
       } else {
@@ -0,0 +1,226 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{EmittedAsset, EmittedChunk, EmittedFile, Output};
+use rolldown_plugin::{
+  HookNoopReturn, HookRenderChunkArgs, HookRenderChunkReturn, HookRenderStartArgs,
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, PluginContext,
+  TransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct EmitWorker {
+  reference_ids: Mutex<Vec<String>>,
+  file_names: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for EmitWorker {
+  fn name(&self) -> Cow<'static, str> {
+    "emit-worker".into()
+  }
+
+  async fn build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
+    let reference_id = ctx.emit_file(EmittedFile::Asset(EmittedAsset {
+      name: Some("style.css".to_string()),
+      file_name: None,
+      source: "body {}".to_string().into(),
+    }))?;
+    self.reference_ids.lock().unwrap().push(reference_id);
+    Ok(())
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.code.contains("new Worker(") {
+      let reference_id = ctx.inner.emit_file(EmittedFile::Chunk(EmittedChunk {
+        name: Some("worker".to_string()),
+        id: "./worker.js".to_string(),
+      }))?;
+      self.reference_ids.lock().unwrap().push(reference_id);
+    }
+    Ok(None)
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    _bundle: &mut Vec<Output>,
+    _is_write: bool,
+  ) -> HookNoopReturn {
+    let file_names = self
+      .reference_ids
+      .lock()
+      .unwrap()
+      .iter()
+      .map(|reference_id| ctx.get_file_name(reference_id).unwrap())
+      .collect();
+    *self.file_names.lock().unwrap() = file_names;
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_chunks_and_assets() {
+  let file_names = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(EmitWorker {
+      reference_ids: Mutex::default(),
+      file_names: Arc::clone(&file_names),
+    })],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let file_names = file_names.lock().unwrap().clone();
+  let [style, worker] = file_names.as_slice() else {
+    panic!("unexpected file names: {file_names:?}");
+  };
+  assert!(style.starts_with("assets/style-") && style.ends_with(".css"), "{style}");
+  assert_eq!(worker, "worker.js");
+
+  let worker_chunk = output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) if chunk.filename.as_str() == worker => Some(chunk),
+      _ => None,
+    })
+    .expect("the emitted chunk should be in the bundle");
+  assert!(worker_chunk.is_entry);
+  assert!(worker_chunk.code.contains("self.onmessage"), "{}", worker_chunk.code);
+  assert!(output.assets.iter().any(|output| output.filename() == style));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_chunk_after_modules_are_loaded() {
+  #[derive(Debug)]
+  struct EmitLate;
+
+  impl Plugin for EmitLate {
+    fn name(&self) -> Cow<'static, str> {
+      "emit-late".into()
+    }
+
+    async fn render_start(
+      &self,
+      ctx: &PluginContext,
+      _args: &HookRenderStartArgs<'_>,
+    ) -> HookNoopReturn {
+      let err = ctx
+        .emit_file(EmittedFile::Chunk(EmittedChunk { name: None, id: "./worker.js".to_string() }))
+        .unwrap_err();
+      assert!(err.to_string().contains("after all modules are loaded"), "{err}");
+      Ok(())
+    }
+  }
+
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(EmitLate)],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+}
+
+#[derive(Debug)]
+struct WorkerUrl {
+  reference_id: Mutex<Option<String>>,
+  file_names: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+impl Plugin for WorkerUrl {
+  fn name(&self) -> Cow<'static, str> {
+    "worker-url".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let url = "new URL('./worker.js', import.meta.url)";
+    if !args.code.contains(url) {
+      return Ok(None);
+    }
+    let reference_id = ctx.inner.emit_file(EmittedFile::Chunk(EmittedChunk {
+      name: Some("worker".to_string()),
+      id: "./worker.js".to_string(),
+    }))?;
+    self.file_names.lock().unwrap().push(ctx.inner.get_file_name(&reference_id));
+    let code = args.code.replace(url, &format!("import.meta.ROLLUP_FILE_URL_{reference_id}"));
+    *self.reference_id.lock().unwrap() = Some(reference_id);
+    Ok(Some(HookTransformOutput { code: Some(code), ..Default::default() }))
+  }
+
+  async fn render_chunk(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    if let Some(reference_id) = self.reference_id.lock().unwrap().as_deref() {
+      self.file_names.lock().unwrap().push(ctx.get_file_name(reference_id));
+    }
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replace_file_url_placeholders() {
+  let file_names = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(WorkerUrl {
+      reference_id: Mutex::default(),
+      file_names: Arc::clone(&file_names),
+    })],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  // Unknown while transforming, then known for each rendered chunk.
+  let file_names = file_names.lock().unwrap().clone();
+  assert_eq!(file_names[0], None);
+  assert!(
+    file_names[1..].iter().all(|name| name.as_deref() == Some("worker.js")),
+    "{file_names:?}"
+  );
+
+  let Some(Output::Chunk(main)) =
+    output.assets.iter().find(|output| output.filename() == "main.js")
+  else {
+    panic!("main.js should be in the bundle");
+  };
+  assert!(main.code.contains(r#"new URL("worker.js", import.meta.url).href"#), "{}", main.code);
+}
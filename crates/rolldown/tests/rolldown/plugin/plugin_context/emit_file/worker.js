@@ -0,0 +1 @@
+self.onmessage = (e) => console.log(e.data);
@@ -0,0 +1,4 @@
+import { dep } from './dep.js';
+import { readFile } from 'node:fs';
+
+export const main = [dep, readFile];
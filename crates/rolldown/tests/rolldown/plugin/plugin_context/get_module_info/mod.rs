@@ -0,0 +1,77 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{ExportsKind, ModuleId, ModuleInfo};
+use rolldown_plugin::{HookBuildEndArgs, HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct CollectModuleInfos {
+  ids: Vec<String>,
+  infos: Arc<Mutex<Vec<Option<ModuleInfo>>>>,
+}
+
+impl Plugin for CollectModuleInfos {
+  fn name(&self) -> Cow<'static, str> {
+    "collect-module-infos".into()
+  }
+
+  async fn build_end(
+    &self,
+    ctx: &PluginContext,
+    _args: Option<&HookBuildEndArgs>,
+  ) -> HookNoopReturn {
+    *self.infos.lock().unwrap() = self.ids.iter().map(|id| ctx.get_module_info(id)).collect();
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_module_info_in_build_end() {
+  let cwd = abs_file_dir!();
+  let main_id = cwd.join("main.js").to_string_lossy().into_owned();
+  let dep_id = cwd.join("dep.js").to_string_lossy().into_owned();
+  let infos = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      external: Some(vec!["node:fs".to_string()].into()),
+      ..Default::default()
+    },
+    vec![Arc::new(CollectModuleInfos {
+      ids: vec![main_id.clone(), dep_id.clone(), "node:fs".to_string(), "unknown".to_string()],
+      infos: Arc::clone(&infos),
+    })],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let infos = infos.lock().unwrap();
+  let [Some(main), Some(dep), Some(fs), None] = infos.as_slice() else {
+    panic!("unexpected module infos: {infos:#?}");
+  };
+
+  assert!(main.is_entry && !main.is_external);
+  assert_eq!(
+    main.imported_ids.iter().map(ModuleId::as_str).collect::<Vec<_>>(),
+    [dep_id.as_str(), "node:fs"]
+  );
+  assert_eq!(main.exports_kind, ExportsKind::Esm);
+  assert_eq!(main.exports.iter().map(ToString::to_string).collect::<Vec<_>>(), ["main"]);
+
+  assert!(!dep.is_entry);
+  assert_eq!(dep.importers.iter().map(ModuleId::as_str).collect::<Vec<_>>(), [main_id.as_str()]);
+  assert_eq!(dep.exports.iter().map(ToString::to_string).collect::<Vec<_>>(), ["dep"]);
+
+  assert!(fs.is_external && !fs.is_entry);
+  assert!(fs.code.is_none());
+  assert_eq!(fs.importers.iter().map(ModuleId::as_str).collect::<Vec<_>>(), [main_id.as_str()]);
+}
@@ -0,0 +1,107 @@
+use std::{
+  borrow::Cow,
+  fmt::Debug,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use tracing::{
+  field::{Field, Visit},
+  Event, Level, Metadata, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, Layer, Registry};
+
+#[derive(Debug)]
+struct LogPlugin;
+
+impl Plugin for LogPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "log-plugin".into()
+  }
+
+  async fn build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
+    ctx.info("info from plugin");
+    ctx.debug(String::from("debug from plugin"));
+    Ok(())
+  }
+}
+
+#[derive(Debug, PartialEq)]
+struct PluginLog {
+  level: Level,
+  plugin: String,
+  message: String,
+}
+
+#[derive(Default)]
+struct PluginLogVisitor {
+  plugin: Option<String>,
+  message: Option<String>,
+}
+
+impl Visit for PluginLogVisitor {
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    match field.name() {
+      "plugin" => self.plugin = Some(format!("{value:?}")),
+      "message" => self.message = Some(format!("{value:?}")),
+      _ => {}
+    }
+  }
+}
+
+#[derive(Clone, Default)]
+struct CollectPluginLogs(Arc<Mutex<Vec<PluginLog>>>);
+
+impl<S: Subscriber> Layer<S> for CollectPluginLogs {
+  fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+    metadata.fields().field("plugin").is_some()
+  }
+
+  fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+    let mut visitor = PluginLogVisitor::default();
+    event.record(&mut visitor);
+    if let (Some(plugin), Some(message)) = (visitor.plugin, visitor.message) {
+      self.0.lock().unwrap().push(PluginLog { level: *event.metadata().level(), plugin, message });
+    }
+  }
+}
+
+// Hooks are run on other threads, so the subscriber needs to be the global one.
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_logs_with_plugin_name() {
+  let cwd = abs_file_dir!();
+  let logs = CollectPluginLogs::default();
+  tracing::subscriber::set_global_default(Registry::default().with(logs.clone())).unwrap();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(LogPlugin)],
+    )
+    .await;
+
+  assert_eq!(
+    *logs.0.lock().unwrap(),
+    [
+      PluginLog {
+        level: Level::INFO,
+        plugin: "log-plugin".to_string(),
+        message: "info from plugin".to_string()
+      },
+      PluginLog {
+        level: Level::DEBUG,
+        plugin: "log-plugin".to_string(),
+        message: "debug from plugin".to_string()
+      },
+    ]
+  );
+}
@@ -0,0 +1,63 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct AssertMeta {
+  watch_mode: bool,
+}
+
+impl Plugin for AssertMeta {
+  fn name(&self) -> Cow<'static, str> {
+    "assert-meta".into()
+  }
+
+  async fn build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
+    let meta = ctx.meta();
+    let version_parts = meta.rolldown_version.split('.').collect::<Vec<_>>();
+    assert_eq!(version_parts.len(), 3, "{:?} isn't a semver version", meta.rolldown_version);
+    assert!(version_parts.iter().all(|part| !part.is_empty()));
+    assert!(version_parts[0].chars().all(|c| c.is_ascii_digit()));
+    assert_eq!(meta.watch_mode, self.watch_mode);
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expose_rolldown_version_in_meta() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(AssertMeta { watch_mode: false })],
+    )
+    .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expose_watch_mode_in_meta() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      watch: Some(true),
+      ..Default::default()
+    },
+    vec![Arc::new(AssertMeta { watch_mode: true })],
+  );
+  bundler.try_build().await.unwrap().unwrap();
+  bundler.close().await.unwrap();
+}
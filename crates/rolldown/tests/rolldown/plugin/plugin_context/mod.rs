@@ -1 +1,9 @@
+mod add_watch_file;
 mod custom_arg_in_resolve;
+mod emit_file;
+mod get_module_info;
+mod log;
+mod meta;
+mod parse;
+mod resolve_skip_self;
+mod warn_and_error;
@@ -0,0 +1,3 @@
+import { answer } from 'virtual:constants';
+
+console.log(answer);
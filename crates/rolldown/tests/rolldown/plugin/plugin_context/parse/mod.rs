@@ -0,0 +1,91 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, Plugin, PluginContext, PluginContextParseOptions,
+};
+use rolldown_testing::abs_file_dir;
+
+const VIRTUAL_ID: &str = "\0virtual:constants";
+
+/// Provides `virtual:constants` from generated code, which is validated by parsing it before it's loaded.
+#[derive(Debug)]
+struct GeneratedConstants {
+  code: &'static str,
+  /// Number of statements of the generated code, or the kinds of the syntax errors in it.
+  parsed: Arc<Mutex<Result<usize, Vec<String>>>>,
+}
+
+impl Plugin for GeneratedConstants {
+  fn name(&self) -> Cow<'static, str> {
+    "generated-constants".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      (args.specifier == "virtual:constants")
+        .then(|| HookResolveIdOutput { id: VIRTUAL_ID.to_string(), ..Default::default() }),
+    )
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id != VIRTUAL_ID {
+      return Ok(None);
+    }
+    match ctx.parse(self.code, PluginContextParseOptions::default()) {
+      Ok(ast) => {
+        *self.parsed.lock().unwrap() = Ok(ast.program().body.len());
+        Ok(Some(HookLoadOutput { code: self.code.to_string(), ..Default::default() }))
+      }
+      Err(errors) => {
+        *self.parsed.lock().unwrap() =
+          Err(errors.iter().map(|error| error.kind().to_string()).collect());
+        Err(errors.into_iter().next().expect("At least one error is reported").into())
+      }
+    }
+  }
+}
+
+async fn bundle(code: &'static str) -> (rolldown::BundleOutput, Result<usize, Vec<String>>) {
+  let parsed = Arc::new(Mutex::new(Ok(0)));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(GeneratedConstants { code, parsed: Arc::clone(&parsed) })],
+  );
+  let output = bundler.generate().await.unwrap();
+  let parsed = parsed.lock().unwrap().clone();
+  (output, parsed)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn parse_generated_code() {
+  let (output, parsed) = bundle("export const answer = 42;\nexport default answer;").await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  assert_eq!(parsed, Ok(2));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn report_syntax_errors_of_generated_code() {
+  let (output, parsed) = bundle("export const answer = ;").await;
+  assert_eq!(parsed, Err(vec!["PARSE_ERROR".to_string()]));
+  let [error] = output.errors.as_slice() else {
+    panic!("expected a single error: {:?}", output.errors);
+  };
+  assert_eq!(error.kind().to_string(), "UNLOADABLE_DEPENDENCY");
+  assert!(error.to_string().contains("Parse failed"), "{error}");
+}
@@ -0,0 +1,5 @@
+import { local } from './local.js';
+import { fallback } from 'fallback';
+import { external } from 'external';
+
+console.log(local, fallback, external);
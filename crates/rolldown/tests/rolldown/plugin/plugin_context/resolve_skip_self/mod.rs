@@ -0,0 +1,93 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct ExternalPackages;
+
+impl Plugin for ExternalPackages {
+  fn name(&self) -> Cow<'static, str> {
+    "external-packages".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok((args.specifier == "external").then(|| HookResolveIdOutput {
+      id: "external".to_string(),
+      external: Some(true.into()),
+      ..Default::default()
+    }))
+  }
+}
+
+/// Resolves bare specifiers to `./fallback/<specifier>.js` if nothing else could resolve them.
+#[derive(Debug)]
+struct AliasFallback {
+  externals: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for AliasFallback {
+  fn name(&self) -> Cow<'static, str> {
+    "alias-fallback".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    // Without skipping itself, this would call the hook again and again.
+    if let Ok(resolved) = ctx.resolve(args.specifier, args.importer, None).await? {
+      if resolved.is_external {
+        self.externals.lock().unwrap().push(resolved.id.to_string());
+      }
+      return Ok(Some(HookResolveIdOutput {
+        id: resolved.id.to_string(),
+        external: Some(resolved.is_external.into()),
+        ..Default::default()
+      }));
+    }
+    let fallback = ctx.cwd().join("fallback").join(format!("{}.js", args.specifier));
+    Ok(Some(HookResolveIdOutput {
+      id: fallback.to_string_lossy().into_owned(),
+      ..Default::default()
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_with_other_plugins_skipping_self() {
+  let externals = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(AliasFallback { externals: Arc::clone(&externals) }), Arc::new(ExternalPackages)],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [Output::Chunk(main)] = output.assets.as_slice() else {
+    panic!("expected a single chunk");
+  };
+  assert!(main.code.contains(r#"const local = "local""#), "{}", main.code);
+  assert!(main.code.contains(r#"const fallback = "fallback""#), "{}", main.code);
+  assert!(main.code.contains(r#"from "external""#), "{}", main.code);
+  assert_eq!(*externals.lock().unwrap(), ["external"]);
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use oxc::span::Span;
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_error::PluginLog;
+use rolldown_plugin::{HookTransformArgs, HookTransformReturn, Plugin, TransformPluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct RejectLegacy {
+  is_fatal: bool,
+}
+
+impl Plugin for RejectLegacy {
+  fn name(&self) -> Cow<'static, str> {
+    "reject-legacy".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let Some(start) = args.code.find("'legacy'") else {
+      return Ok(None);
+    };
+    let start = u32::try_from(start).unwrap();
+    let span = Span::new(start, start + u32::try_from("'legacy'".len()).unwrap());
+    let log = PluginLog {
+      message: "legacy values are deprecated".to_string(),
+      code: Some("DEPRECATED".to_string()),
+      id: Some(args.id.to_string()),
+      loc: Some((args.code.as_str().into(), span)),
+    };
+    if self.is_fatal {
+      return Err(ctx.inner.error(log));
+    }
+    ctx.inner.warn(log);
+    Ok(None)
+  }
+}
+
+fn bundler(is_fatal: bool) -> Bundler {
+  Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(RejectLegacy { is_fatal })],
+  )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warn_from_plugin() {
+  let output = bundler(false).generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [warning] = output.warnings.as_slice() else {
+    panic!("expected a single warning: {:?}", output.warnings);
+  };
+  assert_eq!(warning.kind().to_string(), "PLUGIN_WARNING");
+  let message = warning.to_string();
+  assert!(message.starts_with("[plugin reject-legacy] "), "{message}");
+  assert!(message.ends_with("legacy.js: DEPRECATED: legacy values are deprecated"), "{message}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn error_from_plugin() {
+  let output = bundler(true).generate().await.unwrap();
+  assert!(output.assets.is_empty());
+  let [error] = output.errors.as_slice() else {
+    panic!("expected a single error: {:?}", output.errors);
+  };
+  assert_eq!(error.kind().to_string(), "PLUGIN_ERROR");
+  assert!(error.to_string().starts_with("[plugin reject-legacy] "), "{error}");
+}
@@ -0,0 +1,3 @@
+import { lib_value, lib_greet } from './lib'
+
+console.log(lib_value, lib_greet)
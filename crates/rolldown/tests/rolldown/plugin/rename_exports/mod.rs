@@ -0,0 +1,49 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::{LocalExport, ModuleId};
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_rstr::Rstr;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug)]
+struct PrefixLibExports;
+
+impl Plugin for PrefixLibExports {
+  fn name(&self) -> Cow<'static, str> {
+    "prefix-lib-exports".into()
+  }
+
+  async fn rename_exports(
+    &self,
+    _ctx: &PluginContext,
+    id: &ModuleId,
+    exports: &mut FxHashMap<Rstr, LocalExport>,
+  ) -> HookNoopReturn {
+    if id.ends_with("lib.js") {
+      *exports =
+        exports.drain().map(|(name, export)| (Rstr::from(format!("lib_{name}")), export)).collect();
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn importers_resolve_prefixed_exports() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(PrefixLibExports)],
+    )
+    .await;
+}
@@ -0,0 +1,83 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  __inner::SharedPluginable, HookRenderChunkArgs, HookRenderChunkOutput, HookRenderChunkReturn,
+  Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct UseStrict;
+
+impl Plugin for UseStrict {
+  fn name(&self) -> Cow<'static, str> {
+    "use-strict".into()
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    Ok(Some(HookRenderChunkOutput { code: format!("'use strict';\n{}", args.code), map: None }))
+  }
+}
+
+#[derive(Debug)]
+struct ReplaceNodeEnv {
+  received: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for ReplaceNodeEnv {
+  fn name(&self) -> Cow<'static, str> {
+    "replace-node-env".into()
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    self.received.lock().unwrap().push(args.code.clone());
+    Ok(Some(HookRenderChunkOutput {
+      code: args.code.replace("process.env.NODE_ENV", "\"production\""),
+      map: None,
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn run_render_chunk_hooks_in_sequence() {
+  let received = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(UseStrict), Arc::new(ReplaceNodeEnv { received: Arc::clone(&received) })],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [Output::Chunk(chunk)] = output.assets.as_slice() else {
+    panic!("expected a single chunk, got {} assets", output.assets.len());
+  };
+
+  let received = received.lock().unwrap();
+  let [received] = received.as_slice() else {
+    panic!("expected render_chunk to be called once, got {}", received.len());
+  };
+  assert!(received.starts_with("'use strict';\n"), "{received}");
+  assert!(received.contains("process.env.NODE_ENV"), "{received}");
+  assert!(chunk.code.starts_with("'use strict';\n"), "{}", chunk.code);
+  assert!(chunk.code.contains("console.log(\"production\")"), "{}", chunk.code);
+}
@@ -0,0 +1,67 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat};
+use rolldown_plugin::{
+  HookNoopReturn, HookRenderChunkArgs, HookRenderChunkReturn, HookRenderErrorArgs,
+  HookRenderStartArgs, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Records the render lifecycle, failing to render any chunk.
+#[derive(Debug)]
+struct FailRendering {
+  events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for FailRendering {
+  fn name(&self) -> Cow<'static, str> {
+    "fail-rendering".into()
+  }
+
+  async fn render_start(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderStartArgs<'_>,
+  ) -> HookNoopReturn {
+    let format = if matches!(args.options.format, OutputFormat::Cjs) { "cjs" } else { "other" };
+    self.events.lock().unwrap().push(format!("render_start: {format}"));
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    Err(anyhow::anyhow!("rendering is broken"))
+  }
+
+  async fn render_error(&self, _ctx: &PluginContext, args: &HookRenderErrorArgs) -> HookNoopReturn {
+    self.events.lock().unwrap().push(format!("render_error: {}", args.error));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn call_render_error_before_returning_the_error() {
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      format: Some(OutputFormat::Cjs),
+      ..Default::default()
+    },
+    vec![Arc::new(FailRendering { events: Arc::clone(&events) })],
+  );
+  let err = bundler.generate().await.unwrap_err();
+  assert_eq!(err.to_string(), "rendering is broken");
+
+  assert_eq!(*events.lock().unwrap(), ["render_start: cjs", "render_error: rendering is broken"]);
+}
@@ -0,0 +1,4 @@
+import { value } from './lib'
+import fs from 'node:fs'
+
+console.log(value, fs)
@@ -0,0 +1,47 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, OutputFormat};
+use rolldown_plugin::{
+  HookRenderExternalSpecifierArgs, HookRenderExternalSpecifierReturn, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct AppendJsExtension;
+
+impl Plugin for AppendJsExtension {
+  fn name(&self) -> Cow<'static, str> {
+    "append-js-extension".into()
+  }
+
+  async fn render_external_specifier(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderExternalSpecifierArgs<'_>,
+  ) -> HookRenderExternalSpecifierReturn {
+    if matches!(args.format, OutputFormat::Esm) && args.specifier.starts_with('.') {
+      return Ok(Some(format!("{}.js", args.specifier)));
+    }
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn append_js_extension_to_relative_externals() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        external: Some(vec!["./lib".to_string(), "node:fs".to_string()].into()),
+        ..Default::default()
+      },
+      vec![Arc::new(AppendJsExtension)],
+    )
+    .await;
+}
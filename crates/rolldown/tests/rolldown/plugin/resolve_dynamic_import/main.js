@@ -0,0 +1,5 @@
+import './static.js';
+import('lazy');
+import('./other.js');
+import(customLoader);
+import(unresolved);
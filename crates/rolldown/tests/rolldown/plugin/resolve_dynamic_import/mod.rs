@@ -0,0 +1,103 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug, Default)]
+struct ResolveLazy {
+  dynamic_import_specifiers: Mutex<Vec<String>>,
+  resolve_id_specifiers: Mutex<Vec<String>>,
+  non_literal_specifiers: Mutex<Vec<String>>,
+}
+
+impl Plugin for ResolveLazy {
+  fn name(&self) -> Cow<'static, str> {
+    "resolve-lazy".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if !args.is_entry {
+      self.resolve_id_specifiers.lock().unwrap().push(args.specifier.to_string());
+    }
+    Ok(None)
+  }
+
+  async fn resolve_dynamic_import(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if args.is_non_literal {
+      self.non_literal_specifiers.lock().unwrap().push(args.specifier.to_string());
+      if args.specifier == "customLoader" {
+        let id = ctx.cwd().join("loader.js").to_string_lossy().to_string();
+        return Ok(Some(HookResolveIdOutput { id, ..Default::default() }));
+      }
+      return Ok(None);
+    }
+    self.dynamic_import_specifiers.lock().unwrap().push(args.specifier.to_string());
+    if args.specifier == "lazy" {
+      let id = ctx.cwd().join("lib.js").to_string_lossy().to_string();
+      return Ok(Some(HookResolveIdOutput { id, ..Default::default() }));
+    }
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fall_back_to_resolve_id_for_dynamic_imports() {
+  let plugin = Arc::new(ResolveLazy::default());
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::<ResolveLazy>::clone(&plugin)],
+  );
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let mut dynamic_import_specifiers = plugin.dynamic_import_specifiers.lock().unwrap().clone();
+  dynamic_import_specifiers.sort_unstable();
+  assert_eq!(dynamic_import_specifiers, ["./other.js", "lazy"]);
+
+  let mut resolve_id_specifiers = plugin.resolve_id_specifiers.lock().unwrap().clone();
+  resolve_id_specifiers.sort_unstable();
+  assert_eq!(resolve_id_specifiers, ["./other.js", "./static.js"]);
+
+  let filenames =
+    output.assets.iter().map(|asset| asset.filename().to_string()).collect::<Vec<_>>();
+  assert!(filenames.iter().any(|filename| filename.starts_with("lib")), "{filenames:?}");
+
+  // Non-literal requests only go through `resolve_dynamic_import`. Unresolved ones are kept as written.
+  let mut non_literal_specifiers = plugin.non_literal_specifiers.lock().unwrap().clone();
+  non_literal_specifiers.sort_unstable();
+  assert_eq!(non_literal_specifiers, ["customLoader", "unresolved"]);
+  assert!(filenames.iter().any(|filename| filename.starts_with("loader")), "{filenames:?}");
+
+  let main_chunk = output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) if chunk.is_entry => Some(chunk),
+      _ => None,
+    })
+    .expect("expected an entry chunk");
+  assert!(main_chunk.code.contains("import(\"./loader"), "{}", main_chunk.code);
+  assert!(main_chunk.code.contains("import(unresolved)"), "{}", main_chunk.code);
+}
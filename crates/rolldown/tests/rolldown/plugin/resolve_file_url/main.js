@@ -0,0 +1 @@
+export const logo = new URL('./logo.png', import.meta.url)
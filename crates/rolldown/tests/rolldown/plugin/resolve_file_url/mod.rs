@@ -0,0 +1,118 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{EmittedAsset, EmittedFile, Output};
+use rolldown_plugin::{
+  HookResolveFileUrlArgs, HookResolveFileUrlReturn, HookTransformArgs, HookTransformOutput,
+  HookTransformReturn, Plugin, PluginContext, TransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug, Clone)]
+struct ResolveFileUrlCall {
+  reference_id: String,
+  file_name: String,
+  chunk_id: String,
+  relative_path: String,
+}
+
+/// Emits `logo.png` as an asset and references it with `import.meta.ROLLUP_FILE_URL_<reference id>`.
+#[derive(Debug)]
+struct EmitLogo {
+  cdn: bool,
+  calls: Arc<Mutex<Vec<ResolveFileUrlCall>>>,
+}
+
+impl Plugin for EmitLogo {
+  fn name(&self) -> Cow<'static, str> {
+    "emit-logo".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let url = "new URL('./logo.png', import.meta.url)";
+    if !args.code.contains(url) {
+      return Ok(None);
+    }
+    let reference_id = ctx.inner.emit_file(EmittedFile::Asset(EmittedAsset {
+      name: Some("logo.png".to_string()),
+      file_name: None,
+      source: "logo".to_string().into(),
+    }))?;
+    let code = args.code.replace(url, &format!("import.meta.ROLLUP_FILE_URL_{reference_id}"));
+    Ok(Some(HookTransformOutput { code: Some(code), ..Default::default() }))
+  }
+
+  async fn resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookResolveFileUrlReturn {
+    self.calls.lock().unwrap().push(ResolveFileUrlCall {
+      reference_id: args.reference_id.to_string(),
+      file_name: args.file_name.to_string(),
+      chunk_id: args.chunk_id.to_string(),
+      relative_path: args.relative_path.to_string(),
+    });
+    Ok(self.cdn.then(|| format!("\"https://cdn.example.com/{}\"", args.file_name)))
+  }
+}
+
+async fn bundle(cdn: bool) -> (String, Vec<ResolveFileUrlCall>) {
+  let calls = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      entry_filenames: Some("js/[name].js".to_string()),
+      ..Default::default()
+    },
+    vec![Arc::new(EmitLogo { cdn, calls: Arc::clone(&calls) })],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let Some(Output::Chunk(main)) =
+    output.assets.iter().find(|output| output.filename() == "js/main.js")
+  else {
+    panic!("js/main.js should be in the bundle");
+  };
+  let calls = calls.lock().unwrap().clone();
+  (main.code.clone(), calls)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_file_url_with_plugin() {
+  let (code, calls) = bundle(true).await;
+
+  let [call] = calls.as_slice() else {
+    panic!("unexpected calls of resolve_file_url: {calls:?}");
+  };
+  let file_name = &call.file_name;
+  assert!(!call.reference_id.is_empty());
+  assert!(file_name.starts_with("assets/logo-") && file_name.ends_with(".png"), "{file_name}");
+  assert_eq!(call.chunk_id, "js/main.js");
+  assert_eq!(call.relative_path, format!("../{file_name}"));
+  assert!(code.contains(&format!("\"https://cdn.example.com/{file_name}\"")), "{code}");
+  assert!(!code.contains("ROLLUP_FILE_URL_"), "{code}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_file_url_relative_to_chunk_by_default() {
+  let (code, calls) = bundle(false).await;
+
+  let [call] = calls.as_slice() else {
+    panic!("unexpected calls of resolve_file_url: {calls:?}");
+  };
+  let url = format!("new URL(\"../{}\", import.meta.url).href", call.file_name);
+  assert!(code.contains(&url), "{code}");
+}
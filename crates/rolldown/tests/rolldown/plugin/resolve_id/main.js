@@ -0,0 +1,8 @@
+import { readFileSync } from 'node:fs';
+import { local } from './local.js';
+import { kept } from './kept.js';
+import { absolute } from './absolute.js';
+
+export * from 'externalpkg';
+
+console.log(readFileSync, local, kept, absolute);
@@ -0,0 +1,62 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{Output, ResolvedExternal};
+use rolldown_plugin::{
+  HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct Externals;
+
+impl Plugin for Externals {
+  fn name(&self) -> Cow<'static, str> {
+    "externals".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    let absolute_path = |file: &str| ctx.cwd().join(file).to_string_lossy().into_owned();
+    let (id, external) = match args.specifier {
+      "node:fs" | "externalpkg" => (args.specifier.to_string(), ResolvedExternal::Bool(true)),
+      "./kept.js" => (absolute_path("kept.js"), ResolvedExternal::Bool(true)),
+      "./absolute.js" => (absolute_path("absolute.js"), ResolvedExternal::Absolute),
+      _ => return Ok(None),
+    };
+    Ok(Some(HookResolveIdOutput { id, external: Some(external), ..Default::default() }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mark_resolved_modules_as_external() {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd.clone()),
+      ..Default::default()
+    },
+    vec![Arc::new(Externals)],
+  );
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let Output::Chunk(chunk) = &output.assets[0] else { panic!("expected a chunk") };
+  let code = &chunk.code;
+  assert!(code.contains("from \"node:fs\""), "{code}");
+  assert!(code.contains("export * from \"externalpkg\""), "{code}");
+  // Modules not marked as external are still bundled.
+  assert!(code.contains("\"local\""), "{code}");
+  assert!(!code.contains("local.js\""), "{code}");
+  // The absolute path is only kept for `ResolvedExternal::Absolute`.
+  assert!(code.contains("from \"./kept.js\""), "{code}");
+  let absolute_path = cwd.join("absolute.js").to_string_lossy().into_owned();
+  assert!(code.contains(&format!("from {absolute_path:?}")), "{code}");
+}
@@ -0,0 +1,105 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, TransformPluginContext,
+};
+use rolldown_sourcemap::SourceMap;
+use rolldown_testing::abs_file_dir;
+
+/// Prepends a comment line to each module, returning a sourcemap for it if `with_map` is set.
+#[derive(Debug)]
+struct PrependComment {
+  name: &'static str,
+  with_map: bool,
+  /// Whether a combined sourcemap was available to each call of the hook.
+  has_combined_sourcemap: Arc<Mutex<Vec<bool>>>,
+}
+
+impl Plugin for PrependComment {
+  fn name(&self) -> Cow<'static, str> {
+    self.name.into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    self.has_combined_sourcemap.lock().unwrap().push(ctx.get_combined_sourcemap().is_some());
+    let map = self.with_map.then(|| {
+      // Maps the start of each line after the comment to the start of the line before it.
+      SourceMap::from_json_string(
+        r#"{"version":3,"sources":[],"names":[],"mappings":";AAAA;AACA"}"#,
+      )
+      .expect("Should be a valid sourcemap")
+    });
+    Ok(Some(HookTransformOutput {
+      code: Some(format!("// {}\n{}", self.name, args.code)),
+      map,
+      ..Default::default()
+    }))
+  }
+}
+
+async fn bundle(with_map: bool) -> (rolldown::BundleOutput, Vec<bool>) {
+  let has_combined_sourcemap = Arc::new(Mutex::new(Vec::new()));
+  let plugin = |name| {
+    Arc::new(PrependComment {
+      name,
+      with_map,
+      has_combined_sourcemap: Arc::clone(&has_combined_sourcemap),
+    })
+  };
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![plugin("first"), plugin("second")],
+  );
+  let output = bundler.generate().await.unwrap();
+  let has_combined_sourcemap = has_combined_sourcemap.lock().unwrap().clone();
+  (output, has_combined_sourcemap)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn chain_sourcemaps_of_transforms() {
+  let (output, has_combined_sourcemap) = bundle(true).await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  assert!(output.warnings.is_empty(), "{:?}", output.warnings);
+  assert_eq!(has_combined_sourcemap, [false, true]);
+
+  let Output::Chunk(chunk) = &output.assets[0] else { panic!("expected a chunk") };
+  let map = chunk.map.as_ref().expect("expected a sourcemap");
+  let main_source_lines = map
+    .get_tokens()
+    .filter(|token| {
+      token
+        .get_source_id()
+        .and_then(|id| map.get_source(id))
+        .is_some_and(|source| source.ends_with("main.js"))
+    })
+    .map(|token| token.get_src_line())
+    .collect::<Vec<_>>();
+  // `console.log('original')` is traced back through both transforms to the first line of `main.js`.
+  assert!(!main_source_lines.is_empty());
+  assert!(main_source_lines.iter().all(|line| *line == 0), "{main_source_lines:?}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warn_about_transforms_changing_lines_without_sourcemap() {
+  let (output, _) = bundle(false).await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let kinds = output.warnings.iter().map(|warning| warning.kind().to_string()).collect::<Vec<_>>();
+  assert_eq!(kinds, ["SOURCEMAP_BROKEN", "SOURCEMAP_BROKEN"]);
+  assert!(output.warnings[0].to_string().contains("plugin \"first\""), "{}", output.warnings[0]);
+}
@@ -0,0 +1,3 @@
+import greeting from "./greeting.html";
+
+console.log(greeting);
@@ -0,0 +1,113 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  ChangeEvent, HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use rustc_hash::FxHashMap;
+
+/// Compiles `.html` files into modules exporting their content, caching the result until the file changes.
+#[derive(Debug, Default)]
+struct CompileTemplates {
+  cache: Mutex<FxHashMap<String, String>>,
+  events: Arc<Mutex<Vec<String>>>,
+}
+
+impl CompileTemplates {
+  fn record(&self, event: String) {
+    self.events.lock().unwrap().push(event);
+  }
+}
+
+impl Plugin for CompileTemplates {
+  fn name(&self) -> Cow<'static, str> {
+    "compile-templates".into()
+  }
+
+  async fn build_start(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.record("build_start".to_string());
+    Ok(())
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if !args.id.ends_with(".html") {
+      return Ok(None);
+    }
+    let file_name = Path::new(args.id).file_name().unwrap().to_string_lossy().to_string();
+    let code = self
+      .cache
+      .lock()
+      .unwrap()
+      .entry(args.id.to_string())
+      .or_insert_with(|| {
+        self.record(format!("compile: {file_name}"));
+        let html = std::fs::read_to_string(args.id).unwrap();
+        format!("export default {:?};", html.trim())
+      })
+      .clone();
+    Ok(Some(HookLoadOutput { code, ..Default::default() }))
+  }
+
+  async fn watch_change(
+    &self,
+    _ctx: &PluginContext,
+    id: &str,
+    event: ChangeEvent,
+  ) -> HookNoopReturn {
+    let file_name = Path::new(id).file_name().unwrap().to_string_lossy().to_string();
+    self.record(format!("watch_change: {event:?} {file_name}"));
+    self.cache.lock().unwrap().remove(id);
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invalidate_cached_templates_on_change() {
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(CompileTemplates { events: Arc::clone(&events), ..Default::default() })],
+  );
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty());
+  let template = bundler
+    .watch_files()
+    .into_iter()
+    .find(|file| file.ends_with("greeting.html"))
+    .expect("the template should be watched");
+
+  // Nothing changed, so the cached template is reused.
+  bundler.generate().await.unwrap();
+
+  bundler.watch_change(&template, ChangeEvent::Update).await.unwrap();
+  bundler.generate().await.unwrap();
+
+  // The cache entry of a deleted file is dropped as well, even if no module requests it anymore.
+  bundler.watch_change(&template, ChangeEvent::Delete).await.unwrap();
+
+  assert_eq!(
+    *events.lock().unwrap(),
+    [
+      "build_start",
+      "compile: greeting.html",
+      "build_start",
+      "watch_change: Update greeting.html",
+      "build_start",
+      "compile: greeting.html",
+      "watch_change: Delete greeting.html",
+    ]
+  );
+}
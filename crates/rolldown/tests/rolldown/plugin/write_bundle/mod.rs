@@ -0,0 +1,85 @@
+use std::{
+  borrow::Cow,
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{HookNoopReturn, HookWriteBundleArgs, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug, Default)]
+struct WrittenFiles {
+  dir: Option<PathBuf>,
+  files: Vec<(String, usize, Option<usize>)>,
+}
+
+#[derive(Debug)]
+struct RecordWrittenFiles {
+  written: Arc<Mutex<WrittenFiles>>,
+}
+
+impl Plugin for RecordWrittenFiles {
+  fn name(&self) -> Cow<'static, str> {
+    "record-written-files".into()
+  }
+
+  async fn write_bundle(
+    &self,
+    _ctx: &PluginContext,
+    args: &mut HookWriteBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    let mut written = self.written.lock().unwrap();
+    written.dir = Some(args.dir.to_path_buf());
+    for output in args.bundle.iter() {
+      // The length of the file on disk, to check that the hook runs after the files are written.
+      let len_on_disk = std::fs::read(args.dir.join(output.filename())).ok().map(|c| c.len());
+      written.files.push((
+        output.filename().to_string(),
+        output.content_as_bytes().len(),
+        len_on_disk,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn bundler(written: &Arc<Mutex<WrittenFiles>>) -> Bundler {
+  Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(RecordWrittenFiles { written: Arc::clone(written) })],
+  )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn run_write_bundle_after_writing_files() {
+  let written = Arc::new(Mutex::new(WrittenFiles::default()));
+  let output = bundler(&written).write().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let written = written.lock().unwrap();
+  assert_eq!(written.dir, Some(abs_file_dir!().join("dist")));
+  let [(filename, len, len_on_disk)] = written.files.as_slice() else {
+    panic!("expected a single written file, got {:?}", written.files);
+  };
+  assert_eq!(filename, "main.js");
+  assert_eq!(Some(*len), *len_on_disk);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skip_write_bundle_in_generate() {
+  let written = Arc::new(Mutex::new(WrittenFiles::default()));
+  let output = bundler(&written).generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let written = written.lock().unwrap();
+  assert!(written.dir.is_none());
+  assert!(written.files.is_empty());
+}
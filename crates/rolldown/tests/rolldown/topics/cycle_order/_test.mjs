@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import './dist/main.mjs'
+
+assert.deepEqual(globalThis.order, ['c', 'a', 'b'])
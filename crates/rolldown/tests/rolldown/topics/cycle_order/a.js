@@ -0,0 +1,3 @@
+import './b.js'
+import './c.js'
+globalThis.order = [...(globalThis.order ?? []), 'a']
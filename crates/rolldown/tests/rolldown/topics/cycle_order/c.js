@@ -0,0 +1 @@
+globalThis.order = [...(globalThis.order ?? []), 'c']
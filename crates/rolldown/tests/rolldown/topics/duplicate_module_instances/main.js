@@ -0,0 +1,4 @@
+import { value as a } from './lib/value.js'
+import { value as b } from './linked/value.js'
+
+console.log(a === b)
@@ -0,0 +1,37 @@
+use rolldown::{Bundler, BundlerOptions, InputItem, ResolveOptions};
+use rolldown_error::EventKind;
+use rolldown_testing::abs_file_dir;
+
+fn options(symlinks: bool) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    resolve: Some(ResolveOptions { symlinks: Some(symlinks), ..Default::default() }),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dedupe_symlinked_module() {
+  let mut bundler = Bundler::new(options(true));
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let value_modules =
+    link_output.module_table.modules.iter().filter(|module| module.id().ends_with("value.js"));
+  assert_eq!(value_modules.count(), 1);
+  assert!(link_output.warnings.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warn_duplicate_module_instances_without_resolving_symlinks() {
+  let mut bundler = Bundler::new(options(false));
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let [warning] = link_output.warnings.as_slice() else {
+    panic!("expected a single warning, got {:?}", link_output.warnings);
+  };
+  assert!(matches!(warning.kind(), EventKind::DuplicateModuleInstance));
+}
@@ -0,0 +1,5 @@
+import { load } from './lib.js'
+
+export const value = 'main'
+
+load()
@@ -0,0 +1,28 @@
+use rolldown::{Bundler, BundlerOptions, ExperimentalOptions, InputItem};
+use rolldown_error::{EventKind, Severity};
+use rolldown_testing::abs_file_dir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn report_dynamic_import_cycle_as_info() {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd),
+    experimental: Some(ExperimentalOptions {
+      detect_dynamic_import_cycles: Some(true),
+      ..Default::default()
+    }),
+    ..Default::default()
+  });
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  assert!(!link_output.warnings.iter().any(|w| matches!(w.kind(), EventKind::CircularDependency)));
+  let [info] = link_output.warnings.as_slice() else {
+    panic!("expected a single diagnostic, got {:?}", link_output.warnings);
+  };
+  assert!(matches!(info.kind(), EventKind::DynamicImportCycle));
+  assert!(matches!(info.severity(), Severity::Info));
+}
@@ -0,0 +1 @@
+console.log((await import('./lib.js')).a);
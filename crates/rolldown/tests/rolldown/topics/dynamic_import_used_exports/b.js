@@ -0,0 +1,2 @@
+const { b } = await import('./lib.js');
+console.log(b);
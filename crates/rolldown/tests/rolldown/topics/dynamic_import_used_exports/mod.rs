@@ -0,0 +1,29 @@
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::ModuleId;
+use rolldown_testing::abs_file_dir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn union_exports_used_by_all_dynamic_importers() {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..Default::default()
+  });
+  let output = bundler.try_build().await.unwrap().unwrap();
+
+  let lib_id = ModuleId::new(abs_file_dir!().join("lib.js").to_str().unwrap());
+  let mut used_exports = output
+    .dynamic_import_used_exports(&lib_id)
+    .expect("every importer reads named exports")
+    .into_iter()
+    .map(|name| name.as_str().to_string())
+    .collect::<Vec<_>>();
+  used_exports.sort_unstable();
+  assert_eq!(used_exports, ["a", "b"]);
+
+  let main_id = ModuleId::new(abs_file_dir!().join("main.js").to_str().unwrap());
+  assert_eq!(output.dynamic_import_used_exports(&main_id), None);
+}
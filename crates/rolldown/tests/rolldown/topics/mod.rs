@@ -0,0 +1,14 @@
+mod duplicate_module_instances;
+mod dynamic_import_cycle;
+mod dynamic_import_used_exports;
+mod module_namespace_ref;
+mod module_warnings;
+mod multiple_outputs;
+mod namespace_object;
+mod package_side_effects;
+mod resolved_module_at;
+mod runtime_module;
+mod scan_cache;
+mod top_level_await_modules;
+mod undefined_default_export;
+mod unhandled_import_attributes;
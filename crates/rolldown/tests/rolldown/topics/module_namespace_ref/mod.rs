@@ -0,0 +1,33 @@
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{Module, ModuleId};
+use rolldown_testing::abs_file_dir;
+
+fn options() -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expose_namespace_ref_created_while_scanning() {
+  let main_id = ModuleId::new(abs_file_dir!().join("main.js").to_str().unwrap());
+
+  let scan_output = Bundler::new(options()).scan().await.unwrap().unwrap();
+  let scanned_ref = scan_output
+    .module_table
+    .modules
+    .iter()
+    .filter_map(Module::as_ecma)
+    .find(|module| module.id == main_id)
+    .map(|module| module.namespace_object_ref);
+  assert!(scanned_ref.is_some());
+
+  let link_output = Bundler::new(options()).try_build().await.unwrap().unwrap();
+  assert_eq!(link_output.module_namespace_ref(&main_id), scanned_ref);
+  assert_eq!(link_output.module_namespace_ref(&ModuleId::new("missing.js")), None);
+}
@@ -0,0 +1,4 @@
+const value = 'lib'
+value = 'reassigned'
+
+export { value }
@@ -0,0 +1,26 @@
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::ModuleId;
+use rolldown_error::EventKind;
+use rolldown_testing::abs_file_dir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn attribute_const_assign_warning_to_its_module() {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    ..Default::default()
+  });
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let lib_id = ModuleId::new(cwd.join("lib.js").to_str().unwrap());
+  let main_id = ModuleId::new(cwd.join("main.js").to_str().unwrap());
+  let [warning] = link_output.module_warnings(&lib_id) else {
+    panic!("expected a single warning for lib.js, got {:?}", link_output.module_warnings(&lib_id));
+  };
+  assert!(matches!(warning.kind(), EventKind::IllegalReassignment));
+  assert!(link_output.module_warnings(&main_id).is_empty());
+}
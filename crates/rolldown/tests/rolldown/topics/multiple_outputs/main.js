@@ -0,0 +1,3 @@
+import { value } from './lib.js';
+
+export const doubled = value * 2;
@@ -0,0 +1,95 @@
+use std::{
+  borrow::Cow,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+  },
+};
+
+use oxc::{allocator::Allocator, parser::Parser, span::SourceType};
+use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  HookNoopReturn, HookRenderChunkArgs, HookRenderChunkReturn, Plugin, PluginContext,
+  __inner::SharedPluginable,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug, Default)]
+struct RecordRenderedChunks {
+  build_starts: AtomicUsize,
+  rendered: Mutex<Vec<String>>,
+}
+
+impl Plugin for RecordRenderedChunks {
+  fn name(&self) -> Cow<'static, str> {
+    "record-rendered-chunks".into()
+  }
+
+  async fn build_start(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.build_starts.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    self.rendered.lock().unwrap().push(args.code.clone());
+    Ok(None)
+  }
+}
+
+fn options(format: OutputFormat) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    format: Some(format),
+    ..Default::default()
+  }
+}
+
+fn assert_valid(code: &str, source_type: SourceType) {
+  let allocator = Allocator::default();
+  let ret = Parser::new(&allocator, code, source_type).parse();
+  assert!(ret.errors.is_empty(), "{:?}\n{code}", ret.errors);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn generate_esm_and_cjs_from_one_bundler() {
+  let plugin = Arc::new(RecordRenderedChunks::default());
+  let mut bundler = Bundler::with_plugins(
+    options(OutputFormat::Esm),
+    vec![Arc::<RecordRenderedChunks>::clone(&plugin) as SharedPluginable],
+  );
+
+  let outputs = bundler
+    .generate_outputs(vec![options(OutputFormat::Esm), options(OutputFormat::Cjs)])
+    .await
+    .unwrap();
+
+  let codes = outputs
+    .iter()
+    .map(|output| {
+      assert!(output.errors.is_empty(), "{:?}", output.errors);
+      let [Output::Chunk(chunk)] = output.assets.as_slice() else {
+        panic!("expected a single chunk, got {:?}", output.assets.len());
+      };
+      chunk.code.clone()
+    })
+    .collect::<Vec<_>>();
+  let [esm, cjs] = codes.as_slice() else { unreachable!() };
+
+  assert_valid(esm, SourceType::default().with_module(true));
+  assert!(esm.contains("export { doubled }"), "{esm}");
+  assert_valid(cjs, SourceType::default().with_script(true));
+  assert!(cjs.contains("Object.defineProperty(exports, 'doubled'"), "{cjs}");
+
+  assert_eq!(*plugin.rendered.lock().unwrap(), codes);
+  // Both outputs are generated from the same scanned and linked module graph
+  assert_eq!(plugin.build_starts.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,2 @@
+import * as ns from './lib.js';
+console.log(ns);
@@ -0,0 +1,39 @@
+use rolldown::{Bundler, BundlerOptions, InputItem, NamespaceObject};
+use rolldown_common::Output;
+use rolldown_testing::abs_file_dir;
+
+async fn bundle(namespace_object: Option<NamespaceObject>) -> String {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    namespace_object,
+    ..Default::default()
+  });
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [Output::Chunk(chunk)] = output.assets.as_slice() else {
+    panic!("expected a single chunk, got {:?}", output.assets.len());
+  };
+  chunk.code.clone()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_getters_for_every_export_by_default() {
+  let code = bundle(None).await;
+  for getter in ["count: () => count", "increment: () => increment", "name: () => name"] {
+    assert!(code.contains(getter), "{code}");
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_getters_only_for_reassigned_exports() {
+  let code = bundle(Some(NamespaceObject::Auto)).await;
+  assert!(code.contains("count: () => count"), "{code}");
+  assert!(!code.contains("increment: () => increment"), "{code}");
+  assert!(!code.contains("name: () => name"), "{code}");
+  assert!(code.contains("lib_ns.increment = increment;"), "{code}");
+  assert!(code.contains("lib_ns.name = name;"), "{code}");
+}
@@ -0,0 +1,2 @@
+import 'pkg/src/effect.js';
+import 'pkg/src/pure.js';
@@ -0,0 +1,24 @@
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_testing::abs_file_dir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn match_side_effects_patterns_relative_to_package() {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..Default::default()
+  });
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  let [Output::Chunk(chunk)] = output.assets.as_slice() else {
+    panic!("expected a single chunk, got {} assets", output.assets.len());
+  };
+  // `src/effect.js` is listed in `sideEffects`, so the bare import keeps it, while `src/pure.js` is dropped.
+  assert!(chunk.code.contains(r#"console.log("effect")"#), "{}", chunk.code);
+  assert!(!chunk.code.contains(r#"console.log("pure")"#), "{}", chunk.code);
+}
@@ -0,0 +1,28 @@
+use oxc::span::Span;
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::ModuleId;
+use rolldown_testing::abs_file_dir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_module_of_import_span() {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    ..Default::default()
+  });
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let main_id = ModuleId::new(cwd.join("main.js").to_str().unwrap());
+  let source = std::fs::read_to_string(cwd.join("main.js")).unwrap();
+  let import_end = u32::try_from(source.find(';').unwrap() + 1).unwrap();
+
+  assert_eq!(
+    link_output.resolved_module_at(&main_id, Span::new(0, import_end)),
+    Some(ModuleId::new(cwd.join("lib.js").to_str().unwrap()))
+  );
+  assert_eq!(link_output.resolved_module_at(&main_id, Span::new(0, import_end - 1)), None);
+}
@@ -0,0 +1,65 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+
+fn options() -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn runtime_module_is_identified_and_executed_first() {
+  let mut bundler = Bundler::new(options());
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let runtime_modules = link_output
+    .module_table
+    .modules
+    .iter()
+    .filter(|module| link_output.is_runtime_module(&module.id().to_string().into()))
+    .collect::<Vec<_>>();
+  let [runtime] = runtime_modules.as_slice() else {
+    panic!("expected exactly one runtime module, got {runtime_modules:?}");
+  };
+  let first_executed =
+    link_output.module_table.modules.iter().min_by_key(|module| module.exec_order()).unwrap();
+  assert_eq!(first_executed.id(), runtime.id());
+}
+
+#[derive(Debug)]
+struct AssertRuntimeModule;
+
+impl Plugin for AssertRuntimeModule {
+  fn name(&self) -> Cow<'static, str> {
+    "assert-runtime-module".into()
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    _bundle: &mut Vec<Output>,
+    _is_write: bool,
+  ) -> HookNoopReturn {
+    let module_ids = ctx.get_module_ids().unwrap();
+    let runtime_ids = module_ids.iter().filter(|id| ctx.is_runtime_module(id)).collect::<Vec<_>>();
+    assert_eq!(runtime_ids.len(), 1, "{module_ids:?}");
+    assert_eq!(module_ids.len(), 3, "{module_ids:?}");
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plugins_can_identify_runtime_module() {
+  let mut bundler = Bundler::with_plugins(options(), vec![Arc::new(AssertRuntimeModule)]);
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty());
+}
@@ -0,0 +1,4 @@
+import { greeting } from './lib.js'
+import { value } from 'virtual:changing'
+
+console.log(greeting, value)
@@ -0,0 +1,104 @@
+use std::{
+  borrow::Cow,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+  },
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, HookTransformAstArgs, HookTransformAstReturn, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Counts the modules that are parsed through the `transform_ast` hook, and serves `virtual:changing` with code that
+/// can change between builds.
+#[derive(Debug)]
+struct CountParses {
+  parses: Arc<AtomicUsize>,
+  changing_code: Arc<Mutex<String>>,
+}
+
+impl Plugin for CountParses {
+  fn name(&self) -> Cow<'static, str> {
+    "count-parses".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      (args.specifier == "virtual:changing").then(|| HookResolveIdOutput {
+        id: "\0virtual:changing".to_string(),
+        ..Default::default()
+      }),
+    )
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    Ok((args.id == "\0virtual:changing").then(|| HookLoadOutput {
+      code: self.changing_code.lock().unwrap().clone(),
+      ..Default::default()
+    }))
+  }
+
+  fn transform_ast(
+    &self,
+    _ctx: &PluginContext,
+    args: HookTransformAstArgs,
+  ) -> HookTransformAstReturn {
+    self.parses.fetch_add(1, Ordering::SeqCst);
+    Ok(args.ast)
+  }
+}
+
+fn chunk_code(output: &[Output]) -> &str {
+  output
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk.code.as_str()),
+      Output::Asset(_) => None,
+    })
+    .expect("expected a chunk")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_parse_changed_modules_again() {
+  let parses = Arc::new(AtomicUsize::new(0));
+  let changing_code = Arc::new(Mutex::new("export const value = 1".to_string()));
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(CountParses {
+      parses: Arc::clone(&parses),
+      changing_code: Arc::clone(&changing_code),
+    })],
+  );
+
+  let first = bundler.generate().await.unwrap();
+  assert!(first.errors.is_empty(), "{:?}", first.errors);
+  assert_eq!(parses.swap(0, Ordering::SeqCst), 3);
+
+  // Nothing changed, so no module is parsed again, and the copies of the cached ASTs render the same code.
+  let second = bundler.generate().await.unwrap();
+  assert_eq!(parses.swap(0, Ordering::SeqCst), 0);
+  assert_eq!(chunk_code(&second.assets), chunk_code(&first.assets));
+
+  *changing_code.lock().unwrap() = "export const value = 2".to_string();
+  let third = bundler.generate().await.unwrap();
+  assert_eq!(parses.swap(0, Ordering::SeqCst), 1);
+  assert!(chunk_code(&third.assets).contains("const value = 2"), "{}", chunk_code(&third.assets));
+
+  bundler.close().await.unwrap();
+}
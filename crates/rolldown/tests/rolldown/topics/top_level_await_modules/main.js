@@ -0,0 +1,4 @@
+import { value } from './lib.js'
+
+console.log(value)
+import './no_tla.js'
@@ -0,0 +1,46 @@
+use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat};
+use rolldown_error::EventKind;
+use rolldown_testing::abs_file_dir;
+use sugar_path::SugarPath;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_modules_using_top_level_await() {
+  let cwd = abs_file_dir!();
+
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    ..Default::default()
+  });
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let tla_modules = link_output
+    .top_level_await_modules()
+    .iter()
+    .map(|id| id.as_path().relative(&cwd).to_slash_lossy().into_owned())
+    .collect::<Vec<_>>();
+  assert_eq!(tla_modules, ["tla.js"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reject_top_level_await_in_cjs_output() {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    format: Some(OutputFormat::Cjs),
+    ..Default::default()
+  });
+  let link_output = bundler.try_build().await.unwrap().unwrap();
+
+  let [error] = link_output.errors.as_slice() else {
+    panic!("expected a single diagnostic, got {:?}", link_output.errors);
+  };
+  assert!(matches!(error.kind(), EventKind::UnsupportedTopLevelAwait));
+  assert!(error.to_string().contains("tla.js"), "{error}");
+}
@@ -0,0 +1,5 @@
+export async function load() {
+  for await (const x of []) {
+    console.log(x)
+  }
+}
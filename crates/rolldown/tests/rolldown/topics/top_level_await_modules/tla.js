@@ -0,0 +1,5 @@
+export const value = await Promise.resolve(1)
+
+export async function notTopLevel() {
+  await value
+}
@@ -0,0 +1,33 @@
+use rolldown::{Bundler, BundlerOptions, ExperimentalOptions, InputItem};
+use rolldown_error::{EventKind, Severity};
+use rolldown_testing::abs_file_dir;
+
+fn options(warn_undefined_default_export: Option<bool>) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    experimental: Some(ExperimentalOptions { warn_undefined_default_export, ..Default::default() }),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warn_about_export_default_undefined() {
+  let link_output = Bundler::new(options(Some(true))).try_build().await.unwrap().unwrap();
+
+  let [warning] = link_output.warnings.as_slice() else {
+    panic!("expected a single diagnostic, got {:?}", link_output.warnings);
+  };
+  assert!(matches!(warning.kind(), EventKind::UndefinedDefaultExport));
+  assert!(matches!(warning.severity(), Severity::Warning));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn no_warning_by_default() {
+  let link_output = Bundler::new(options(None)).try_build().await.unwrap().unwrap();
+
+  assert!(link_output.warnings.is_empty(), "{:?}", link_output.warnings);
+}
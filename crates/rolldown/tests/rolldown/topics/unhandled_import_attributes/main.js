@@ -0,0 +1,4 @@
+import styles from './styles.js' with { type: 'css' };
+import data from './data.json' with { type: 'json' };
+
+console.log(styles, data);
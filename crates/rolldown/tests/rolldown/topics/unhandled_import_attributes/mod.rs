@@ -0,0 +1,65 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_error::{EventKind, Severity};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, TransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+fn options() -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..Default::default()
+  }
+}
+
+#[derive(Debug)]
+struct TransformStyles;
+
+impl Plugin for TransformStyles {
+  fn name(&self) -> Cow<'static, str> {
+    "transform-styles".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id.ends_with("styles.js") {
+      return Ok(Some(HookTransformOutput {
+        code: Some("export default 'transformed styles';\n".to_string()),
+        ..Default::default()
+      }));
+    }
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warn_about_unhandled_import_attributes() {
+  let link_output = Bundler::new(options()).try_build().await.unwrap().unwrap();
+
+  let [warning] = link_output.warnings.as_slice() else {
+    panic!("expected a single diagnostic, got {:?}", link_output.warnings);
+  };
+  assert!(matches!(warning.kind(), EventKind::UnhandledImportAttribute));
+  assert!(matches!(warning.severity(), Severity::Warning));
+  assert!(warning.to_string().contains(r#"type: "css""#), "{warning}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn no_warning_if_a_plugin_transforms_the_module() {
+  let link_output = Bundler::with_plugins(options(), vec![Arc::new(TransformStyles)])
+    .try_build()
+    .await
+    .unwrap()
+    .unwrap();
+
+  assert!(link_output.warnings.is_empty(), "{:?}", link_output.warnings);
+}
@@ -0,0 +1,3 @@
+console.log('reachable')
+throw new Error('stop')
+console.log('unreachable')
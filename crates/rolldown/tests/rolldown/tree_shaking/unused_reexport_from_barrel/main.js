@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { a } from './barrel.js'
+
+assert.strictEqual(a, 'a')
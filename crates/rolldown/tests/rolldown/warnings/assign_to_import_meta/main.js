@@ -0,0 +1,5 @@
+import.meta.foo = 1
+import.meta['bar'] += 1
+import.meta.count++
+// Writing to nested properties is fine
+import.meta.hot.data.foo = 1
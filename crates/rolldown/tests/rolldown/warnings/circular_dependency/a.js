@@ -0,0 +1,3 @@
+import './b.js'
+
+console.log('a')
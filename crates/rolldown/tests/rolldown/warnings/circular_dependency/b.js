@@ -0,0 +1,3 @@
+import './c.js'
+
+console.log('b')
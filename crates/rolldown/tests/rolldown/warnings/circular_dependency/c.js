@@ -0,0 +1,3 @@
+import './a.js'
+
+console.log('c')
@@ -0,0 +1,4 @@
+import * as ns from './reexport.js'
+import * as star from './star.js'
+const x = 1
+export { x as "__proto__", ns, star }
@@ -0,0 +1 @@
+export { y as __proto__ } from './lib.js'
@@ -0,0 +1 @@
+export * as "__proto__" from './lib.js'
@@ -0,0 +1,3 @@
+import { unused } from './lib.js'
+
+const alsoUnused = unused
@@ -0,0 +1,4 @@
+import data from './data.json' with { type: 'json' }
+
+export { default as reexported } from './data.json'
+export { data }
@@ -59,6 +59,8 @@ pub struct BindingInputOptions {
   // preserveModules?: boolean;
   // pub preserve_symlinks: bool,
   pub shim_missing_exports: Option<bool>,
+  /// Known-safe circular dependencies, each listing its modules relative to `cwd` in execution order.
+  pub cycle_order: Option<Vec<Vec<String>>>,
   // strictDeprecations?: boolean;
   // pub treeshake: Option<bool>,
   // watch?: WatcherOptions | false;
@@ -70,6 +72,10 @@ pub struct BindingInputOptions {
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void")]
   pub on_log: BindingOnLog,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "undefined | ((id: string) => void)")]
+  pub on_module_added: Option<ThreadsafeFunction<String, (), String, false>>,
   // extra
   pub cwd: String,
   // pub builtins: BuiltinsOptions,
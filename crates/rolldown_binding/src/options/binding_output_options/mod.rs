@@ -45,7 +45,7 @@ pub struct BindingOutputOptions {
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub footer: Option<AddonOutputOption>,
-  #[napi(ts_type = "'es' | 'cjs' | 'iife'")]
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'")]
   pub format: Option<String>,
   // freeze: boolean;
   // generatedCode: NormalizedGeneratedCodeOptions;
@@ -53,7 +53,8 @@ pub struct BindingOutputOptions {
   // hoistTransitiveImports: boolean;
   // indent: true | string;
   // inlineDynamicImports: boolean;
-  // interop: GetInterop;
+  #[napi(ts_type = "'auto' | 'default' | 'esModule' | 'compat'")]
+  pub interop: Option<String>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
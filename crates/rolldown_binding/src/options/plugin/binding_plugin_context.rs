@@ -7,7 +7,7 @@ use rolldown_plugin::PluginContext;
 use crate::{types::binding_module_info::BindingModuleInfo, utils::napi_error};
 
 use super::types::{
-  binding_emitted_asset::BindingEmittedAsset,
+  binding_emitted_asset::BindingEmittedAsset, binding_emitted_chunk::BindingEmittedChunk,
   binding_plugin_context_resolve_options::BindingPluginContextResolveOptions,
 };
 
@@ -43,13 +43,24 @@ impl BindingPluginContext {
   }
 
   #[napi]
-  pub fn emit_file(&self, file: BindingEmittedAsset) -> String {
-    self.inner.emit_file(file.into())
+  pub fn emit_file(&self, file: BindingEmittedAsset) -> napi::Result<String> {
+    self
+      .inner
+      .emit_file(rolldown_common::EmittedFile::Asset(file.into()))
+      .map_err(|err| napi::Error::from_reason(err.to_string()))
+  }
+
+  #[napi]
+  pub fn emit_chunk(&self, file: BindingEmittedChunk) -> napi::Result<String> {
+    self
+      .inner
+      .emit_file(rolldown_common::EmittedFile::Chunk(file.into()))
+      .map_err(|err| napi::Error::from_reason(err.to_string()))
   }
 
   #[napi]
-  pub fn get_file_name(&self, reference_id: String) -> String {
-    self.inner.get_file_name(reference_id.as_str())
+  pub fn get_file_name(&self, reference_id: String) -> napi::Result<String> {
+    self.inner.try_get_file_name(reference_id.as_str()).map_err(napi::Error::from_reason)
   }
 
   #[napi]
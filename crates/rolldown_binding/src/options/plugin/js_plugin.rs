@@ -107,6 +107,10 @@ impl Plugin for JsPlugin {
     ctx: &rolldown_plugin::PluginContext,
     args: &rolldown_plugin::HookResolveIdArgs<'_>,
   ) -> rolldown_plugin::HookResolveIdReturn {
+    // The JS hook only receives the specifier, so it couldn't tell the source text of `import(expr)` from a path.
+    if args.is_non_literal {
+      return Ok(None);
+    }
     if let Some(cb) = &self.resolve_dynamic_import {
       Ok(
         cb.await_call((
@@ -208,6 +212,7 @@ impl Plugin for JsPlugin {
   async fn render_start(
     &self,
     ctx: &rolldown_plugin::PluginContext,
+    _args: &rolldown_plugin::HookRenderStartArgs<'_>,
   ) -> rolldown_plugin::HookNoopReturn {
     if let Some(cb) = &self.render_start {
       cb.await_call(ctx.clone().into()).await?;
@@ -377,12 +382,12 @@ impl Plugin for JsPlugin {
   async fn write_bundle(
     &self,
     ctx: &rolldown_plugin::PluginContext,
-    bundle: &mut Vec<rolldown_common::Output>,
+    args: &mut rolldown_plugin::HookWriteBundleArgs<'_>,
   ) -> rolldown_plugin::HookNoopReturn {
     if let Some(cb) = &self.write_bundle {
-      let old_bundle = UniqueArc::new(Mutex::new(mem::take(bundle)));
+      let old_bundle = UniqueArc::new(Mutex::new(mem::take(args.bundle)));
       cb.await_call((ctx.clone().into(), BindingOutputs::new(old_bundle.weak_ref()))).await?;
-      *bundle = old_bundle.into_inner().into_inner()?;
+      *args.bundle = old_bundle.into_inner().into_inner()?;
     }
     Ok(())
   }
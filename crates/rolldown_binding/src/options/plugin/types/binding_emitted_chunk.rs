@@ -0,0 +1,16 @@
+use derivative::Derivative;
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Default, Derivative)]
+#[derivative(Debug)]
+pub struct BindingEmittedChunk {
+  pub name: Option<String>,
+  pub id: String,
+}
+
+impl From<BindingEmittedChunk> for rolldown_common::EmittedChunk {
+  fn from(value: BindingEmittedChunk) -> Self {
+    Self { name: value.name, id: value.id }
+  }
+}
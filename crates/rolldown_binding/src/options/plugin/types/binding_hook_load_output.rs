@@ -1,5 +1,6 @@
 use derivative::Derivative;
 use rolldown::ModuleType;
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use super::binding_hook_side_effects::BindingHookSideEffects;
@@ -25,6 +26,7 @@ impl TryFrom<BindingHookLoadOutput> for rolldown_plugin::HookLoadOutput {
       map: value.map.map(TryInto::try_into).transpose()?,
       side_effects: value.side_effects.map(Into::into),
       module_type: value.module_type.map(|ty| ModuleType::from_str_with_fallback(ty.as_str())),
+      meta: FxHashMap::default(),
     })
   }
 }
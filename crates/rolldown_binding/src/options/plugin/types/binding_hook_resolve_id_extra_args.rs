@@ -8,6 +8,6 @@ use serde::Deserialize;
 pub struct BindingHookResolveIdExtraArgs {
   pub custom: Option<u32>,
   pub is_entry: bool,
-  #[napi(ts_type = "'import' | 'dynamic-import' | 'require-call'")]
+  #[napi(ts_type = "'import' | 'dynamic-import' | 'require-call' | 'resolve-only'")]
   pub kind: String,
 }
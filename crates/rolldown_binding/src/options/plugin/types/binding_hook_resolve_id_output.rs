@@ -1,4 +1,5 @@
 use derivative::Derivative;
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use super::binding_hook_side_effects::BindingHookSideEffects;
@@ -17,8 +18,9 @@ impl From<BindingHookResolveIdOutput> for rolldown_plugin::HookResolveIdOutput {
   fn from(value: BindingHookResolveIdOutput) -> Self {
     Self {
       id: value.id,
-      external: value.external,
+      external: value.external.map(Into::into),
       side_effects: value.side_effects.map(Into::into),
+      meta: FxHashMap::default(),
     }
   }
 }
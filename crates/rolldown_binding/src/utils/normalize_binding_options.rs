@@ -3,9 +3,10 @@ use crate::{
   options::plugin::JsPlugin,
   types::{binding_rendered_chunk::RenderedChunk, js_callback::MaybeAsyncJsCallbackExt},
 };
-use napi::bindgen_prelude::Either;
+use napi::{bindgen_prelude::Either, threadsafe_function::ThreadsafeFunctionCallMode};
 use rolldown::{
-  AddonOutputOption, BundlerOptions, IsExternal, ModuleType, OutputExports, OutputFormat, Platform,
+  AddonOutputOption, BundlerOptions, Interop, IsExternal, ModuleType, OnModuleAdded, OutputExports,
+  OutputFormat, Platform,
 };
 use rolldown_plugin::__inner::SharedPluginable;
 use rolldown_utils::indexmap::FxIndexMap;
@@ -63,6 +64,13 @@ pub fn normalize_binding_options(
     })
   });
 
+  let on_module_added = input_options.on_module_added.map(|ts_fn| {
+    OnModuleAdded::new(std::sync::Arc::new(move |module_id| {
+      // The callback is only for reporting, so the module loader doesn't wait for it.
+      ts_fn.call(module_id.to_string(), ThreadsafeFunctionCallMode::NonBlocking);
+    }))
+  });
+
   let sourcemap_ignore_list = output_options.sourcemap_ignore_list.map(|ts_fn| {
     rolldown::SourceMapIgnoreList::new(Box::new(move |source, sourcemap_path| {
       let ts_fn = ts_fn.clone();
@@ -114,6 +122,15 @@ pub fn normalize_binding_options(
       .transpose()
       .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
     shim_missing_exports: input_options.shim_missing_exports,
+    scan_timeout: None,
+    module_wrapper: None,
+    cycle_order: input_options.cycle_order,
+    fail_on_circular_dependency: None,
+    fail_on_import_reassignment: None,
+    on_module_added,
+    watch: None,
+    file_name: None,
+    manual_chunks: None,
     name: output_options.name,
     entry_filenames: output_options.entry_file_names,
     chunk_filenames: output_options.chunk_file_names,
@@ -124,6 +141,8 @@ pub fn normalize_binding_options(
       Either::A(es_module_bool) => es_module_bool.into(),
       Either::B(es_module_string) => es_module_string.into(),
     }),
+    empty_chunk: None,
+    namespace_object: None,
     banner: normalize_addon_option(output_options.banner),
     footer: normalize_addon_option(output_options.footer),
     intro: normalize_addon_option(output_options.intro),
@@ -137,17 +156,31 @@ pub fn normalize_binding_options(
       "none" => OutputExports::None,
       _ => panic!("Invalid exports: {format_str}"),
     }),
+    interop: output_options.interop.map(|interop_str| match interop_str.as_str() {
+      "auto" => Interop::Auto,
+      "default" => Interop::Default,
+      "esModule" => Interop::EsModule,
+      "compat" => Interop::Compat,
+      _ => panic!("Invalid interop: {interop_str}"),
+    }),
     format: output_options.format.map(|format_str| match format_str.as_str() {
       "es" => OutputFormat::Esm,
       "cjs" => OutputFormat::Cjs,
       "app" => OutputFormat::App,
       "iife" => OutputFormat::Iife,
+      "umd" => OutputFormat::Umd,
+      "amd" => OutputFormat::Amd,
+      "system" => OutputFormat::System,
       _ => panic!("Invalid format: {format_str}"),
     }),
     globals: output_options.globals,
+    amd: None,
     module_types,
+    jsx_runtime: None,
+    jsx_import_source: None,
     experimental: None,
     minify: output_options.minify,
+    mangle: None,
     css_entry_filenames: None,
     css_chunk_filenames: None,
     extend: output_options.extend,
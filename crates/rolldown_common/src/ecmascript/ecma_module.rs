@@ -1,19 +1,21 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use crate::side_effects::DeterminedSideEffects;
 use crate::{
-  types::ast_scopes::AstScopes, DebugStmtInfoForTreeShaking, ExportsKind, ImportRecord,
-  ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleId, ModuleIdx, ModuleInfo, NamedImport,
-  StmtInfo, StmtInfos, SymbolRef,
+  types::ast_scopes::AstScopes, DebugStmtInfoForTreeShaking, DynamicImportUse, ExportsKind,
+  ImportPattern, ImportRecord, ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleId, ModuleIdx,
+  ModuleInfo, ModuleMeta, NamedImport, StmtInfo, StmtInfos, SymbolRef,
 };
-use crate::{EcmaAstIdx, IndexModules, Module, ModuleType};
+use crate::{EcmaAstIdx, IndexModules, Interop, Module, ModuleType};
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
-use oxc::span::Span;
+use oxc::span::{CompactStr, SourceType, Span};
 use rolldown_rstr::Rstr;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)] // Using raw booleans is more clear in this case
 pub struct EcmaModule {
   pub exec_order: u32,
   pub source: ArcStr,
@@ -21,6 +23,9 @@ pub struct EcmaModule {
   pub ecma_ast_idx: Option<EcmaAstIdx>,
   pub is_user_defined_entry: bool,
   pub has_eval: bool,
+  pub has_top_level_await: bool,
+  /// Span of the first top-level `await` expression or `for await` loop.
+  pub top_level_await_span: Option<Span>,
   pub id: ModuleId,
   /// `stable_id` is calculated based on `id` to be stable across machine and os.
   pub stable_id: String,
@@ -35,14 +40,19 @@ pub struct EcmaModule {
   /// `stmt_infos[0]` represents the namespace binding statement
   pub stmt_infos: StmtInfos,
   pub import_records: IndexVec<ImportRecordIdx, ImportRecord>,
+  /// Requests that can't be resolved statically but have a static prefix or suffix. See [ImportPattern].
+  pub import_patterns: Vec<ImportPattern>,
   /// The key is the `Span` of `ImportDeclaration`, `ImportExpression`, `ExportNamedDeclaration`, `ExportAllDeclaration`
   /// and `CallExpression`(only when the callee is `require`).
   pub imports: FxHashMap<Span, ImportRecordIdx>,
   // [[StarExportEntries]] in https://tc39.es/ecma262/#sec-source-text-module-records
   pub star_exports: Vec<ImportRecordIdx>,
   pub exports_kind: ExportsKind,
-  pub scope: AstScopes,
+  /// Shared with the scan cache, since it isn't changed after scanning.
+  pub scope: Arc<AstScopes>,
   pub default_export_ref: SymbolRef,
+  /// Whether the module has a `default` export, so `default_export_ref` refers to something.
+  pub has_default_export: bool,
   pub sourcemap_chain: Vec<rolldown_sourcemap::SourceMap>,
   pub is_included: bool,
   // the ids of all modules that statically import this module
@@ -55,9 +65,46 @@ pub struct EcmaModule {
   pub dynamically_imported_ids: Vec<ModuleId>,
   pub side_effects: DeterminedSideEffects,
   pub module_type: ModuleType,
+  /// The `SourceType` the module was parsed with, e.g. whether TypeScript or JSX syntax was enabled.
+  pub source_type: SourceType,
+  /// Legal comments (`/*! ... */`, `@license`, `@preserve`) collected while scanning, in source order.
+  pub legal_comments: Vec<(Span, String)>,
+  /// Span of `exports.default = ...` if this CommonJS module is Babel-style transpiled ESM, i.e. it also sets
+  /// `exports.__esModule`. Default imports of such a module resolve to `exports.default`.
+  pub es_module_interop_default: Option<Span>,
+  /// Export names of this CommonJS module that could be enumerated statically, e.g. from
+  /// `Object.assign(exports, { a, b })`. `None` if the module isn't CommonJS or its exports are opaque.
+  pub cjs_named_exports: Option<Vec<Rstr>>,
+  /// How the namespaces of `import('...')` records are used. Records missing here are [DynamicImportUse::All].
+  pub dynamic_import_uses: FxHashMap<ImportRecordIdx, DynamicImportUse>,
+  /// Local exports written after being declared, which the namespace object has to read through getters.
+  pub mutated_exports: FxHashSet<SymbolRef>,
+  /// Spans of `import.meta` accesses keyed by their property chain, like `import.meta.url` or `import.meta.hot`.
+  pub import_meta_usage: FxHashMap<CompactStr, Vec<Span>>,
+  /// Whether a plugin loaded or transformed the module, so it may have taken care of import attributes used to
+  /// import it.
+  pub is_handled_by_plugin: bool,
+  /// Custom meta attached by plugins when resolving and loading the module.
+  pub meta: ModuleMeta,
 }
 
 impl EcmaModule {
+  /// Whether importers have to convert `module.exports` of this CommonJS module by `__toESM` to get its namespace.
+  /// Modules transpiled from ESM by Babel can be used as they are, since their default export is `exports.default`
+  /// anyway, unless `interop` ignores the `__esModule` marker for the importer.
+  pub fn requires_to_esm(
+    &self,
+    interop: Interop,
+    importer_def_format: ModuleDefFormat,
+    uses_default_export: bool,
+  ) -> bool {
+    if !interop.requires_to_esm() {
+      return false;
+    }
+    let ignores_es_module_marker = uses_default_export && interop.is_node_mode(importer_def_format);
+    self.es_module_interop_default.is_none() || ignores_es_module_marker
+  }
+
   pub fn star_export_module_ids(&self) -> impl Iterator<Item = ModuleIdx> + '_ {
     self.star_exports.iter().map(|rec_id| {
       let rec = &self.import_records[*rec_id];
@@ -82,6 +129,7 @@ impl EcmaModule {
       code: Some(self.source.clone()),
       id: self.id.clone(),
       is_entry: self.is_user_defined_entry,
+      is_external: false,
       importers: {
         let mut value = self.importers.clone();
         value.sort_unstable();
@@ -94,6 +142,15 @@ impl EcmaModule {
       },
       imported_ids: self.imported_ids.clone(),
       dynamically_imported_ids: self.dynamically_imported_ids.clone(),
+      source_type: self.source_type,
+      import_patterns: self.import_patterns.clone(),
+      exports_kind: self.exports_kind,
+      exports: {
+        let mut value = self.named_exports.keys().cloned().collect::<Vec<_>>();
+        value.sort_unstable();
+        value
+      },
+      meta: self.meta.clone(),
     }
   }
 
@@ -1,11 +1,14 @@
-use crate::{AssetSource, FileNameRenderOptions, NormalizedBundlerOptions, Output, OutputAsset};
+use crate::{
+  AssetSource, FileNameRenderOptions, ModuleId, NormalizedBundlerOptions, Output, OutputAsset,
+  OutputFileInfo, OutputFileKind,
+};
 use dashmap::{DashMap, DashSet};
 use rolldown_utils::sanitize_file_name::sanitize_file_name;
 use rolldown_utils::xxhash::xxhash_base64_url;
 use std::ffi::OsStr;
 use std::path::Path;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug)]
 pub struct EmittedAsset {
@@ -14,9 +17,29 @@ pub struct EmittedAsset {
   pub source: AssetSource,
 }
 
+/// A module to be bundled as an additional entry, like a web worker discovered while transforming.
+#[derive(Debug, Clone)]
+pub struct EmittedChunk {
+  pub name: Option<String>,
+  /// The unresolved id of the entry module, which is resolved like `input` does.
+  pub id: String,
+}
+
+#[derive(Debug)]
+pub enum EmittedFile {
+  Chunk(EmittedChunk),
+  Asset(EmittedAsset),
+}
+
 #[derive(Debug)]
 pub struct FileEmitter {
   files: DashMap<String, EmittedAsset>,
+  chunks: DashMap<String, EmittedChunk>,
+  /// Reference ids of the chunks that haven't been picked up by the module loader yet, in emitting order.
+  unloaded_chunks: Mutex<Vec<String>>,
+  chunk_module_ids: DashMap<String, ModuleId>,
+  chunk_file_names: DashMap<String, String>,
+  is_module_loading_finished: AtomicBool,
   base_reference_id: AtomicUsize,
   options: Arc<NormalizedBundlerOptions>,
   /// Mark the files that have been emitted to bundle.
@@ -27,20 +50,88 @@ impl FileEmitter {
   pub fn new(options: Arc<NormalizedBundlerOptions>) -> Self {
     Self {
       files: DashMap::default(),
+      chunks: DashMap::default(),
+      unloaded_chunks: Mutex::default(),
+      chunk_module_ids: DashMap::default(),
+      chunk_file_names: DashMap::default(),
+      is_module_loading_finished: AtomicBool::new(false),
       base_reference_id: AtomicUsize::new(0),
       options,
       emitted_files: DashSet::default(),
     }
   }
 
-  pub fn emit_file(&self, mut file: EmittedAsset) -> String {
+  pub fn emit_file(&self, file: EmittedFile) -> anyhow::Result<String> {
+    match file {
+      EmittedFile::Chunk(chunk) => self.emit_chunk(chunk),
+      EmittedFile::Asset(asset) => Ok(self.emit_asset(asset)),
+    }
+  }
+
+  pub fn emit_asset(&self, mut file: EmittedAsset) -> String {
     let reference_id = self.assign_reference_id(file.file_name.clone());
     self.generate_file_name(&mut file);
     self.files.insert(reference_id.clone(), file);
     reference_id
   }
 
+  /// Emitted chunks become entries of the module graph, so they could only be emitted until all modules are loaded.
+  pub fn emit_chunk(&self, chunk: EmittedChunk) -> anyhow::Result<String> {
+    if self.is_module_loading_finished.load(Ordering::Relaxed) {
+      return Err(anyhow::format_err!(
+        "Unable to emit chunk {:?}: chunks could not be emitted after all modules are loaded.",
+        chunk.id
+      ));
+    }
+    let reference_id = self.assign_reference_id(None);
+    self.chunks.insert(reference_id.clone(), chunk);
+    self.unloaded_chunks.lock().unwrap().push(reference_id.clone());
+    Ok(reference_id)
+  }
+
+  /// Takes the chunks emitted since the last call, with their reference ids, in emitting order.
+  pub fn take_unloaded_chunks(&self) -> Vec<(String, EmittedChunk)> {
+    std::mem::take(&mut *self.unloaded_chunks.lock().unwrap())
+      .into_iter()
+      .filter_map(|reference_id| {
+        let chunk = self.chunks.get(&reference_id)?.clone();
+        Some((reference_id, chunk))
+      })
+      .collect()
+  }
+
+  pub fn set_chunk_module_id(&self, reference_id: &str, module_id: ModuleId) {
+    self.chunk_module_ids.insert(reference_id.to_string(), module_id);
+  }
+
+  /// Lets [FileEmitter::add_additional_files] add the emitted files again, to the bundle of another output.
+  pub fn clear_added_files(&self) {
+    self.emitted_files.clear();
+  }
+
+  pub fn set_module_loading_finished(&self, is_finished: bool) {
+    self.is_module_loading_finished.store(is_finished, Ordering::Relaxed);
+  }
+
+  /// Records the file names of the chunks generated for emitted chunks, looked up by their entry module ids. It's
+  /// called with preliminary file names before chunks are rendered and again with the final ones.
+  pub fn set_chunk_file_names(&self, file_name_of: impl Fn(&ModuleId) -> Option<String>) {
+    for item in &self.chunk_module_ids {
+      let (reference_id, module_id) = item.pair();
+      if let Some(file_name) = file_name_of(module_id) {
+        self.chunk_file_names.insert(reference_id.clone(), file_name);
+      }
+    }
+  }
+
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<String, String> {
+    if self.chunks.contains_key(reference_id) {
+      return self.chunk_file_names.get(reference_id).map(|file_name| file_name.clone()).ok_or(
+        format!(
+          "Unable to get file name for emitted chunk {reference_id}: file names of chunks are only available after chunks are generated"
+        ),
+      );
+    }
     let file = self
       .files
       .get(reference_id)
@@ -52,12 +143,14 @@ impl FileEmitter {
     self.try_get_file_name(reference_id).unwrap()
   }
 
+  /// Reference ids are valid identifier parts, so they could be used in `import.meta.ROLLUP_FILE_URL_<id>`.
   pub fn assign_reference_id(&self, filename: Option<String>) -> String {
     xxhash_base64_url(
       filename
         .unwrap_or_else(|| self.base_reference_id.fetch_add(1, Ordering::Relaxed).to_string())
         .as_bytes(),
     )
+    .replace('-', "$")
   }
 
   pub fn generate_file_name(&self, file: &mut EmittedAsset) {
@@ -67,7 +160,14 @@ impl FileEmitter {
       let name = path
         .and_then(|x| x.file_stem().and_then(OsStr::to_str))
         .map(|x| sanitize_file_name(x.into()));
-      let file_name = self.options.asset_filenames.render(&FileNameRenderOptions {
+      let custom_template = self.options.file_name.as_ref().map(|file_name| {
+        file_name.call(&OutputFileInfo {
+          name: name.as_deref().unwrap_or(""),
+          kind: OutputFileKind::Asset,
+        })
+      });
+      let template = custom_template.as_ref().unwrap_or(&self.options.asset_filenames);
+      let file_name = template.render(&FileNameRenderOptions {
         name: name.as_deref(),
         hash: Some(&xxhash_base64_url(file.source.as_bytes()).as_str()[..8]),
         ext: extension,
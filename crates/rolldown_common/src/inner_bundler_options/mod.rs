@@ -1,5 +1,5 @@
 use rolldown_utils::indexmap::FxIndexMap;
-use std::{collections::HashMap, fmt::Debug, path::PathBuf};
+use std::{collections::HashMap, fmt::Debug, path::PathBuf, time::Duration};
 use types::inject_import::InjectImport;
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -12,7 +12,10 @@ use types::experimental_options::ExperimentalOptions;
 
 use self::types::treeshake::TreeshakeOptions;
 use self::types::{
-  es_module_flag::EsModuleFlag, input_item::InputItem, is_external::IsExternal,
+  amd_options::AmdOptions, empty_chunk_behavior::EmptyChunkBehavior, es_module_flag::EsModuleFlag,
+  file_name_function::FileNameFunction, input_item::InputItem, interop::Interop,
+  is_external::IsExternal, jsx_runtime::JsxRuntime, manual_chunks::ManualChunks,
+  module_wrapper::ModuleWrapper, namespace_object::NamespaceObject, on_module_added::OnModuleAdded,
   output_exports::OutputExports, output_format::OutputFormat, output_option::AddonOutputOption,
   platform::Platform, resolve_options::ResolveOptions, source_map_type::SourceMapType,
   sourcemap_path_transform::SourceMapPathTransform,
@@ -39,6 +42,42 @@ pub struct BundlerOptions {
   pub external: Option<IsExternal>,
   pub platform: Option<Platform>,
   pub shim_missing_exports: Option<bool>,
+  /// Abort the build if scanning the module graph takes longer than this, even if a plugin hook never returns. In the
+  /// config file, it's given in milliseconds.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_scan_timeout"),
+    schemars(with = "Option<u64>")
+  )]
+  pub scan_timeout: Option<Duration>,
+  /// Rewrites the code of each module before it's scanned, e.g. to add instrumentation for code coverage. It receives
+  /// the id and the code of the module, after non-JavaScript modules have been converted to JavaScript.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub module_wrapper: Option<ModuleWrapper>,
+  /// Circular dependencies that are known to be safe, with the order their modules should be executed in. Each item
+  /// lists the modules of one cycle, relative to `cwd`. When exactly these modules form a cycle, they are executed in
+  /// the listed order and no `CIRCULAR_DEPENDENCY` warning is emitted for it.
+  pub cycle_order: Option<Vec<Vec<String>>>,
+  /// Report circular dependencies as errors that fail the build instead of warnings. Cycles allowed by `cycle_order`
+  /// are still accepted. Defaults to `false`.
+  pub fail_on_circular_dependency: Option<bool>,
+  /// Report writes to imported bindings, like `a = 1` in `import { a } from './foo'`, as errors that fail the build
+  /// instead of warnings. Such writes throw at runtime. Defaults to `true`.
+  pub fail_on_import_reassignment: Option<bool>,
+  /// Called each time a module is added to the module graph, e.g. to report progress of long builds.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub on_module_added: Option<OnModuleAdded>,
+  /// Whether the bundler is driven by a watcher that rebuilds when files change. Plugins see it through
+  /// `PluginContext::meta`, and it decides which plugins `Plugin::apply` enables. Defaults to `false`.
+  pub watch: Option<bool>,
   // --- options for output
   pub name: Option<String>,
   pub entry_filenames: Option<String>,
@@ -46,12 +85,36 @@ pub struct BundlerOptions {
   pub css_entry_filenames: Option<String>,
   pub css_chunk_filenames: Option<String>,
   pub asset_filenames: Option<String>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub file_name: Option<FileNameFunction>,
+  /// Groups modules into chunks named by the function, instead of the chunks they'd be put into by code splitting.
+  /// Ignored by the formats that always output a single chunk.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub manual_chunks: Option<ManualChunks>,
   pub dir: Option<String>,
   pub format: Option<OutputFormat>,
   pub exports: Option<OutputExports>,
+  /// Defaults to `Interop::Auto`.
+  pub interop: Option<Interop>,
   pub globals: Option<HashMap<String, String>>,
+  /// Only used when `format` is `OutputFormat::Amd`.
+  pub amd: Option<AmdOptions>,
+  /// Sourcemaps are only generated if this is set. `SourceMapType::Hidden` emits the `.map` file without referring to it
+  /// by a `//# sourceMappingURL` comment.
   pub sourcemap: Option<SourceMapType>,
   pub es_module: Option<EsModuleFlag>,
+  /// Defaults to `EmptyChunkBehavior::Ignore`.
+  pub empty_chunk: Option<EmptyChunkBehavior>,
+  /// Defaults to `NamespaceObject::Live`.
+  pub namespace_object: Option<NamespaceObject>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_addon"),
@@ -91,6 +154,11 @@ pub struct BundlerOptions {
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
   pub module_types: Option<HashMap<String, ModuleType>>,
+  /// Defaults to `JsxRuntime::Automatic`.
+  pub jsx_runtime: Option<JsxRuntime>,
+  /// The package providing `jsx-runtime` for `JsxRuntime::Automatic`, like `preact` for `preact/jsx-runtime`. A
+  /// `/** @jsxImportSource ... */` comment in a module still takes precedence. Defaults to `react`.
+  pub jsx_import_source: Option<String>,
   // --- options for resolve
   pub resolve: Option<ResolveOptions>,
   #[cfg_attr(
@@ -100,6 +168,11 @@ pub struct BundlerOptions {
   pub treeshake: TreeshakeOptions,
   pub experimental: Option<ExperimentalOptions>,
   pub minify: Option<bool>,
+  /// Rename top-level and local symbols to short names like `a` and `b` while deconflicting them. Exports keep their
+  /// public names, and the names imported from external modules aren't touched. Unlike `minify`, the code isn't
+  /// compressed. Only effective for the formats rendering exports with explicit names.
+  /// - Defaults to `false`.
+  pub mangle: Option<bool>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     schemars(with = "Option<HashMap<String, String>>")
@@ -128,6 +201,15 @@ where
   Ok(deserialized.map(|s| AddonOutputOption::String(Some(s))))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_scan_timeout<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<u64>::deserialize(deserializer)?;
+  Ok(deserialized.map(Duration::from_millis))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_treeshake<'de, D>(deserializer: D) -> Result<TreeshakeOptions, D::Error>
 where
@@ -0,0 +1,38 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for the AMD output format.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct AmdOptions {
+  /// The module id passed to `define`, like `define("my-lib", [...], function() {...})`. The module is anonymous if
+  /// not set.
+  pub id: Option<String>,
+  /// Pass the local `require` of the module to the factory function.
+  pub require: Option<bool>,
+  /// Pass `exports` to the factory function even if the chunk has no named exports. It's always passed if the chunk
+  /// has named exports.
+  pub exports: Option<bool>,
+  /// Pass `module` to the factory function, so code in the chunk can read `module.id` or `module.config()`.
+  pub module: Option<bool>,
+}
+
+impl AmdOptions {
+  pub fn is_require_passed(&self) -> bool {
+    self.require.unwrap_or(false)
+  }
+
+  pub fn is_exports_forced(&self) -> bool {
+    self.exports.unwrap_or(false)
+  }
+
+  pub fn is_module_passed(&self) -> bool {
+    self.module.unwrap_or(false)
+  }
+}
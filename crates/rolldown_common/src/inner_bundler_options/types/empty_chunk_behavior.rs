@@ -0,0 +1,18 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// What to do with chunks that have nothing left to render after tree shaking: no code, no exports and no imports.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum EmptyChunkBehavior {
+  #[default]
+  Ignore,
+  Warn,
+  Error,
+}
@@ -11,10 +11,37 @@ use serde::Deserialize;
 )]
 pub struct ExperimentalOptions {
   pub strict_execution_order: Option<bool>,
+  /// Report cycles that are only closed through dynamic `import(...)` as informational diagnostics.
+  pub detect_dynamic_import_cycles: Option<bool>,
+  /// Warn about `export default undefined` and `export default void 0`, which are usually left over from an
+  /// incomplete refactor.
+  pub warn_undefined_default_export: Option<bool>,
+  /// Use a single import record for `import` and `export ... from` statements of a module requesting the same module
+  /// with the same import attributes.
+  pub merge_duplicate_import_records: Option<bool>,
+  /// Warn about imported bindings that are never used, like `a` in `import { a } from './foo'` without any reference
+  /// to `a`. Imports kept only for their side effects, like `import './foo'`, are not reported.
+  pub warn_unused_imports: Option<bool>,
 }
 
 impl ExperimentalOptions {
   pub fn is_strict_execution_order_enabled(&self) -> bool {
     self.strict_execution_order.unwrap_or(false)
   }
+
+  pub fn is_dynamic_import_cycle_detection_enabled(&self) -> bool {
+    self.detect_dynamic_import_cycles.unwrap_or(false)
+  }
+
+  pub fn is_undefined_default_export_warning_enabled(&self) -> bool {
+    self.warn_undefined_default_export.unwrap_or(false)
+  }
+
+  pub fn is_import_record_merging_enabled(&self) -> bool {
+    self.merge_duplicate_import_records.unwrap_or(false)
+  }
+
+  pub fn is_unused_import_warning_enabled(&self) -> bool {
+    self.warn_unused_imports.unwrap_or(false)
+  }
 }
@@ -0,0 +1,42 @@
+use std::fmt::Debug;
+
+use super::filename_template::FilenameTemplate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFileKind {
+  /// A chunk created for a user-defined entry.
+  Entry,
+  /// A common chunk or a chunk created for a dynamic import.
+  Chunk,
+  /// An asset emitted by plugins.
+  Asset,
+}
+
+#[derive(Debug)]
+pub struct OutputFileInfo<'a> {
+  /// The value `[name]` would be replaced with.
+  pub name: &'a str,
+  pub kind: OutputFileKind,
+}
+
+type FileNameFn = dyn Fn(&OutputFileInfo) -> String + Send + Sync;
+
+/// Returns the filename template of each output file, taking precedence over `entry_filenames`, `chunk_filenames`
+/// and `asset_filenames`. The returned template is rendered as usual, so `[name]`, `[hash]` and `[ext]` still work.
+pub struct FileNameFunction(Box<FileNameFn>);
+
+impl Debug for FileNameFunction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "FileNameFunction::Fn(...)")
+  }
+}
+
+impl FileNameFunction {
+  pub fn new(f: Box<FileNameFn>) -> Self {
+    Self(f)
+  }
+
+  pub fn call(&self, info: &OutputFileInfo) -> FilenameTemplate {
+    FilenameTemplate::new(self.0(info))
+  }
+}
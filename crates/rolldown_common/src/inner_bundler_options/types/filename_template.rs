@@ -33,7 +33,7 @@ impl FilenameTemplate {
       tmp = tmp.replace("[name]", name);
     }
     if let Some(hash) = options.hash {
-      tmp = tmp.replace("[hash]", hash);
+      tmp = replace_hash_patterns(&tmp, hash);
     }
     if let Some(ext) = options.ext {
       tmp = tmp.replace("[ext]", ext).replace("[extname]", &format!(".{ext}"));
@@ -42,7 +42,44 @@ impl FilenameTemplate {
   }
 }
 
+/// Replaces both `[hash]` and `[hash:8]` with `hash`, which is expected to have the length given by the pattern.
+fn replace_hash_patterns(template: &str, hash: &str) -> String {
+  let mut replaced = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some(start) = rest.find("[hash") {
+    replaced.push_str(&rest[..start]);
+    let after = &rest[start + "[hash".len()..];
+    let end = after.find(']').filter(|end| {
+      let len = &after[..*end];
+      len.is_empty() || len.strip_prefix(':').is_some_and(|n| n.parse::<usize>().is_ok())
+    });
+    if let Some(end) = end {
+      replaced.push_str(hash);
+      rest = &after[end + 1..];
+    } else {
+      replaced.push_str("[hash");
+      rest = after;
+    }
+  }
+  replaced.push_str(rest);
+  replaced
+}
+
 #[test]
 fn basic() {
   FilenameTemplate::new("[name]-[hash:8].js".to_string());
 }
+
+#[test]
+fn render_hash_with_length() {
+  let render = |template: &str| {
+    FilenameTemplate::new(template.to_string()).render(&FileNameRenderOptions {
+      name: Some("main"),
+      hash: Some("abcd1234"),
+      ext: Some("js"),
+    })
+  };
+  assert_eq!(render("[name].[hash].[ext]"), "main.abcd1234.js");
+  assert_eq!(render("[name]-[hash:8][extname]"), "main-abcd1234.js");
+  assert_eq!(render("[name]-[hash:x].js"), "main-[hash:x].js");
+}
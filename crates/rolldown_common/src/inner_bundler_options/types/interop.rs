@@ -0,0 +1,51 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use crate::ModuleDefFormat;
+
+/// What `import foo from '...'` and `import * as ns from '...'` bind to, if the imported module is a CommonJS module.
+///
+/// `Default`, `EsModule` and `Compat` behave like the options of rollup with the same names. You may find rollup
+/// explanation [here](https://rollupjs.org/configuration-options/#output-interop).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum Interop {
+  /// Behaves like esbuild. Importers that node treats as ES modules, like `.mjs` files or files in a package with
+  /// `"type": "module"`, get `module.exports` as the default export, just like in node. Other importers behave like
+  /// `Compat`.
+  ///
+  /// For external modules, which are imported once for all the modules of a chunk, this is the same as `Compat`.
+  #[default]
+  Auto,
+  /// The default export is always `module.exports`, even if the module is transpiled from an ES module.
+  Default,
+  /// The module is assumed to be transpiled from an ES module. The default export is `module.exports.default`, and the
+  /// namespace is `module.exports` itself.
+  EsModule,
+  /// The default export is `module.exports.default` if the module is marked with `__esModule`, and `module.exports`
+  /// otherwise.
+  Compat,
+}
+
+impl Interop {
+  /// Returns `false` if `module.exports` is used as the namespace as it is, without being converted by `__toESM`.
+  pub fn requires_to_esm(&self) -> bool {
+    !matches!(self, Self::EsModule)
+  }
+
+  /// Returns `true` if `__toESM` should ignore the `__esModule` marker, like node does, for modules imported by a
+  /// module of `importer_def_format`.
+  pub fn is_node_mode(&self, importer_def_format: ModuleDefFormat) -> bool {
+    match self {
+      Self::Auto => importer_def_format.is_esm(),
+      Self::Default => true,
+      Self::EsModule | Self::Compat => false,
+    }
+  }
+}
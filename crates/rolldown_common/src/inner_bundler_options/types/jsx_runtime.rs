@@ -0,0 +1,21 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How JSX in `.jsx` and `.tsx` modules is compiled.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum JsxRuntime {
+  /// Compile JSX to `React.createElement(...)` calls.
+  Classic,
+  /// Compile JSX to calls of functions imported from `react/jsx-runtime`.
+  #[default]
+  Automatic,
+  /// Keep JSX as it is for tools that transform it later. No runtime import is injected.
+  Preserve,
+}
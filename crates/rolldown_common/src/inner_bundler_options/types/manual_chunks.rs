@@ -0,0 +1,26 @@
+use std::{fmt::Debug, sync::Arc};
+
+use crate::{ModuleId, ModuleInfo};
+
+type ManualChunksFn = dyn Fn(&ModuleId, &ModuleInfo) -> Option<String> + Send + Sync;
+
+/// Decides the chunk a module is put into after linking. Modules for which the same name is returned are grouped into
+/// one chunk with that name. Returning `None` leaves the module to the default code splitting.
+#[derive(Clone)]
+pub struct ManualChunks(Arc<ManualChunksFn>);
+
+impl Debug for ManualChunks {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "ManualChunks::Fn(...)")
+  }
+}
+
+impl ManualChunks {
+  pub fn new(f: Arc<ManualChunksFn>) -> Self {
+    Self(f)
+  }
+
+  pub fn call(&self, module_id: &ModuleId, module_info: &ModuleInfo) -> Option<String> {
+    self.0(module_id, module_info)
+  }
+}
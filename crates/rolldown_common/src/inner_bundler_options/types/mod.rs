@@ -1,11 +1,20 @@
+pub mod amd_options;
+pub mod empty_chunk_behavior;
 pub mod es_module_flag;
 pub mod experimental_options;
+pub mod file_name_function;
 pub mod filename_template;
 pub mod inject_import;
 pub mod input_item;
+pub mod interop;
 pub mod is_external;
+pub mod jsx_runtime;
+pub mod manual_chunks;
 pub mod module_type;
+pub mod module_wrapper;
+pub mod namespace_object;
 pub mod normalized_bundler_options;
+pub mod on_module_added;
 pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
@@ -0,0 +1,28 @@
+use std::fmt::Debug;
+
+use crate::ModuleId;
+
+type ModuleWrapperFn = dyn Fn(&ModuleId, &str) -> String + Send + Sync;
+
+/// Rewrites the code of each module before it's parsed, so that anything it injects is analyzed like code written
+/// by the user.
+///
+/// With sourcemaps enabled, the wrapped code is mapped back to the original code as long as the wrapper keeps the
+/// original code as-is in its output, e.g. by only adding a prefix or a suffix.
+pub struct ModuleWrapper(Box<ModuleWrapperFn>);
+
+impl Debug for ModuleWrapper {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "ModuleWrapper::Fn(...)")
+  }
+}
+
+impl ModuleWrapper {
+  pub fn new(f: Box<ModuleWrapperFn>) -> Self {
+    Self(f)
+  }
+
+  pub fn call(&self, module_id: &ModuleId, code: &str) -> String {
+    self.0(module_id, code)
+  }
+}
@@ -0,0 +1,20 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How properties of the module namespace object, which is what `import * as ns from '...'` refers to, are emitted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum NamespaceObject {
+  /// Every export is a getter reading the binding, as the spec requires.
+  #[default]
+  Live,
+  /// Only exports reassigned by their module are getters. Others are copied into the namespace object once the module
+  /// finishes executing, so they can't be read through the namespace object by code running before that.
+  Auto,
+}
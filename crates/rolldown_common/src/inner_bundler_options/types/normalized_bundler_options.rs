@@ -1,15 +1,19 @@
 //! [crate::InputOptions] meant to provide dx-friendly options for the `rolldown` users, but it's not suitable for
 //! the `rolldown` internal use.
 
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use oxc::minifier::InjectGlobalVariablesConfig;
 use rustc_hash::FxHashMap;
 
+use super::amd_options::AmdOptions;
 use super::experimental_options::ExperimentalOptions;
 use super::treeshake::TreeshakeOptions;
 use super::{
-  filename_template::FilenameTemplate, is_external::IsExternal, output_exports::OutputExports,
+  empty_chunk_behavior::EmptyChunkBehavior, file_name_function::FileNameFunction,
+  filename_template::FilenameTemplate, interop::Interop, is_external::IsExternal,
+  jsx_runtime::JsxRuntime, manual_chunks::ManualChunks, module_wrapper::ModuleWrapper,
+  namespace_object::NamespaceObject, on_module_added::OnModuleAdded, output_exports::OutputExports,
   output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
   source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
   sourcemap_path_transform::SourceMapPathTransform,
@@ -27,8 +31,18 @@ pub struct NormalizedBundlerOptions {
   pub treeshake: TreeshakeOptions,
   pub platform: Platform,
   pub shim_missing_exports: bool,
+  pub scan_timeout: Option<Duration>,
+  pub module_wrapper: Option<ModuleWrapper>,
+  /// Unlike `BundlerOptions`, the module ids are absolute paths.
+  pub cycle_order: Vec<Vec<String>>,
+  pub fail_on_circular_dependency: bool,
+  pub fail_on_import_reassignment: bool,
+  pub on_module_added: Option<OnModuleAdded>,
+  pub watch: bool,
   /// The key is the extension. Unlike `BundlerOptions`, the extension doesn't start with a dot.
   pub module_types: FxHashMap<String, ModuleType>,
+  pub jsx_runtime: JsxRuntime,
+  pub jsx_import_source: String,
   // --- Output
   pub name: Option<String>,
   pub entry_filenames: FilenameTemplate,
@@ -36,12 +50,19 @@ pub struct NormalizedBundlerOptions {
   pub css_entry_filenames: FilenameTemplate,
   pub css_chunk_filenames: FilenameTemplate,
   pub asset_filenames: FilenameTemplate,
+  pub file_name: Option<FileNameFunction>,
+  pub manual_chunks: Option<ManualChunks>,
   pub dir: String,
   pub format: OutputFormat,
   pub exports: OutputExports,
+  pub interop: Interop,
   pub es_module: EsModuleFlag,
+  pub empty_chunk: EmptyChunkBehavior,
+  pub namespace_object: NamespaceObject,
   pub globals: FxHashMap<String, String>,
-  pub sourcemap: SourceMapType,
+  pub amd: AmdOptions,
+  /// `None` if sourcemaps are disabled.
+  pub sourcemap: Option<SourceMapType>,
   pub banner: Option<AddonOutputOption>,
   pub footer: Option<AddonOutputOption>,
   pub intro: Option<AddonOutputOption>,
@@ -50,6 +71,7 @@ pub struct NormalizedBundlerOptions {
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub experimental: ExperimentalOptions,
   pub minify: bool,
+  pub mangle: bool,
   pub extend: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
   pub inject: Vec<InjectImport>,
@@ -0,0 +1,26 @@
+use std::{fmt::Debug, sync::Arc};
+
+use crate::ModuleId;
+
+type OnModuleAddedFn = dyn Fn(&ModuleId) + Send + Sync;
+
+/// Notified each time a module, including external ones, is discovered and added to the module graph. Modules are
+/// scanned in parallel, but the callback is called once per unique module id.
+#[derive(Clone)]
+pub struct OnModuleAdded(Arc<OnModuleAddedFn>);
+
+impl Debug for OnModuleAdded {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "OnModuleAdded::Fn(...)")
+  }
+}
+
+impl OnModuleAdded {
+  pub fn new(f: Arc<OnModuleAddedFn>) -> Self {
+    Self(f)
+  }
+
+  pub fn call(&self, module_id: &ModuleId) {
+    self.0(module_id);
+  }
+}
@@ -14,10 +14,13 @@ pub enum OutputFormat {
   Cjs,
   App,
   Iife,
+  Umd,
+  Amd,
+  System,
 }
 
 impl OutputFormat {
   pub fn requires_scope_hoisting(&self) -> bool {
-    matches!(self, Self::Esm | Self::Cjs | Self::Iife)
+    matches!(self, Self::Esm | Self::Cjs | Self::Iife | Self::Umd | Self::Amd | Self::System)
   }
 }
@@ -12,13 +12,23 @@ mod types;
 pub mod bundler_options {
   pub use crate::inner_bundler_options::{
     types::{
+      amd_options::AmdOptions,
+      empty_chunk_behavior::EmptyChunkBehavior,
       es_module_flag::EsModuleFlag,
+      experimental_options::ExperimentalOptions,
+      file_name_function::{FileNameFunction, OutputFileInfo, OutputFileKind},
       filename_template::{FileNameRenderOptions, FilenameTemplate},
       inject_import::InjectImport,
       input_item::InputItem,
+      interop::Interop,
       is_external::IsExternal,
+      jsx_runtime::JsxRuntime,
+      manual_chunks::ManualChunks,
       module_type::ModuleType,
+      module_wrapper::ModuleWrapper,
+      namespace_object::NamespaceObject,
       normalized_bundler_options::NormalizedBundlerOptions,
+      on_module_added::OnModuleAdded,
       output_exports::OutputExports,
       output_format::OutputFormat,
       output_option::{AddonFunction, AddonOutputOption},
@@ -44,7 +54,7 @@ pub use crate::{
   },
   css::{css_module::CssModule, css_module_idx::CssModuleIdx},
   ecmascript::{ecma_asset_meta::EcmaAssetMeta, ecma_module::EcmaModule, module_idx::ModuleIdx},
-  file_emitter::{EmittedAsset, FileEmitter, SharedFileEmitter},
+  file_emitter::{EmittedAsset, EmittedChunk, EmittedFile, FileEmitter, SharedFileEmitter},
   module::external_module::ExternalModule,
   module::Module,
   types::asset::{Asset, PreliminaryAsset},
@@ -55,19 +65,22 @@ pub use crate::{
   types::bundler_file_system::BundlerFileSystem,
   types::chunk_idx::ChunkIdx,
   types::chunk_kind::ChunkKind,
+  types::dynamic_import_use::DynamicImportUse,
   types::ecma_ast_idx::EcmaAstIdx,
   types::entry_point::{EntryPoint, EntryPointKind},
   types::exports_kind::ExportsKind,
   types::external_module_idx::ExternalModuleIdx,
+  types::import_pattern::ImportPattern,
   types::import_record::{
     ImportKind, ImportRecord, ImportRecordIdx, ImportRecordMeta, RawImportRecord,
   },
   types::importer_record::ImporterRecord,
   types::member_expr_ref::MemberExprRef,
   types::module_def_format::ModuleDefFormat,
-  types::module_id::ModuleId,
+  types::module_id::{ModuleId, RUNTIME_MODULE_ID},
   types::module_idx::LegacyModuleIdx,
   types::module_info::ModuleInfo,
+  types::module_meta::ModuleMeta,
   types::module_table::{IndexExternalModules, IndexModules, ModuleTable},
   types::named_export::LocalExport,
   types::named_import::{NamedImport, Specifier},
@@ -76,6 +89,7 @@ pub use crate::{
   types::package_json::PackageJson,
   types::rendered_module::RenderedModule,
   types::resolved_export::ResolvedExport,
+  types::resolved_external::ResolvedExternal,
   types::resolved_request_info::ResolvedId,
   types::rollup_rendered_chunk::RollupRenderedChunk,
   types::side_effects,
@@ -1,15 +1,23 @@
 use crate::side_effects::DeterminedSideEffects;
-use crate::{ImportRecord, ImportRecordIdx, ModuleIdx};
+use crate::{
+  ExportsKind, ImportRecord, ImportRecordIdx, ModuleId, ModuleIdx, ModuleInfo, ModuleMeta,
+};
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
+use oxc::span::SourceType;
 
 #[derive(Debug)]
 pub struct ExternalModule {
   pub idx: ModuleIdx,
   pub exec_order: u32,
   pub name: ArcStr,
+  /// The specifier used to import this module in the output. Same as `name` unless rewritten by the
+  /// `render_external_specifier` hook.
+  pub rendered_specifier: ArcStr,
   pub import_records: IndexVec<ImportRecordIdx, ImportRecord>,
   pub side_effects: DeterminedSideEffects,
+  /// Custom meta attached by the plugin that resolved the module.
+  pub meta: ModuleMeta,
 }
 
 impl ExternalModule {
@@ -17,9 +25,36 @@ impl ExternalModule {
     Self {
       idx,
       exec_order: u32::MAX,
+      rendered_specifier: module_id.clone(),
       name: module_id,
       import_records: IndexVec::default(),
       side_effects,
+      meta: ModuleMeta::default(),
+    }
+  }
+
+  /// External modules aren't loaded, so only the id and the importers are known.
+  pub fn to_module_info(
+    &self,
+    mut importers: Vec<ModuleId>,
+    mut dynamic_importers: Vec<ModuleId>,
+  ) -> ModuleInfo {
+    importers.sort_unstable();
+    dynamic_importers.sort_unstable();
+    ModuleInfo {
+      code: None,
+      id: ModuleId::new(ArcStr::clone(&self.name)),
+      is_entry: false,
+      is_external: true,
+      importers,
+      dynamic_importers,
+      imported_ids: vec![],
+      dynamically_imported_ids: vec![],
+      source_type: SourceType::default(),
+      import_patterns: vec![],
+      exports_kind: ExportsKind::None,
+      exports: vec![],
+      meta: self.meta.clone(),
     }
   }
 }
@@ -0,0 +1,11 @@
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashSet;
+
+/// How the module namespace returned by an `import('...')` is used by the importer.
+#[derive(Debug, Clone)]
+pub enum DynamicImportUse {
+  /// The namespace is passed around or read in ways we can't follow, so any export could be used.
+  All,
+  /// Only these exports are read, like `a` in `(await import('./foo')).a` or `const { a } = await import('./foo')`.
+  Partial(FxHashSet<Rstr>),
+}
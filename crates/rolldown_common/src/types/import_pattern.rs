@@ -0,0 +1,17 @@
+use oxc::span::Span;
+use rolldown_rstr::Rstr;
+
+use crate::ImportKind;
+
+/// A request that can't be resolved statically, but is known to start with `prefix` and end with `suffix`.
+///
+/// For `` require(`./cmds/${name}.js`) ``, the prefix is `./cmds/` and the suffix is `.js`. Plugins could use it to
+/// find out which modules might be requested.
+#[derive(Debug, Clone)]
+pub struct ImportPattern {
+  pub kind: ImportKind,
+  pub prefix: Rstr,
+  pub suffix: Rstr,
+  /// Span of the whole request expression, such as `` `./cmds/${name}.js` ``.
+  pub span: Span,
+}
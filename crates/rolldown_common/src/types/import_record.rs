@@ -13,6 +13,8 @@ pub enum ImportKind {
   Import,
   DynamicImport,
   Require,
+  /// `import.meta.resolve('...')` only needs the path of the module, which isn't executed by the importer.
+  ResolveOnly,
 }
 
 impl ImportKind {
@@ -29,6 +31,7 @@ impl TryFrom<&str> for ImportKind {
       "import" => Ok(Self::Import),
       "dynamic-import" => Ok(Self::DynamicImport),
       "require-call" => Ok(Self::Require),
+      "resolve-only" => Ok(Self::ResolveOnly),
       _ => Err(format!("Invalid import kind: {value:?}")),
     }
   }
@@ -40,12 +43,13 @@ impl Display for ImportKind {
       Self::Import => write!(f, "import-statement"),
       Self::DynamicImport => write!(f, "dynamic-import"),
       Self::Require => write!(f, "require-call"),
+      Self::ResolveOnly => write!(f, "resolve-only"),
     }
   }
 }
 
 /// See [ImportRecord] for more details.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RawImportRecord {
   // Module Request
   pub module_request: Rstr,
@@ -57,10 +61,12 @@ pub struct RawImportRecord {
   /// Using this trick could save some memory.
   pub module_request_start: u32,
   pub meta: ImportRecordMeta,
+  /// See [ImportRecord] for more details.
+  pub attributes: Vec<(Rstr, Rstr)>,
 }
 
 bitflags::bitflags! {
-  #[derive(Debug)]
+  #[derive(Debug, Clone, Copy)]
   pub struct ImportRecordMeta: u8 {
     /// If it is `import * as ns from '...'` or `export * as ns from '...'`
     const CONTAINS_IMPORT_STAR = 1;
@@ -68,6 +74,13 @@ bitflags::bitflags! {
     const CONTAINS_IMPORT_DEFAULT = 1 << 1;
     /// If it is `import {} from '...'` or `import '...'`
     const IS_PLAIN_IMPORT = 1 << 2;
+    /// If it's marked with `/* webpackIgnore: true */`, like `import(/* webpackIgnore: true */ '...')`. Such
+    /// requests are kept as written in the output, like externals, without being resolved.
+    const IS_IGNORED_BY_COMMENT = 1 << 3;
+    /// If the request of `import(expr)` isn't a string literal, like `import(dynamicVar)`. The request is the source
+    /// text of `expr`, which is only passed to the `resolve_dynamic_import` hook. Unless a plugin resolves it, the
+    /// expression is kept as written.
+    const IS_NON_LITERAL_REQUEST = 1 << 4;
   }
 }
 
@@ -84,6 +97,7 @@ impl RawImportRecord {
       namespace_ref,
       module_request_start,
       meta: ImportRecordMeta::empty(),
+      attributes: Vec::new(),
     }
   }
 
@@ -99,13 +113,15 @@ impl RawImportRecord {
       kind: self.kind,
       namespace_ref: self.namespace_ref,
       meta: self.meta,
+      attributes: self.attributes,
     }
   }
 }
 
 #[derive(Debug)]
 pub struct ImportRecord {
-  // Module Request
+  /// The specifier exactly as written in the source, such as `./foo/../bar.js`. It's never normalized, so externals
+  /// matched by their specifier are emitted the same way the user wrote them.
   pub module_request: Rstr,
   pub resolved_module: ModuleIdx,
   pub kind: ImportKind,
@@ -113,4 +129,7 @@ pub struct ImportRecord {
   /// `namespace_ref` represent the potential `import_foo` in above example. It's useless if we imported n esm module.
   pub namespace_ref: SymbolRef,
   pub meta: ImportRecordMeta,
+  /// Import attributes in source order, such as `[("type", "json")]` for `import data from './data.json' with { type: 'json' }`
+  /// or `import('./data.json', { with: { type: 'json' } })`.
+  pub attributes: Vec<(Rstr, Rstr)>,
 }
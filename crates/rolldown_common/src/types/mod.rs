@@ -6,10 +6,12 @@ pub mod ast_scopes;
 pub mod bundler_file_system;
 pub mod chunk_idx;
 pub mod chunk_kind;
+pub mod dynamic_import_use;
 pub mod ecma_ast_idx;
 pub mod entry_point;
 pub mod exports_kind;
 pub mod external_module_idx;
+pub mod import_pattern;
 pub mod import_record;
 pub mod importer_record;
 pub mod member_expr_ref;
@@ -17,6 +19,7 @@ pub mod module_def_format;
 pub mod module_id;
 pub mod module_idx;
 pub mod module_info;
+pub mod module_meta;
 pub mod module_table;
 pub mod named_export;
 pub mod named_import;
@@ -25,6 +28,7 @@ pub mod output_chunk;
 pub mod package_json;
 pub mod rendered_module;
 pub mod resolved_export;
+pub mod resolved_external;
 pub mod resolved_request_info;
 pub mod rollup_rendered_chunk;
 pub mod side_effects;
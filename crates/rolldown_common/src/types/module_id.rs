@@ -4,6 +4,9 @@ use arcstr::ArcStr;
 use rolldown_utils::path_ext::PathExt;
 use sugar_path::SugarPath;
 
+/// Id of the module providing runtime helpers like `__toESM`. It's always the first module to be executed.
+pub static RUNTIME_MODULE_ID: &str = "rolldown:runtime";
+
 /// `ModuleId` is the unique string identifier for each module.
 /// - It will be used to identify the module in the whole bundle.
 /// - Users could stored the `ModuleId` to track the module in different stages/hooks.
@@ -1,14 +1,26 @@
 use arcstr::ArcStr;
+use oxc::span::SourceType;
+use rolldown_rstr::Rstr;
 
-use crate::ModuleId;
+use crate::{ExportsKind, ImportPattern, ModuleId, ModuleMeta};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ModuleInfo {
+  /// `None` for external modules.
   pub code: Option<ArcStr>,
   pub id: ModuleId,
   pub is_entry: bool,
+  pub is_external: bool,
   pub importers: Vec<ModuleId>,
   pub dynamic_importers: Vec<ModuleId>,
   pub imported_ids: Vec<ModuleId>,
   pub dynamically_imported_ids: Vec<ModuleId>,
+  pub source_type: SourceType,
+  pub import_patterns: Vec<ImportPattern>,
+  /// The module format detected while scanning the module.
+  pub exports_kind: ExportsKind,
+  /// Names of the exports declared by the module itself, sorted. Exports from `export * from '...'` aren't included.
+  pub exports: Vec<Rstr>,
+  /// Custom meta attached by plugins when resolving and loading the module.
+  pub meta: ModuleMeta,
 }
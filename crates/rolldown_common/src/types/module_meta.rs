@@ -0,0 +1,32 @@
+use rustc_hash::FxHashMap;
+
+/// Custom meta attached to a module by plugins through the `resolve_id` and `load` hooks.
+#[derive(Debug, Default, Clone)]
+pub struct ModuleMeta {
+  /// The value of each key, with the name of the plugin that set it.
+  values: FxHashMap<String, (serde_json::Value, String)>,
+}
+
+impl ModuleMeta {
+  /// Merges the meta returned by `plugin` into this one. Values of existing keys are overwritten.
+  pub fn merge(&mut self, plugin: &str, meta: FxHashMap<String, serde_json::Value>) {
+    self.values.extend(meta.into_iter().map(|(key, value)| (key, (value, plugin.to_string()))));
+  }
+
+  pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+    self.values.get(key).map(|(value, _)| value)
+  }
+
+  /// Name of the plugin that set the value of `key` last, for debugging.
+  pub fn plugin_of(&self, key: &str) -> Option<&str> {
+    self.values.get(key).map(|(_, plugin)| plugin.as_str())
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.values.is_empty()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &serde_json::Value)> {
+    self.values.iter().map(|(key, (value, _))| (key.as_str(), value))
+  }
+}
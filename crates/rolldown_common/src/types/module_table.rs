@@ -1,5 +1,5 @@
-use crate::{ExternalModule, ExternalModuleIdx, Module, ModuleIdx};
-use oxc::index::IndexVec;
+use crate::{ExternalModule, ExternalModuleIdx, Module, ModuleId, ModuleIdx, ModuleInfo};
+use oxc::index::{index_vec, IndexVec};
 
 pub type IndexModules = IndexVec<ModuleIdx, Module>;
 pub type IndexExternalModules = IndexVec<ExternalModuleIdx, ExternalModule>;
@@ -8,3 +8,34 @@ pub type IndexExternalModules = IndexVec<ExternalModuleIdx, ExternalModule>;
 pub struct ModuleTable {
   pub modules: IndexModules,
 }
+
+impl ModuleTable {
+  /// Infos of all modules, as exposed to plugins. External modules only know their id and importers.
+  pub fn to_module_infos(&self) -> Vec<ModuleInfo> {
+    // Static and dynamic importers of each external module
+    let mut importers_of_externals: IndexVec<ModuleIdx, (Vec<ModuleId>, Vec<ModuleId>)> =
+      index_vec![(vec![], vec![]); self.modules.len()];
+    for module in self.modules.iter().filter_map(Module::as_ecma) {
+      for rec in &module.import_records {
+        if !matches!(self.modules[rec.resolved_module], Module::External(_)) {
+          continue;
+        }
+        let (importers, dynamic_importers) = &mut importers_of_externals[rec.resolved_module];
+        let importers = if rec.kind.is_static() { importers } else { dynamic_importers };
+        if !importers.contains(&module.id) {
+          importers.push(module.id.clone());
+        }
+      }
+    }
+
+    self
+      .modules
+      .iter()
+      .zip(importers_of_externals)
+      .map(|(module, (importers, dynamic_importers))| match module {
+        Module::Ecma(module) => module.to_module_info(),
+        Module::External(module) => module.to_module_info(importers, dynamic_importers),
+      })
+      .collect()
+  }
+}
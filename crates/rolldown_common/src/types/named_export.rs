@@ -5,7 +5,7 @@ use oxc::span::Span;
 /// - Case A: `export function foo() {}`
 /// - Case B: `const foo = 1; export { foo }`
 /// - Case C: `const foo = 1; export { foo as foo2 }`
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LocalExport {
   pub span: Span,
   pub referenced: SymbolRef,
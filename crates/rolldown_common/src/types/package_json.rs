@@ -1,4 +1,6 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use sugar_path::SugarPath;
 
 use crate::side_effects::{glob_match_with_normalized_pattern, SideEffects};
 
@@ -32,9 +34,16 @@ impl PackageJson {
     self.r#type.as_deref()
   }
 
+  /// Patterns of `sideEffects` are relative to the package, so `module_path` is matched against them relative to the
+  /// directory containing `package.json`.
   pub fn check_side_effects_for(&self, module_path: &str) -> Option<bool> {
     let side_effects = self.side_effects.as_ref()?;
-    // Is it necessary to convert module_path to relative path?
+    let module_path = self
+      .path
+      .parent()
+      .and_then(|package_dir| Path::new(module_path).strip_prefix(package_dir).ok())
+      .map_or_else(|| module_path.to_string(), |path| path.to_slash_lossy().into_owned());
+    let module_path = module_path.as_str();
     match side_effects {
       SideEffects::Bool(s) => Some(*s),
       SideEffects::String(p) => Some(glob_match_with_normalized_pattern(p.as_str(), module_path)),
@@ -0,0 +1,22 @@
+/// Whether the module resolved by a `resolve_id` hook is external, corresponding to rollup's
+/// `external: boolean | "absolute"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolvedExternal {
+  /// With `true`, the module is imported in the output by the specifier it's imported with if it's resolved to an
+  /// absolute path from a relative specifier, otherwise by the resolved id.
+  Bool(bool),
+  /// The module is external and always imported in the output by the resolved id, even if it's an absolute path.
+  Absolute,
+}
+
+impl ResolvedExternal {
+  pub fn is_external(self) -> bool {
+    !matches!(self, Self::Bool(false))
+  }
+}
+
+impl From<bool> for ResolvedExternal {
+  fn from(value: bool) -> Self {
+    Self::Bool(value)
+  }
+}
@@ -2,7 +2,7 @@ use std::{path::Path, sync::Arc};
 
 use arcstr::ArcStr;
 
-use crate::{side_effects::HookSideEffects, ModuleDefFormat, PackageJson};
+use crate::{side_effects::HookSideEffects, ModuleDefFormat, ModuleMeta, PackageJson};
 
 use super::module_id::stabilize_module_id;
 
@@ -13,8 +13,13 @@ pub struct ResolvedId {
   pub ignored: bool,
   pub module_def_format: ModuleDefFormat,
   pub is_external: bool,
+  /// Whether an external module is imported by its id in the output even if it's an absolute path. See
+  /// [crate::ResolvedExternal::Absolute].
+  pub is_absolute_external: bool,
   pub package_json: Option<Arc<PackageJson>>,
   pub side_effects: Option<HookSideEffects>,
+  /// Custom meta returned by the plugin that resolved the module.
+  pub meta: ModuleMeta,
 }
 
 impl ResolvedId {
@@ -3,7 +3,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{ImportRecordIdx, SymbolOrMemberExprRef, SymbolRef};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct StmtInfos {
   pub infos: IndexVec<StmtInfoIdx, StmtInfo>,
   // only for top level symbols
@@ -69,7 +69,7 @@ oxc::index::define_index_type! {
   pub struct StmtInfoIdx = u32;
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct StmtInfo {
   /// The index of this statement in the module body.
   ///
@@ -84,6 +84,8 @@ pub struct StmtInfo {
   /// Top level symbols referenced by this statement.
   pub referenced_symbols: Vec<SymbolOrMemberExprRef>,
   pub side_effect: bool,
+  /// Whether this statement comes after a top-level `throw` or `return`, so it's never executed.
+  pub is_unreachable: bool,
   pub is_included: bool,
   pub import_records: Vec<ImportRecordIdx>,
   pub debug_label: Option<String>,
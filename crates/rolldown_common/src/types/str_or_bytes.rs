@@ -4,6 +4,13 @@ pub enum StrOrBytes {
 }
 
 impl StrOrBytes {
+  pub fn as_bytes(&self) -> &[u8] {
+    match self {
+      Self::Str(s) => s.as_bytes(),
+      Self::Bytes(b) => b,
+    }
+  }
+
   pub fn try_into_string(self) -> anyhow::Result<String> {
     match self {
       Self::Str(s) => Ok(s),
@@ -0,0 +1,71 @@
+use oxc::{
+  allocator::{Allocator, CloneIn},
+  ast::{
+    ast::{BindingIdentifier, IdentifierReference},
+    Visit,
+  },
+  semantic::{ReferenceId, SymbolId},
+};
+
+use super::program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner};
+use crate::EcmaAst;
+
+impl EcmaAst {
+  /// Deep copies the AST into a new allocator. Unlike `CloneIn`, the copy keeps the `SymbolId`s and `ReferenceId`s of
+  /// identifiers, so it can still be used with the symbols and scopes created for this AST.
+  pub fn clone_with_another_arena(&self) -> Self {
+    let mut semantic_ids = CollectSemanticIds::default();
+    semantic_ids.visit_program(self.program());
+    let program = ProgramCell::new(
+      ProgramCellOwner { source: self.source().clone(), allocator: Allocator::default() },
+      |owner| {
+        let program = self.program().clone_in(&owner.allocator);
+        RestoreSemanticIds {
+          symbol_ids: semantic_ids.symbol_ids.into_iter(),
+          reference_ids: semantic_ids.reference_ids.into_iter(),
+        }
+        .visit_program(&program);
+        ProgramCellDependent { program }
+      },
+    );
+    Self {
+      program,
+      trivias: self.trivias.clone(),
+      source_type: self.source_type,
+      contains_use_strict: self.contains_use_strict,
+    }
+  }
+}
+
+#[derive(Default)]
+struct CollectSemanticIds {
+  symbol_ids: Vec<Option<SymbolId>>,
+  reference_ids: Vec<Option<ReferenceId>>,
+}
+
+impl<'ast> Visit<'ast> for CollectSemanticIds {
+  fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'ast>) {
+    self.symbol_ids.push(ident.symbol_id.get());
+  }
+
+  fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'ast>) {
+    self.reference_ids.push(ident.reference_id.get());
+  }
+}
+
+/// Visits the copy in the same order as [CollectSemanticIds] visited the original, so the ids are assigned to the
+/// same identifiers.
+struct RestoreSemanticIds {
+  symbol_ids: std::vec::IntoIter<Option<SymbolId>>,
+  reference_ids: std::vec::IntoIter<Option<ReferenceId>>,
+}
+
+impl<'ast> Visit<'ast> for RestoreSemanticIds {
+  fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'ast>) {
+    ident.symbol_id.set(self.symbol_ids.next().flatten());
+  }
+
+  fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'ast>) {
+    ident.reference_id.set(self.reference_ids.next().flatten());
+  }
+}
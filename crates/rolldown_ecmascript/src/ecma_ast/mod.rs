@@ -7,6 +7,7 @@ use oxc::{allocator::Allocator, ast::ast::Program, span::SourceType};
 
 use self::program_cell::ProgramCell;
 
+mod clone;
 mod helpers;
 pub mod program_cell;
 
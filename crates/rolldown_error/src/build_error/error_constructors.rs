@@ -1,4 +1,7 @@
-use std::path::{Path, PathBuf};
+use std::{
+  path::{Path, PathBuf},
+  time::Duration,
+};
 
 use super::BuildDiagnostic;
 use arcstr::ArcStr;
@@ -8,22 +11,41 @@ use rolldown_resolver::ResolveError;
 use crate::events::illegal_identifier_as_name::IllegalIdentifierAsName;
 use crate::events::missing_global_name::MissingGlobalName;
 use crate::events::missing_name_option_for_iife_export::MissingNameOptionForIifeExport;
+use crate::events::missing_name_option_for_umd_export::MissingNameOptionForUmdExport;
 use crate::events::unloadable_dependency::{UnloadableDependency, UnloadableDependencyContext};
 use crate::events::{
   ambiguous_external_namespace::{AmbiguousExternalNamespace, AmbiguousExternalNamespaceModule},
+  assign_to_import_meta::AssignToImportMeta,
   circular_dependency::CircularDependency,
+  circular_reexport::CircularReexport,
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
+  conflicting_default_export::ConflictingDefaultExport,
+  dangerous_export_name::DangerousExportName,
+  duplicate_module_instance::DuplicateModuleInstance,
+  dynamic_import_cycle::DynamicImportCycle,
+  empty_bundle::EmptyBundle,
+  esm_import_in_commonjs::EsmImportInCommonJs,
   eval::Eval,
   external_entry::ExternalEntry,
   forbid_const_assign::ForbidConstAssign,
+  forbid_import_assign::ForbidImportAssign,
+  inconsistent_import_attributes::InconsistentImportAttributes,
   invalid_export_option::InvalidExportOption,
+  invalid_manual_chunk::InvalidManualChunk,
   missing_export::MissingExport,
   mixed_export::MixedExport,
   parse_error::ParseError,
+  plugin_log::{PluginDiagnostic, PluginLog},
+  scan_timeout::ScanTimeout,
+  sourcemap_broken::SourcemapBroken,
   sourcemap_error::SourceMapError,
+  undefined_default_export::UndefinedDefaultExport,
+  unhandled_import_attribute::UnhandledImportAttribute,
   unresolved_entry::UnresolvedEntry,
   unresolved_import::UnresolvedImport,
   unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal,
+  unsupported_top_level_await::UnsupportedTopLevelAwait,
+  unused_import::UnusedImport,
   NapiError,
 };
 
@@ -76,6 +98,28 @@ impl BuildDiagnostic {
     Self::new_inner(CircularDependency { paths })
   }
 
+  pub fn dynamic_import_cycle(paths: Vec<String>) -> Self {
+    Self::new_inner(DynamicImportCycle { paths })
+  }
+
+  pub fn duplicate_module_instance(real_path: String, ids: Vec<String>) -> Self {
+    Self::new_inner(DuplicateModuleInstance { real_path, ids })
+  }
+
+  pub fn circular_reexport(
+    stable_exporter: String,
+    exporter_source: ArcStr,
+    exported_name: String,
+    exported_name_span: Span,
+  ) -> Self {
+    Self::new_inner(CircularReexport {
+      stable_exporter,
+      exporter_source,
+      exported_name,
+      exported_name_span,
+    })
+  }
+
   pub fn unresolved_import_treated_as_external(
     specifier: impl Into<String>,
     importer: impl Into<PathBuf>,
@@ -104,6 +148,26 @@ impl BuildDiagnostic {
     })
   }
 
+  pub fn inconsistent_import_attributes(
+    importee: String,
+    importer: String,
+    attributes: Vec<(String, String)>,
+    existing_importer: String,
+    existing_attributes: Vec<(String, String)>,
+  ) -> Self {
+    Self::new_inner(InconsistentImportAttributes {
+      importee,
+      importer,
+      attributes,
+      existing_importer,
+      existing_attributes,
+    })
+  }
+
+  pub fn empty_bundle(chunk_name: String) -> Self {
+    Self::new_inner(EmptyBundle { chunk_name })
+  }
+
   pub fn mixed_export(module_name: ArcStr, entry_module: ArcStr, export_keys: Vec<ArcStr>) -> Self {
     Self::new_inner(MixedExport { module_name, entry_module, export_keys })
   }
@@ -116,6 +180,10 @@ impl BuildDiagnostic {
     Self::new_inner(MissingNameOptionForIifeExport {})
   }
 
+  pub fn missing_name_option_for_umd_export() -> Self {
+    Self::new_inner(MissingNameOptionForUmdExport {})
+  }
+
   pub fn illegal_identifier_as_name(identifier_name: ArcStr) -> Self {
     Self::new_inner(IllegalIdentifierAsName { identifier_name })
   }
@@ -127,6 +195,18 @@ impl BuildDiagnostic {
   ) -> Self {
     Self::new_inner(InvalidExportOption { export_mode, export_keys, entry_module })
   }
+
+  pub fn plugin_warning(plugin: String, log: PluginLog) -> Self {
+    Self::new_inner(PluginDiagnostic { plugin, log, is_error: false }).with_severity_warning()
+  }
+
+  pub fn plugin_error(plugin: String, log: PluginLog) -> Self {
+    Self::new_inner(PluginDiagnostic { plugin, log, is_error: true })
+  }
+
+  pub fn sourcemap_broken(plugin: String, id: String) -> Self {
+    Self::new_inner(SourcemapBroken { plugin, id }).with_severity_warning()
+  }
   // Esbuild
   pub fn commonjs_variable_in_esm(
     filename: String,
@@ -142,6 +222,20 @@ impl BuildDiagnostic {
     })
   }
 
+  pub fn esm_import_in_commonjs(
+    filename: String,
+    source: ArcStr,
+    esm_import_span: Span,
+    cjs_export_ident_span: CjsExportSpan,
+  ) -> Self {
+    Self::new_inner(EsmImportInCommonJs {
+      filename,
+      source,
+      esm_import_span,
+      cjs_export_ident_span,
+    })
+  }
+
   // --- Rolldown related
 
   pub fn oxc_parse_error(
@@ -164,6 +258,66 @@ impl BuildDiagnostic {
     Self::new_inner(ForbidConstAssign { filename, source, name, reference_span, re_assign_span })
   }
 
+  pub fn forbid_import_assign(
+    filename: String,
+    source: ArcStr,
+    name: String,
+    import_span: Span,
+    re_assign_span: Span,
+  ) -> Self {
+    Self::new_inner(ForbidImportAssign { filename, source, name, import_span, re_assign_span })
+  }
+
+  pub fn assign_to_import_meta(filename: String, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(AssignToImportMeta { filename, source, span })
+  }
+
+  pub fn dangerous_export_name(filename: String, source: ArcStr, span: Span, name: String) -> Self {
+    Self::new_inner(DangerousExportName { filename, source, span, name })
+  }
+
+  pub fn undefined_default_export(filename: String, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(UndefinedDefaultExport { filename, source, span })
+  }
+
+  pub fn unhandled_import_attribute(
+    importee: String,
+    importer: String,
+    key: String,
+    value: String,
+  ) -> Self {
+    Self::new_inner(UnhandledImportAttribute { importee, importer, key, value })
+  }
+
+  pub fn unsupported_top_level_await(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    format: &'static str,
+  ) -> Self {
+    Self::new_inner(UnsupportedTopLevelAwait { filename, source, span, format })
+  }
+
+  pub fn unused_import(filename: String, source: ArcStr, name: String, span: Span) -> Self {
+    Self::new_inner(UnusedImport { filename, source, name, span })
+  }
+
+  pub fn conflicting_default_export(
+    chunk_name: String,
+    exported_name: String,
+    modules: Vec<String>,
+  ) -> Self {
+    Self::new_inner(ConflictingDefaultExport { chunk_name, exported_name, modules })
+  }
+
+  pub fn invalid_manual_chunk(chunk_name: String, importer: String, importee: String) -> Self {
+    Self::new_inner(InvalidManualChunk { chunk_name, importer, importee })
+  }
+
+  pub fn scan_timeout(timeout: Duration, scanned_modules_count: usize) -> Self {
+    Self::new_inner(ScanTimeout { timeout, scanned_modules_count })
+  }
+
   pub fn napi_error(status: String, reason: String) -> Self {
     Self::new_inner(NapiError { status, reason })
   }
@@ -26,11 +26,21 @@ impl Display for BuildDiagnostic {
   }
 }
 
+impl std::error::Error for BuildDiagnostic {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    self.source.as_deref().map(|source| source as &(dyn std::error::Error + 'static))
+  }
+}
+
 impl BuildDiagnostic {
   pub fn kind(&self) -> crate::event_kind::EventKind {
     self.inner.kind()
   }
 
+  pub fn severity(&self) -> &Severity {
+    &self.severity
+  }
+
   #[must_use]
   pub fn with_source(
     mut self,
@@ -46,6 +56,12 @@ impl BuildDiagnostic {
     self
   }
 
+  #[must_use]
+  pub fn with_severity_info(mut self) -> Self {
+    self.severity = Severity::Info;
+    self
+  }
+
   pub fn into_diagnostic(self) -> Diagnostic {
     self.into_diagnostic_with(&DiagnosticOptions::default())
   }
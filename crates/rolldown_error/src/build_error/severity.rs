@@ -2,4 +2,6 @@
 pub enum Severity {
   Error,
   Warning,
+  /// Reported for the user's information. Nothing needs to be fixed.
+  Info,
 }
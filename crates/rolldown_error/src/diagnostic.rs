@@ -66,6 +66,7 @@ impl Diagnostic {
       match self.severity {
         Severity::Error => ReportKind::Error,
         Severity::Warning => ReportKind::Warning,
+        Severity::Info => ReportKind::Advice,
       },
       "",
       0,
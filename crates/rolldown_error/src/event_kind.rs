@@ -13,12 +13,29 @@ pub enum EventKind {
 
   Eval,
   CircularDependency,
+  CircularReexport,
   SourcemapError,
+  SourcemapBroken,
   MissingExport,
   InvalidExportOption,
+  InconsistentImportAttributes,
+  EmptyBundle,
+  PluginWarning,
+  PluginError,
   // --- These kinds are rolldown specific
   IllegalReassignment,
   UnloadableDependency,
+  ScanTimeout,
+  DangerousExportName,
+  ConflictingDefaultExport,
+  DynamicImportCycle,
+  DuplicateModuleInstance,
+  UndefinedDefaultExport,
+  UnhandledImportAttribute,
+  UnsupportedTopLevelAwait,
+  EsmImportInCommonJs,
+  InvalidManualChunk,
+  UnusedImport,
   // !! Only add new kind if it's not covered by the kinds from rollup !!
 
   // TODO remove following kinds
@@ -40,17 +57,34 @@ impl Display for EventKind {
       EventKind::IllegalReassignment => write!(f, "ILLEGAL_REASSIGNMENT"),
       EventKind::Eval => write!(f, "EVAL"),
       EventKind::SourcemapError => write!(f, "SOURCEMAP_ERROR"),
+      EventKind::SourcemapBroken => write!(f, "SOURCEMAP_BROKEN"),
       EventKind::MixedExport => write!(f, "MIXED_EXPORT"),
       EventKind::MissingGlobalName => write!(f, "MISSING_GLOBAL_NAME"),
       EventKind::MissingNameOptionForIifeExport => write!(f, "MISSING_NAME_OPTION_FOR_IIFE_EXPORT"),
       EventKind::IllegalIdentifierAsName => write!(f, "ILLEGAL_IDENTIFIER_AS_NAME"),
       EventKind::CircularDependency => write!(f, "CIRCULAR_DEPENDENCY"),
+      EventKind::CircularReexport => write!(f, "CIRCULAR_REEXPORT"),
       EventKind::MissingExport => write!(f, "MISSING_EXPORT"),
       EventKind::InvalidExportOption => write!(f, "INVALID_EXPORT_OPTION"),
+      EventKind::InconsistentImportAttributes => write!(f, "INCONSISTENT_IMPORT_ATTRIBUTES"),
+      EventKind::EmptyBundle => write!(f, "EMPTY_BUNDLE"),
+      EventKind::PluginWarning => write!(f, "PLUGIN_WARNING"),
+      EventKind::PluginError => write!(f, "PLUGIN_ERROR"),
       // --- Rolldown specific
       EventKind::NapiError => write!(f, "NAPI_ERROR"),
       EventKind::IoError => write!(f, "IO_ERROR"),
       EventKind::CommonJsVariableInEsm => write!(f, "COMMONJS_VARIABLE_IN_ESM"),
+      EventKind::ScanTimeout => write!(f, "SCAN_TIMEOUT"),
+      EventKind::DangerousExportName => write!(f, "DANGEROUS_EXPORT_NAME"),
+      EventKind::ConflictingDefaultExport => write!(f, "CONFLICTING_DEFAULT_EXPORT"),
+      EventKind::DynamicImportCycle => write!(f, "DYNAMIC_IMPORT_CYCLE"),
+      EventKind::DuplicateModuleInstance => write!(f, "DUPLICATE_MODULE_INSTANCE"),
+      EventKind::UndefinedDefaultExport => write!(f, "UNDEFINED_DEFAULT_EXPORT"),
+      EventKind::UnhandledImportAttribute => write!(f, "UNHANDLED_IMPORT_ATTRIBUTE"),
+      EventKind::UnsupportedTopLevelAwait => write!(f, "UNSUPPORTED_TOP_LEVEL_AWAIT"),
+      EventKind::EsmImportInCommonJs => write!(f, "ESM_IMPORT_IN_COMMONJS"),
+      EventKind::InvalidManualChunk => write!(f, "INVALID_MANUAL_CHUNK"),
+      EventKind::UnusedImport => write!(f, "UNUSED_IMPORT"),
     }
   }
 }
@@ -0,0 +1,35 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct AssignToImportMeta {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+}
+
+impl BuildEvent for AssignToImportMeta {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::IllegalReassignment
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("Unexpected assignment to `import.meta` at {}", self.filename)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = "Unexpected assignment to `import.meta`".to_string();
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "`import.meta` is written here".to_string(),
+    );
+  }
+}
@@ -3,6 +3,8 @@ use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions}
 
 #[derive(Debug)]
 pub struct CircularDependency {
+  /// Modules of the cycle in the order they import each other, ending with the module that closes the loop again,
+  /// like `a.js -> b.js -> a.js`.
   pub paths: Vec<String>,
 }
 
@@ -0,0 +1,47 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct CircularReexport {
+  pub stable_exporter: String,
+  pub exporter_source: ArcStr,
+  pub exported_name: String,
+  pub exported_name_span: Span,
+}
+
+impl BuildEvent for CircularReexport {
+  fn kind(&self) -> EventKind {
+    EventKind::CircularReexport
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#""{}" cannot be exported from "{}" as it is a reexport that references itself."#,
+      self.exported_name, self.stable_exporter
+    )
+  }
+
+  fn on_diagnostic(
+    &self,
+    diagnostic: &mut crate::diagnostic::Diagnostic,
+    _opts: &DiagnosticOptions,
+  ) {
+    let exporter_file =
+      diagnostic.add_file(self.stable_exporter.clone(), self.exporter_source.clone());
+
+    diagnostic.title = format!(
+      r#""{}" is a reexport that references itself in "{}"."#,
+      self.exported_name, self.stable_exporter
+    );
+
+    diagnostic.add_label(
+      &exporter_file,
+      self.exported_name_span.start..self.exported_name_span.end,
+      "Circular reexport".to_string(),
+    );
+  }
+}
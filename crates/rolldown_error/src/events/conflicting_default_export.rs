@@ -0,0 +1,29 @@
+use crate::events::BuildEvent;
+use crate::{DiagnosticOptions, EventKind};
+
+#[derive(Debug)]
+pub struct ConflictingDefaultExport {
+  pub chunk_name: String,
+  pub exported_name: String,
+  pub modules: Vec<String>,
+}
+
+impl BuildEvent for ConflictingDefaultExport {
+  fn kind(&self) -> EventKind {
+    EventKind::ConflictingDefaultExport
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Chunk "{}" exports "{}" more than once. It is derived from the default exports of {}."#,
+      self.chunk_name,
+      self.exported_name,
+      self
+        .modules
+        .iter()
+        .map(|m| format!(r#""{}""#, opts.stabilize_path(m)))
+        .collect::<Vec<_>>()
+        .join(", ")
+    )
+  }
+}
@@ -0,0 +1,39 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct DangerousExportName {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub name: String,
+}
+
+impl BuildEvent for DangerousExportName {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::DangerousExportName
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "Exporting `{}` at {} may change the prototype of the namespace object instead of adding an export",
+      self.name, self.filename
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = format!("Dangerous export name `{}`", self.name);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      format!("`{}` may change the prototype of the namespace object", self.name),
+    );
+  }
+}
@@ -0,0 +1,28 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct DuplicateModuleInstance {
+  pub real_path: String,
+  pub ids: Vec<String>,
+}
+
+impl BuildEvent for DuplicateModuleInstance {
+  fn kind(&self) -> EventKind {
+    EventKind::DuplicateModuleInstance
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#""{}" is bundled {} times, because it's reached through different paths: {}. Enable `resolve.symlinks` to bundle it once."#,
+      opts.stabilize_path(&self.real_path),
+      self.ids.len(),
+      self
+        .ids
+        .iter()
+        .map(|id| format!(r#""{}""#, opts.stabilize_path(id)))
+        .collect::<Vec<_>>()
+        .join(", ")
+    )
+  }
+}
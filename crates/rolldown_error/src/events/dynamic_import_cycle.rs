@@ -0,0 +1,20 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct DynamicImportCycle {
+  pub paths: Vec<String>,
+}
+
+impl BuildEvent for DynamicImportCycle {
+  fn kind(&self) -> EventKind {
+    EventKind::DynamicImportCycle
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Circular dependency through dynamic import: {}. It doesn't affect the execution order.",
+      self.paths.iter().map(|p| opts.stabilize_path(p)).collect::<Vec<_>>().join(" -> ")
+    )
+  }
+}
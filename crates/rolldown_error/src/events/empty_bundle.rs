@@ -0,0 +1,17 @@
+use crate::events::BuildEvent;
+use crate::{DiagnosticOptions, EventKind};
+
+#[derive(Debug)]
+pub struct EmptyBundle {
+  pub chunk_name: String,
+}
+
+impl BuildEvent for EmptyBundle {
+  fn kind(&self) -> EventKind {
+    EventKind::EmptyBundle
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(r#"Generated an empty chunk: "{}"."#, self.chunk_name)
+  }
+}
@@ -0,0 +1,50 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::{commonjs_variable_in_esm::CjsExportSpan, BuildEvent};
+
+#[derive(Debug)]
+pub struct EsmImportInCommonJs {
+  pub filename: String,
+  pub source: ArcStr,
+  pub esm_import_span: Span,
+  pub cjs_export_ident_span: CjsExportSpan,
+}
+
+impl BuildEvent for EsmImportInCommonJs {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::EsmImportInCommonJs
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Module "{}" uses the `import` keyword but is treated as a CommonJS module, which may not work as expected"#,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let variable = match self.cjs_export_ident_span {
+      CjsExportSpan::Module(_) => "module",
+      CjsExportSpan::Exports(_) => "exports",
+    };
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.esm_import_span.start..self.esm_import_span.end,
+      String::new(),
+    );
+
+    diagnostic.add_label(
+      &file_id,
+      self.cjs_export_ident_span.start()..self.cjs_export_ident_span.end(),
+      format!(
+        "This file is considered to be a CommonJS module because of the `{variable}` variable here:"
+      ),
+    );
+  }
+}
@@ -0,0 +1,43 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ForbidImportAssign {
+  pub filename: String,
+  pub source: ArcStr,
+  pub name: String,
+  pub import_span: Span,
+  pub re_assign_span: Span,
+}
+
+impl BuildEvent for ForbidImportAssign {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::IllegalReassignment
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("Unexpected re-assignment of imported binding `{0}` at {1}", self.name, self.filename)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = format!("Unexpected re-assignment of imported binding `{0}`", self.name);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic
+      .add_label(
+        &file_id,
+        self.re_assign_span.start..self.re_assign_span.end,
+        format!("`{0}` is re-assigned here", self.name),
+      )
+      .add_label(
+        &file_id,
+        self.import_span.start..self.import_span.end,
+        format!("`{0}` is imported here, and imported bindings are read-only", self.name),
+      );
+  }
+}
@@ -0,0 +1,38 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct InconsistentImportAttributes {
+  pub importee: String,
+  pub importer: String,
+  pub attributes: Vec<(String, String)>,
+  pub existing_importer: String,
+  pub existing_attributes: Vec<(String, String)>,
+}
+
+fn format_attributes(attributes: &[(String, String)]) -> String {
+  if attributes.is_empty() {
+    "no".to_string()
+  } else {
+    let attributes =
+      attributes.iter().map(|(key, value)| format!("{key}: {value:?}")).collect::<Vec<_>>();
+    format!("{{ {} }}", attributes.join(", "))
+  }
+}
+
+impl BuildEvent for InconsistentImportAttributes {
+  fn kind(&self) -> EventKind {
+    EventKind::InconsistentImportAttributes
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Module "{}" tried to import "{}" with {} attributes, but it was already imported by "{}" with {} attributes. Please ensure that import attributes for the same module are always consistent."#,
+      opts.stabilize_path(&self.importer),
+      opts.stabilize_path(&self.importee),
+      format_attributes(&self.attributes),
+      opts.stabilize_path(&self.existing_importer),
+      format_attributes(&self.existing_attributes),
+    )
+  }
+}
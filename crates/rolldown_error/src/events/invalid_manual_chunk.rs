@@ -0,0 +1,24 @@
+use crate::events::BuildEvent;
+use crate::{DiagnosticOptions, EventKind};
+
+#[derive(Debug)]
+pub struct InvalidManualChunk {
+  pub chunk_name: String,
+  pub importer: String,
+  pub importee: String,
+}
+
+impl BuildEvent for InvalidManualChunk {
+  fn kind(&self) -> EventKind {
+    EventKind::InvalidManualChunk
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Manual chunk "{}" can't be executed in the right order. "{}" in it imports "{}" from another chunk, which depends on the manual chunk in turn."#,
+      self.chunk_name,
+      opts.stabilize_path(&self.importer),
+      opts.stabilize_path(&self.importee),
+    )
+  }
+}
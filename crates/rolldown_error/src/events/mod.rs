@@ -5,23 +5,42 @@ use crate::{
 };
 
 pub mod ambiguous_external_namespace;
+pub mod assign_to_import_meta;
 pub mod circular_dependency;
+pub mod circular_reexport;
 pub mod commonjs_variable_in_esm;
+pub mod conflicting_default_export;
+pub mod dangerous_export_name;
+pub mod duplicate_module_instance;
+pub mod dynamic_import_cycle;
+pub mod empty_bundle;
+pub mod esm_import_in_commonjs;
 pub mod eval;
 pub mod external_entry;
 pub mod forbid_const_assign;
+pub mod forbid_import_assign;
 pub mod illegal_identifier_as_name;
+pub mod inconsistent_import_attributes;
 pub mod invalid_export_option;
+pub mod invalid_manual_chunk;
 pub mod missing_export;
 pub mod missing_global_name;
 pub mod missing_name_option_for_iife_export;
+pub mod missing_name_option_for_umd_export;
 pub mod mixed_export;
 pub mod parse_error;
+pub mod plugin_log;
+pub mod scan_timeout;
+pub mod sourcemap_broken;
 pub mod sourcemap_error;
+pub mod undefined_default_export;
+pub mod unhandled_import_attribute;
 pub mod unloadable_dependency;
 pub mod unresolved_entry;
 pub mod unresolved_import;
 pub mod unresolved_import_treated_as_external;
+pub mod unsupported_top_level_await;
+pub mod unused_import;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
@@ -0,0 +1,67 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+/// A warning or an error reported by a plugin through the plugin context.
+#[derive(Debug, Default)]
+pub struct PluginLog {
+  pub message: String,
+  /// A code defined by the plugin, such as `INVALID_CONFIG`.
+  pub code: Option<String>,
+  /// Id of the module the log is about.
+  pub id: Option<String>,
+  /// Source of the module with `id` and the span of the code the log is about.
+  pub loc: Option<(ArcStr, Span)>,
+}
+
+impl From<String> for PluginLog {
+  fn from(message: String) -> Self {
+    Self { message, ..Default::default() }
+  }
+}
+
+impl From<&str> for PluginLog {
+  fn from(message: &str) -> Self {
+    message.to_string().into()
+  }
+}
+
+#[derive(Debug)]
+pub struct PluginDiagnostic {
+  pub plugin: String,
+  pub log: PluginLog,
+  pub is_error: bool,
+}
+
+impl BuildEvent for PluginDiagnostic {
+  fn kind(&self) -> EventKind {
+    if self.is_error {
+      EventKind::PluginError
+    } else {
+      EventKind::PluginWarning
+    }
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let code = self.log.code.as_ref().map(|code| format!("{code}: ")).unwrap_or_default();
+    match &self.log.id {
+      Some(id) => {
+        format!("[plugin {}] {}: {code}{}", self.plugin, opts.stabilize_path(id), self.log.message)
+      }
+      None => format!("[plugin {}] {code}{}", self.plugin, self.log.message),
+    }
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let (Some(id), Some((source, span))) = (&self.log.id, &self.log.loc) else {
+      return;
+    };
+    let file_id = diagnostic.add_file(opts.stabilize_path(id), source.clone());
+    diagnostic.add_label(&file_id, span.start..span.end, self.log.message.clone());
+  }
+}
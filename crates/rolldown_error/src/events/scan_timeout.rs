@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use super::BuildEvent;
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+#[derive(Debug)]
+pub struct ScanTimeout {
+  pub(crate) timeout: Duration,
+  pub(crate) scanned_modules_count: usize,
+}
+
+impl BuildEvent for ScanTimeout {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::ScanTimeout
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "Scanning modules didn't finish within {}ms. {} module(s) were scanned before the build was aborted.",
+      self.timeout.as_millis(),
+      self.scanned_modules_count
+    )
+  }
+}
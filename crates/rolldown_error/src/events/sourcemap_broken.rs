@@ -0,0 +1,23 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SourcemapBroken {
+  pub plugin: String,
+  pub id: String,
+}
+
+impl BuildEvent for SourcemapBroken {
+  fn kind(&self) -> EventKind {
+    EventKind::SourcemapBroken
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Sourcemap is likely to be incorrect: plugin {:?} changed the number of lines of {:?} without generating a sourcemap for the transformation.",
+      self.plugin,
+      opts.stabilize_path(&self.id)
+    )
+  }
+}
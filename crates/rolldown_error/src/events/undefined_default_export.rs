@@ -0,0 +1,35 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UndefinedDefaultExport {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+}
+
+impl BuildEvent for UndefinedDefaultExport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UndefinedDefaultExport
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("The default export of {} is always `undefined`", self.filename)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = "Default export is always `undefined`".to_string();
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "This evaluates to `undefined`. Is the expression missing?".to_string(),
+    );
+  }
+}
@@ -0,0 +1,26 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct UnhandledImportAttribute {
+  pub importee: String,
+  pub importer: String,
+  pub key: String,
+  pub value: String,
+}
+
+impl BuildEvent for UnhandledImportAttribute {
+  fn kind(&self) -> EventKind {
+    EventKind::UnhandledImportAttribute
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Module "{}" imports "{}" with the attribute {{ {}: {:?} }}, but neither rolldown nor any plugin handles it. The attribute will be ignored."#,
+      opts.stabilize_path(&self.importer),
+      opts.stabilize_path(&self.importee),
+      self.key,
+      self.value,
+    )
+  }
+}
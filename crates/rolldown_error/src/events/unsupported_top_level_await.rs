@@ -0,0 +1,41 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnsupportedTopLevelAwait {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub format: &'static str,
+}
+
+impl BuildEvent for UnsupportedTopLevelAwait {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnsupportedTopLevelAwait
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Module "{}" uses top-level await, which isn't supported with the "{}" output format."#,
+      opts.stabilize_path(&self.filename),
+      self.format
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title =
+      format!(r#"Top-level await isn't supported with the "{}" output format"#, self.format);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      r#"Use the "esm" output format or move this into an async function."#.to_string(),
+    );
+  }
+}
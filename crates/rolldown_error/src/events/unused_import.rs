@@ -0,0 +1,36 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnusedImport {
+  pub filename: String,
+  pub source: ArcStr,
+  pub name: String,
+  pub span: Span,
+}
+
+impl BuildEvent for UnusedImport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnusedImport
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("`{}` is imported by {}, but never used", self.name, self.filename)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = format!("`{}` is imported but never used", self.name);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Remove this import if it's not needed".to_string(),
+    );
+  }
+}
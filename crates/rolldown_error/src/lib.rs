@@ -6,10 +6,11 @@ mod type_aliases;
 mod types;
 
 pub use crate::{
-  build_error::{BuildDiagnostic, BuildResult},
+  build_error::{severity::Severity, BuildDiagnostic, BuildResult},
   event_kind::EventKind,
   events::ambiguous_external_namespace::AmbiguousExternalNamespaceModule,
   events::commonjs_variable_in_esm::CjsExportSpan,
+  events::plugin_log::PluginLog,
   events::unloadable_dependency::UnloadableDependencyContext,
   type_aliases::{DiagnosableResult, UnhandleableResult},
   types::diagnostic_options::DiagnosticOptions,
@@ -11,15 +11,17 @@ mod utils;
 /// Only for usage by the rolldown's crate. Do not use this directly.
 #[cfg(feature = "inner")]
 pub mod __inner {
-  pub use super::utils::resolve_id_with_plugins::resolve_id_with_plugins;
+  pub use super::utils::resolve_id_with_plugins::{
+    resolve_id_with_plugins, resolve_non_literal_dynamic_import,
+  };
   pub use crate::pluginable::{BoxPluginable, Pluginable, SharedPluginable};
 }
 
 pub use crate::{
   plugin::{
     HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookLoadReturn, HookNoopReturn,
-    HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn,
-    Plugin,
+    HookRenderChunkReturn, HookRenderExternalSpecifierReturn, HookResolveFileUrlReturn,
+    HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn, Plugin,
   },
   plugin_context::PluginContext,
   plugin_driver::{PluginDriver, SharedPluginDriver},
@@ -32,12 +34,20 @@ pub use crate::{
   types::hook_render_chunk_args::HookRenderChunkArgs,
   types::hook_render_chunk_output::HookRenderChunkOutput,
   types::hook_render_error::HookRenderErrorArgs,
+  types::hook_render_external_specifier_args::HookRenderExternalSpecifierArgs,
+  types::hook_render_start_args::HookRenderStartArgs,
+  types::hook_resolve_file_url_args::HookResolveFileUrlArgs,
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_output::HookResolveIdOutput,
   types::hook_transform_args::HookTransformArgs,
   types::hook_transform_ast_args::HookTransformAstArgs,
   types::hook_transform_output::HookTransformOutput,
+  types::hook_write_bundle_args::HookWriteBundleArgs,
+  types::plugin_apply::PluginApply,
+  types::plugin_context_meta::ContextMeta,
+  types::plugin_context_parse_options::PluginContextParseOptions,
   types::plugin_context_resolve_options::PluginContextResolveOptions,
+  types::watch_change_event::ChangeEvent,
 };
 
 pub use typedmap;
@@ -2,18 +2,22 @@ use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
 
 use super::plugin_context::PluginContext;
 use crate::{
-  plugin_hook_meta::PluginHookMeta,
+  plugin_hook_meta::{PluginHookMeta, PluginOrder},
   transform_plugin_context::TransformPluginContext,
   types::{
     hook_render_error::HookRenderErrorArgs, hook_transform_ast_args::HookTransformAstArgs,
-    hook_transform_output::HookTransformOutput,
+    hook_transform_output::HookTransformOutput, plugin_apply::PluginApply,
   },
-  HookAddonArgs, HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookRenderChunkArgs,
-  HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput, HookTransformArgs,
+  ChangeEvent, HookAddonArgs, HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookRenderChunkArgs,
+  HookRenderChunkOutput, HookRenderExternalSpecifierArgs, HookRenderStartArgs,
+  HookResolveFileUrlArgs, HookResolveIdArgs, HookResolveIdOutput, HookTransformArgs,
+  HookWriteBundleArgs,
 };
 use anyhow::Result;
-use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk};
+use rolldown_common::{LocalExport, ModuleId, ModuleInfo, Output, RollupRenderedChunk};
 use rolldown_ecmascript::EcmaAst;
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashMap;
 
 pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
 pub type HookTransformAstReturn = Result<EcmaAst>;
@@ -23,10 +27,24 @@ pub type HookNoopReturn = Result<()>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub type HookInjectionOutputReturn = Result<Option<String>>;
+pub type HookRenderExternalSpecifierReturn = Result<Option<String>>;
+pub type HookResolveFileUrlReturn = Result<Option<String>>;
 
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
 
+  /// Plugins that don't apply to the current mode are left out, and none of their hooks are called.
+  fn apply(&self) -> PluginApply {
+    PluginApply::Always
+  }
+
+  /// Plugins enforced as [PluginOrder::Pre] run before other plugins, and those enforced as [PluginOrder::Post] run
+  /// after them, keeping the registration order within each group. The order of a hook given by its meta, such as
+  /// [Plugin::resolve_id_meta], still takes precedence.
+  fn enforce(&self) -> Option<PluginOrder> {
+    None
+  }
+
   // The `option` hook consider call at node side.
 
   // --- Build hooks ---
@@ -105,6 +123,21 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Rename the exports of the module `id` after all modules are scanned and before they are linked. Keys are the
+  /// exported names, so importers of the module have to use the new names.
+  fn rename_exports(
+    &self,
+    _ctx: &PluginContext,
+    _id: &ModuleId,
+    _exports: &mut FxHashMap<Rstr, LocalExport>,
+  ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
+    async { Ok(()) }
+  }
+
+  fn rename_exports_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn build_end(
     &self,
     _ctx: &PluginContext,
@@ -117,11 +150,41 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Called for each file reported as changed through `Bundler::watch_change`, including files added by
+  /// `PluginContext::add_watch_file`, before the `build_start` hook of the rebuild. Deleted files are reported with
+  /// [ChangeEvent::Delete], even though they won't be part of the next module graph.
+  fn watch_change(
+    &self,
+    _ctx: &PluginContext,
+    _id: &str,
+    _event: ChangeEvent,
+  ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
+    async { Ok(()) }
+  }
+
+  fn watch_change_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Called once when the watch session the bundler is used for ends, through `Bundler::close_watcher`.
+  fn close_watcher(
+    &self,
+    _ctx: &PluginContext,
+  ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
+    async { Ok(()) }
+  }
+
+  fn close_watcher_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   // --- Generate hooks ---
 
+  /// Called once when generating the output starts, before any chunk is rendered.
   fn render_start(
     &self,
     _ctx: &PluginContext,
+    _args: &HookRenderStartArgs<'_>,
   ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
     async { Ok(()) }
   }
@@ -190,6 +253,35 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Rewrite the specifier an external module is imported with in the output. The first plugin
+  /// returning `Some` wins; returning `None` keeps the specifier as resolved.
+  fn render_external_specifier(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderExternalSpecifierArgs<'_>,
+  ) -> impl std::future::Future<Output = HookRenderExternalSpecifierReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn render_external_specifier_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Render the url of an emitted file referenced by `import.meta.ROLLUP_FILE_URL_<reference id>`. The returned code
+  /// replaces the reference as it is. The first plugin returning `Some` wins; returning `None` renders a url relative
+  /// to the chunk that fits the output format, like `new URL("../assets/img.png", import.meta.url).href`.
+  fn resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveFileUrlArgs<'_>,
+  ) -> impl std::future::Future<Output = HookResolveFileUrlReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn resolve_file_url_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn augment_chunk_hash(
     &self,
     _ctx: &PluginContext,
@@ -227,10 +319,11 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Called only by `Bundler::write`, after every file of `args.bundle` has been written into `args.dir`.
   fn write_bundle(
     &self,
     _ctx: &PluginContext,
-    _bundle: &mut Vec<Output>,
+    _args: &mut HookWriteBundleArgs<'_>,
   ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
     async { Ok(()) }
   }
@@ -239,6 +332,19 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Called exactly once when the bundler is closed by `Bundler::close` or dropped, whether or not its builds
+  /// succeeded. Plugins should release the resources they hold here, like child processes or worker threads.
+  fn close_bundle(
+    &self,
+    _ctx: &PluginContext,
+  ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
+    async { Ok(()) }
+  }
+
+  fn close_bundle_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   // --- experimental hooks ---
 
   fn transform_ast(
@@ -1,15 +1,19 @@
 use std::{
+  borrow::Cow,
   ops::Deref,
   path::PathBuf,
   sync::{Arc, OnceLock, Weak},
 };
 
 use rolldown_common::{ModuleTable, ResolvedId, SharedFileEmitter};
+use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult, PluginLog};
 use rolldown_resolver::{ResolveError, Resolver};
 
 use crate::{
   types::{
-    hook_resolve_id_skipped::HookResolveIdSkipped,
+    hook_resolve_id_skipped::HookResolveIdSkipped, plugin_context_meta::ContextMeta,
+    plugin_context_parse_options::PluginContextParseOptions,
     plugin_context_resolve_options::PluginContextResolveOptions, plugin_idx::PluginIdx,
   },
   utils::resolve_id_with_plugins::resolve_id_with_plugins,
@@ -32,6 +36,7 @@ impl PluginContext {
       resolver: Arc::clone(&self.resolver),
       file_emitter: Arc::clone(&self.file_emitter),
       module_table: self.module_table.clone(),
+      meta: Arc::clone(&self.meta),
     }))
   }
 }
@@ -53,6 +58,7 @@ pub struct PluginContextImpl {
   pub(crate) file_emitter: SharedFileEmitter,
   #[allow(clippy::redundant_allocation)]
   pub(crate) module_table: OnceLock<&'static ModuleTable>,
+  pub(crate) meta: Arc<ContextMeta>,
 }
 
 impl From<PluginContextImpl> for PluginContext {
@@ -101,7 +107,9 @@ impl PluginContextImpl {
     .await
   }
 
-  pub fn emit_file(&self, file: rolldown_common::EmittedAsset) -> String {
+  /// Emits a chunk or an asset, returning a reference id to get its final file name with
+  /// [`PluginContextImpl::get_file_name`].
+  pub fn emit_file(&self, file: rolldown_common::EmittedFile) -> anyhow::Result<String> {
     self.file_emitter.emit_file(file)
   }
 
@@ -109,22 +117,21 @@ impl PluginContextImpl {
     self.file_emitter.try_get_file_name(reference_id)
   }
 
-  pub fn get_file_name(&self, reference_id: &str) -> String {
-    self.file_emitter.get_file_name(reference_id)
+  /// The file name of an emitted file, or `None` if the reference id is unknown or the file name isn't known yet.
+  /// File names of emitted chunks are known once chunks are named, so they could be used from `render_chunk` on.
+  pub fn get_file_name(&self, reference_id: &str) -> Option<String> {
+    self.file_emitter.try_get_file_name(reference_id).ok()
   }
 
+  /// Info of the module `module_id`, available once all modules are loaded, from the `build_end` hook on.
   pub fn get_module_info(&self, module_id: &str) -> Option<rolldown_common::ModuleInfo> {
-    self.module_table.get().as_ref().and_then(|module_table| {
-      for normal_module in &module_table.modules {
-        if let Some(ecma_module) = normal_module.as_ecma() {
-          if ecma_module.id.as_str() == module_id {
-            return Some(ecma_module.to_module_info());
-          }
-        }
-      }
-      // TODO external module
-      None
-    })
+    self.plugin_driver.upgrade()?.get_module_info(module_id)
+  }
+
+  /// Whether `module_id` is the module providing runtime helpers, which doesn't correspond to any user code.
+  #[allow(clippy::unused_self)] // Kept as a method to be queried like the rest of the context
+  pub fn is_runtime_module(&self, module_id: &str) -> bool {
+    module_id == rolldown_common::RUNTIME_MODULE_ID
   }
 
   pub fn get_module_ids(&self) -> Option<Vec<String>> {
@@ -140,7 +147,61 @@ impl PluginContextImpl {
     }
   }
 
+  /// Watch `path` for changes in watch mode, which is resolved against `cwd` if it's relative. The file is watched
+  /// even if the module that added it isn't included in the output.
+  pub fn add_watch_file(&self, path: &str) {
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      plugin_driver.add_watch_file(self.cwd().join(path).to_string_lossy().as_ref().into());
+    }
+  }
+
   pub fn cwd(&self) -> &PathBuf {
     self.resolver.cwd()
   }
+
+  pub fn meta(&self) -> &ContextMeta {
+    &self.meta
+  }
+
+  /// Parse `code` with the parser used for the modules of the bundle. The returned [EcmaAst] owns both the source
+  /// and the allocator of the program. Syntax errors are reported as diagnostics about a file named after the plugin.
+  pub fn parse(
+    &self,
+    code: &str,
+    options: PluginContextParseOptions,
+  ) -> DiagnosableResult<EcmaAst> {
+    EcmaCompiler::parse(&format!("[plugin {}]", self.plugin_name()), code, options.source_type())
+  }
+
+  /// Report a warning, which is added to the warnings of the build with the name of the plugin.
+  pub fn warn(&self, log: impl Into<PluginLog>) {
+    let warning = BuildDiagnostic::plugin_warning(self.plugin_name().into_owned(), log.into());
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      plugin_driver.add_warning(warning);
+    }
+  }
+
+  /// Create an error with the name of the plugin, which aborts the build when returned from a hook, like
+  /// `return Err(ctx.error("..."))`.
+  pub fn error(&self, log: impl Into<PluginLog>) -> anyhow::Error {
+    BuildDiagnostic::plugin_error(self.plugin_name().into_owned(), log.into()).into()
+  }
+
+  /// Emit an informational message through `tracing`, with the name of the plugin as the `plugin` field.
+  /// Unlike warnings, logs don't affect the build output.
+  pub fn info(&self, msg: impl Into<String>) {
+    tracing::info!(plugin = %self.plugin_name(), "{}", msg.into());
+  }
+
+  /// Same as [`PluginContextImpl::info`], but at the debug level.
+  pub fn debug(&self, msg: impl Into<String>) {
+    tracing::debug!(plugin = %self.plugin_name(), "{}", msg.into());
+  }
+
+  fn plugin_name(&self) -> Cow<'static, str> {
+    self
+      .plugin_driver
+      .upgrade()
+      .map_or(Cow::Borrowed("unknown"), |plugin_driver| plugin_driver.plugin_name(self.plugin_idx))
+  }
 }
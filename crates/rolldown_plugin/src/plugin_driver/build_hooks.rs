@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use crate::{
   pluginable::HookTransformAstReturn,
@@ -6,13 +6,18 @@ use crate::{
     hook_resolve_id_skipped::HookResolveIdSkipped, hook_transform_ast_args::HookTransformAstArgs,
     plugin_idx::PluginIdx,
   },
-  HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
-  HookResolveIdReturn, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
+  ChangeEvent, HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookNoopReturn, HookResolveIdArgs,
+  HookResolveIdOutput, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
 };
 use anyhow::Result;
-use rolldown_common::{side_effects::HookSideEffects, ModuleInfo, ModuleType};
-use rolldown_sourcemap::SourceMap;
+use rolldown_common::{
+  side_effects::HookSideEffects, LocalExport, ModuleId, ModuleInfo, ModuleType,
+};
+use rolldown_error::BuildDiagnostic;
+use rolldown_rstr::Rstr;
+use rolldown_sourcemap::{lines_count, SourceMap};
 use rolldown_utils::futures::block_on_spawn_all;
+use rustc_hash::FxHashMap;
 
 impl PluginDriver {
   #[tracing::instrument(level = "trace", skip_all)]
@@ -69,11 +74,12 @@ impl PluginDriver {
     skipped_plugins
   }
 
+  /// Returns the output of the first plugin resolving the specifier, with the name of the plugin.
   pub async fn resolve_id(
     &self,
     args: &HookResolveIdArgs<'_>,
     skipped_resolve_calls: Option<&Vec<Arc<HookResolveIdSkipped>>>,
-  ) -> HookResolveIdReturn {
+  ) -> Result<Option<(HookResolveIdOutput, Cow<'static, str>)>> {
     let skipped_plugins =
       Self::get_resolve_call_skipped_plugins(args.specifier, args.importer, skipped_resolve_calls);
     for (plugin_idx, plugin, ctx) in
@@ -97,7 +103,7 @@ impl PluginDriver {
         )
         .await?
       {
-        return Ok(Some(r));
+        return Ok(Some((r, plugin.call_name())));
       }
     }
     Ok(None)
@@ -109,7 +115,7 @@ impl PluginDriver {
     &self,
     args: &HookResolveIdArgs<'_>,
     skipped_resolve_calls: Option<&Vec<Arc<HookResolveIdSkipped>>>,
-  ) -> HookResolveIdReturn {
+  ) -> Result<Option<(HookResolveIdOutput, Cow<'static, str>)>> {
     let skipped_plugins =
       Self::get_resolve_call_skipped_plugins(args.specifier, args.importer, skipped_resolve_calls);
     for (plugin_idx, plugin, ctx) in
@@ -133,16 +139,20 @@ impl PluginDriver {
         )
         .await?
       {
-        return Ok(Some(r));
+        return Ok(Some((r, plugin.call_name())));
       }
     }
     Ok(None)
   }
 
-  pub async fn load(&self, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+  /// Returns the output of the first plugin loading the module, with the name of the plugin.
+  pub async fn load(
+    &self,
+    args: &HookLoadArgs<'_>,
+  ) -> Result<Option<(HookLoadOutput, Cow<'static, str>)>> {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_load_meta) {
       if let Some(r) = plugin.call_load(ctx, args).await? {
-        return Ok(Some(r));
+        return Ok(Some((r, plugin.call_name())));
       }
     }
     Ok(None)
@@ -165,6 +175,7 @@ impl PluginDriver {
         )
         .await?
       {
+        let is_map_missing = r.map.is_none();
         if let Some(mut map) = r.map {
           // If sourcemap  hasn't `sources`, using original id to fill it.
           if map.get_source(0).map_or(true, str::is_empty) {
@@ -180,6 +191,13 @@ impl PluginDriver {
           *side_effects = Some(v);
         }
         if let Some(v) = r.code {
+          // Code without a sourcemap is treated as mapped to itself, which is only close enough if lines are kept.
+          if is_map_missing && lines_count(&v) != lines_count(&code) {
+            self.add_warning(BuildDiagnostic::sourcemap_broken(
+              plugin.call_name().into_owned(),
+              args.id.to_string(),
+            ));
+          }
           code = v;
         }
         if let Some(ty) = r.module_type {
@@ -209,10 +227,40 @@ impl PluginDriver {
     Ok(())
   }
 
+  pub async fn rename_exports(
+    &self,
+    id: &ModuleId,
+    exports: &mut FxHashMap<Rstr, LocalExport>,
+  ) -> HookNoopReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_rename_exports_meta)
+    {
+      plugin.call_rename_exports(ctx, id, exports).await?;
+    }
+    Ok(())
+  }
+
   pub async fn build_end(&self, args: Option<&HookBuildEndArgs>) -> HookNoopReturn {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_build_end_meta) {
       plugin.call_build_end(ctx, args).await?;
     }
     Ok(())
   }
+
+  pub async fn watch_change(&self, id: &str, event: ChangeEvent) -> HookNoopReturn {
+    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_watch_change_meta)
+    {
+      plugin.call_watch_change(ctx, id, event).await?;
+    }
+    Ok(())
+  }
+
+  pub async fn close_watcher(&self) -> HookNoopReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_close_watcher_meta)
+    {
+      plugin.call_close_watcher(ctx).await?;
+    }
+    Ok(())
+  }
 }
@@ -1,18 +1,22 @@
 use std::{
+  borrow::Cow,
   ops::Deref,
-  sync::{Arc, OnceLock, Weak},
+  sync::{Arc, Mutex, OnceLock, RwLock, Weak},
   vec,
 };
 
-use rolldown_common::{ModuleTable, SharedFileEmitter};
+use arcstr::ArcStr;
+use rolldown_common::{ModuleInfo, ModuleTable, SharedFileEmitter};
+use rolldown_error::BuildDiagnostic;
 use rolldown_resolver::Resolver;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   __inner::SharedPluginable,
   plugin_context::PluginContextImpl,
   type_aliases::{IndexPluginContext, IndexPluginable},
   types::plugin_idx::PluginIdx,
-  PluginContext, PluginHookMeta, PluginOrder,
+  ContextMeta, PluginContext, PluginHookMeta, PluginOrder,
 };
 
 mod build_hooks;
@@ -21,9 +25,16 @@ mod output_hooks;
 pub type SharedPluginDriver = Arc<PluginDriver>;
 
 pub struct PluginDriver {
+  meta: Arc<ContextMeta>,
   plugins: IndexPluginable,
   contexts: IndexPluginContext,
   order_indicates: HookOrderIndicates,
+  /// Files that should trigger a rebuild when changed, collected during a build.
+  watch_files: Mutex<FxHashSet<ArcStr>>,
+  /// Infos of the modules of the last scanned module graph, by module id.
+  module_infos: RwLock<FxHashMap<ArcStr, ModuleInfo>>,
+  /// Warnings reported by plugins through `PluginContext::warn`, in reporting order.
+  warnings: Mutex<Vec<BuildDiagnostic>>,
 }
 
 impl PluginDriver {
@@ -31,12 +42,25 @@ impl PluginDriver {
     plugins: Vec<SharedPluginable>,
     resolver: &Arc<Resolver>,
     file_emitter: &SharedFileEmitter,
+    meta: ContextMeta,
   ) -> SharedPluginDriver {
     Arc::new_cyclic(|plugin_driver| {
+      let meta = Arc::new(meta);
       let mut index_plugins = IndexPluginable::with_capacity(plugins.len());
       let mut index_contexts = IndexPluginContext::with_capacity(plugins.len());
 
-      plugins.into_iter().for_each(|plugin| {
+      let mut applicable_plugins = plugins
+        .into_iter()
+        .filter(|plugin| plugin.call_apply().is_applicable(meta.watch_mode))
+        .collect::<Vec<_>>();
+      // Plugins are indexed in the order they are enforced, which is the order their hooks run in unless ordered by
+      // the meta of the hook. The sort is stable, so the registration order is kept within each group.
+      applicable_plugins.sort_by_key(|plugin| match plugin.call_enforce() {
+        Some(PluginOrder::Pre) => 0,
+        None => 1,
+        Some(PluginOrder::Post) => 2,
+      });
+      applicable_plugins.into_iter().for_each(|plugin| {
         let plugin_idx = index_plugins.push(Arc::clone(&plugin));
         index_contexts.push(
           PluginContextImpl {
@@ -46,19 +70,82 @@ impl PluginDriver {
             resolver: Arc::clone(resolver),
             file_emitter: Arc::clone(file_emitter),
             module_table: OnceLock::default(),
+            meta: Arc::clone(&meta),
           }
           .into(),
         );
       });
 
       Self {
+        meta,
         order_indicates: HookOrderIndicates::new(&index_plugins),
         plugins: index_plugins,
         contexts: index_contexts,
+        watch_files: Mutex::default(),
+        module_infos: RwLock::default(),
+        warnings: Mutex::default(),
       }
     })
   }
 
+  /// Creates a driver running the same plugins, without carrying over any state from the builds this driver was
+  /// used for, such as the module table.
+  pub fn new_shared_with_same_plugins(
+    &self,
+    resolver: &Arc<Resolver>,
+    file_emitter: &SharedFileEmitter,
+  ) -> SharedPluginDriver {
+    Self::new_shared(
+      self.plugins.iter().map(Arc::clone).collect(),
+      resolver,
+      file_emitter,
+      ContextMeta::new(self.meta.watch_mode),
+    )
+  }
+
+  pub(crate) fn plugin_name(&self, plugin_idx: PluginIdx) -> Cow<'static, str> {
+    self.plugins[plugin_idx].call_name()
+  }
+
+  pub(crate) fn add_warning(&self, warning: BuildDiagnostic) {
+    self.warnings.lock().unwrap().push(warning);
+  }
+
+  /// Takes the warnings reported by plugins since the last call.
+  pub fn take_warnings(&self) -> Vec<BuildDiagnostic> {
+    std::mem::take(&mut *self.warnings.lock().unwrap())
+  }
+
+  pub fn add_watch_file(&self, path: ArcStr) {
+    self.watch_files.lock().unwrap().insert(path);
+  }
+
+  /// Clears the watch files collected by the previous build.
+  pub fn clear_watch_files(&self) {
+    self.watch_files.lock().unwrap().clear();
+  }
+
+  /// Sorted watch files added by plugins and by loading modules.
+  pub fn watch_files(&self) -> Vec<ArcStr> {
+    let mut watch_files = self.watch_files.lock().unwrap().iter().cloned().collect::<Vec<_>>();
+    watch_files.sort_unstable();
+    watch_files
+  }
+
+  /// Let plugins query the modules of `module_table` through `PluginContext::get_module_info`, which works from the
+  /// `build_end` hook on.
+  pub fn set_module_infos(&self, module_table: &ModuleTable) {
+    *self.module_infos.write().unwrap() = module_table
+      .to_module_infos()
+      .into_iter()
+      .map(|info| (ArcStr::from(info.id.as_str()), info))
+      .collect();
+  }
+
+  pub(crate) fn get_module_info(&self, module_id: &str) -> Option<ModuleInfo> {
+    self.module_infos.read().unwrap().get(module_id).cloned()
+  }
+
   pub fn set_module_table(&self, module_table: &'static ModuleTable) {
     self.contexts.iter().for_each(|ctx| {
       ctx.module_table.set(module_table).expect("module_table is already set before");
@@ -93,17 +180,23 @@ pub struct HookOrderIndicates {
   pub order_by_load_meta: Vec<PluginIdx>,
   pub order_by_transform_meta: Vec<PluginIdx>,
   pub order_by_module_parsed_meta: Vec<PluginIdx>,
+  pub order_by_rename_exports_meta: Vec<PluginIdx>,
   pub order_by_build_end_meta: Vec<PluginIdx>,
+  pub order_by_watch_change_meta: Vec<PluginIdx>,
+  pub order_by_close_watcher_meta: Vec<PluginIdx>,
   pub order_by_render_start_meta: Vec<PluginIdx>,
   pub order_by_banner_meta: Vec<PluginIdx>,
   pub order_by_footer_meta: Vec<PluginIdx>,
   pub order_by_intro_meta: Vec<PluginIdx>,
   pub order_by_outro_meta: Vec<PluginIdx>,
   pub order_by_render_chunk_meta: Vec<PluginIdx>,
+  pub order_by_render_external_specifier_meta: Vec<PluginIdx>,
+  pub order_by_resolve_file_url_meta: Vec<PluginIdx>,
   pub order_by_augment_chunk_hash_meta: Vec<PluginIdx>,
   pub order_by_render_error_meta: Vec<PluginIdx>,
   pub order_by_generate_bundle_meta: Vec<PluginIdx>,
   pub order_by_write_bundle_meta: Vec<PluginIdx>,
+  pub order_by_close_bundle_meta: Vec<PluginIdx>,
   pub order_by_transform_ast_meta: Vec<PluginIdx>,
 }
 
@@ -126,9 +219,18 @@ impl HookOrderIndicates {
       order_by_module_parsed_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_module_parsed_meta()
       }),
+      order_by_rename_exports_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_rename_exports_meta()
+      }),
       order_by_build_end_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_build_end_meta()
       }),
+      order_by_watch_change_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_watch_change_meta()
+      }),
+      order_by_close_watcher_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_close_watcher_meta()
+      }),
       order_by_render_start_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_start_meta()
       }),
@@ -143,6 +245,13 @@ impl HookOrderIndicates {
       order_by_render_chunk_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_chunk_meta()
       }),
+      order_by_render_external_specifier_meta: Self::sort_plugins_by_hook_meta(
+        index_plugins,
+        |p| p.call_render_external_specifier_meta(),
+      ),
+      order_by_resolve_file_url_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_resolve_file_url_meta()
+      }),
       order_by_augment_chunk_hash_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_augment_chunk_hash_meta()
       }),
@@ -155,6 +264,9 @@ impl HookOrderIndicates {
       order_by_write_bundle_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_write_bundle_meta()
       }),
+      order_by_close_bundle_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_close_bundle_meta()
+      }),
       order_by_transform_ast_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_transform_ast_meta()
       }),
@@ -1,15 +1,19 @@
 use crate::types::hook_render_error::HookRenderErrorArgs;
 use crate::{HookAddonArgs, PluginDriver};
-use crate::{HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs};
+use crate::{
+  HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs, HookRenderExternalSpecifierArgs,
+  HookRenderExternalSpecifierReturn, HookRenderStartArgs, HookResolveFileUrlArgs,
+  HookResolveFileUrlReturn, HookWriteBundleArgs,
+};
 use anyhow::{Ok, Result};
 use rolldown_common::{Output, RollupRenderedChunk};
 use rolldown_sourcemap::SourceMap;
 
 impl PluginDriver {
-  pub async fn render_start(&self) -> HookNoopReturn {
+  pub async fn render_start(&self, args: &HookRenderStartArgs<'_>) -> HookNoopReturn {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_render_start_meta)
     {
-      plugin.call_render_start(ctx).await?;
+      plugin.call_render_start(ctx, args).await?;
     }
     Ok(())
   }
@@ -21,7 +25,9 @@ impl PluginDriver {
   ) -> Result<Option<String>> {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_banner_meta) {
       if let Some(r) = plugin.call_banner(ctx, &args).await? {
-        banner.push('\n');
+        if !banner.is_empty() {
+          banner.push('\n');
+        }
         banner.push_str(r.as_str());
       }
     }
@@ -38,7 +44,9 @@ impl PluginDriver {
   ) -> Result<Option<String>> {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_footer_meta) {
       if let Some(r) = plugin.call_footer(ctx, &args).await? {
-        footer.push('\n');
+        if !footer.is_empty() {
+          footer.push('\n');
+        }
         footer.push_str(r.as_str());
       }
     }
@@ -51,7 +59,9 @@ impl PluginDriver {
   pub async fn intro(&self, args: HookAddonArgs<'_>, mut intro: String) -> Result<Option<String>> {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_intro_meta) {
       if let Some(r) = plugin.call_intro(ctx, &args).await? {
-        intro.push('\n');
+        if !intro.is_empty() {
+          intro.push('\n');
+        }
         intro.push_str(r.as_str());
       }
     }
@@ -64,7 +74,9 @@ impl PluginDriver {
   pub async fn outro(&self, args: HookAddonArgs<'_>, mut outro: String) -> Result<Option<String>> {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_outro_meta) {
       if let Some(r) = plugin.call_outro(ctx, &args).await? {
-        outro.push('\n');
+        if !outro.is_empty() {
+          outro.push('\n');
+        }
         outro.push_str(r.as_str());
       }
     }
@@ -91,6 +103,34 @@ impl PluginDriver {
     Ok((args.code, sourcemap_chain))
   }
 
+  pub async fn render_external_specifier(
+    &self,
+    args: &HookRenderExternalSpecifierArgs<'_>,
+  ) -> HookRenderExternalSpecifierReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_external_specifier_meta)
+    {
+      if let Some(r) = plugin.call_render_external_specifier(ctx, args).await? {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
+  pub async fn resolve_file_url(
+    &self,
+    args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookResolveFileUrlReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_resolve_file_url_meta)
+    {
+      if let Some(r) = plugin.call_resolve_file_url(ctx, args).await? {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
   pub async fn augment_chunk_hash(
     &self,
     chunk: &RollupRenderedChunk,
@@ -124,12 +164,26 @@ impl PluginDriver {
     Ok(())
   }
 
-  pub async fn write_bundle(&self, bundle: &mut Vec<Output>) -> HookNoopReturn {
+  pub async fn write_bundle(&self, args: &mut HookWriteBundleArgs<'_>) -> HookNoopReturn {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_write_bundle_meta)
     {
-      plugin.call_write_bundle(ctx, bundle).await?;
-      ctx.file_emitter.add_additional_files(bundle);
+      plugin.call_write_bundle(ctx, args).await?;
+      ctx.file_emitter.add_additional_files(args.bundle);
     }
     Ok(())
   }
+
+  /// Every plugin gets the chance to release its resources, even if closing a previous one failed. The first error
+  /// is returned.
+  pub async fn close_bundle(&self) -> HookNoopReturn {
+    let mut ret = Ok(());
+    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_close_bundle_meta)
+    {
+      let closed = plugin.call_close_bundle(ctx).await;
+      if ret.is_ok() {
+        ret = closed;
+      }
+    }
+    ret
+  }
 }
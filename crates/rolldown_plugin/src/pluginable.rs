@@ -2,18 +2,24 @@ use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
 
 use super::plugin_context::PluginContext;
 use crate::{
-  plugin_hook_meta::PluginHookMeta,
+  plugin_hook_meta::{PluginHookMeta, PluginOrder},
   transform_plugin_context::TransformPluginContext,
   types::{hook_render_error::HookRenderErrorArgs, hook_transform_ast_args::HookTransformAstArgs},
-  HookAddonArgs, HookBuildEndArgs, HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs,
-  HookResolveIdArgs, HookTransformArgs, Plugin,
+  ChangeEvent, HookAddonArgs, HookBuildEndArgs, HookInjectionOutputReturn, HookLoadArgs,
+  HookRenderChunkArgs, HookRenderExternalSpecifierArgs, HookRenderStartArgs,
+  HookResolveFileUrlArgs, HookResolveIdArgs, HookTransformArgs, HookWriteBundleArgs, Plugin,
+  PluginApply,
 };
-use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk};
+use rolldown_common::{LocalExport, ModuleId, ModuleInfo, Output, RollupRenderedChunk};
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashMap;
 
 pub use crate::plugin::HookAugmentChunkHashReturn;
 pub use crate::plugin::HookLoadReturn;
 pub use crate::plugin::HookNoopReturn;
 pub use crate::plugin::HookRenderChunkReturn;
+pub use crate::plugin::HookRenderExternalSpecifierReturn;
+pub use crate::plugin::HookResolveFileUrlReturn;
 pub use crate::plugin::HookResolveIdReturn;
 pub use crate::plugin::HookTransformAstReturn;
 pub use crate::plugin::HookTransformReturn;
@@ -30,6 +36,10 @@ pub type SharedPluginable = Arc<dyn Pluginable>;
 pub trait Pluginable: Any + Debug + Send + Sync + 'static {
   fn call_name(&self) -> Cow<'static, str>;
 
+  fn call_apply(&self) -> PluginApply;
+
+  fn call_enforce(&self) -> Option<PluginOrder>;
+
   // The `option` hook consider call at node side.
 
   // --- Build hooks ---
@@ -85,6 +95,15 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_module_parsed_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_rename_exports(
+    &self,
+    _ctx: &PluginContext,
+    _id: &ModuleId,
+    _exports: &mut FxHashMap<Rstr, LocalExport>,
+  ) -> HookNoopReturn;
+
+  fn call_rename_exports_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_build_end(
     &self,
     _ctx: &PluginContext,
@@ -93,9 +112,26 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_build_end_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_watch_change(
+    &self,
+    _ctx: &PluginContext,
+    _id: &str,
+    _event: ChangeEvent,
+  ) -> HookNoopReturn;
+
+  fn call_watch_change_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_close_watcher(&self, _ctx: &PluginContext) -> HookNoopReturn;
+
+  fn call_close_watcher_meta(&self) -> Option<PluginHookMeta>;
+
   // --- Generate hooks ---
 
-  async fn call_render_start(&self, _ctx: &PluginContext) -> HookNoopReturn;
+  async fn call_render_start(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderStartArgs,
+  ) -> HookNoopReturn;
 
   fn call_render_start_meta(&self) -> Option<PluginHookMeta>;
 
@@ -139,6 +175,22 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_render_chunk_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_render_external_specifier(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderExternalSpecifierArgs<'_>,
+  ) -> HookRenderExternalSpecifierReturn;
+
+  fn call_render_external_specifier_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookResolveFileUrlReturn;
+
+  fn call_resolve_file_url_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_augment_chunk_hash(
     &self,
     _ctx: &PluginContext,
@@ -167,10 +219,14 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
   async fn call_write_bundle(
     &self,
     _ctx: &PluginContext,
-    _bundle: &mut Vec<Output>,
+    _args: &mut HookWriteBundleArgs<'_>,
   ) -> HookNoopReturn;
 
   fn call_write_bundle_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_close_bundle(&self, _ctx: &PluginContext) -> HookNoopReturn;
+
+  fn call_close_bundle_meta(&self) -> Option<PluginHookMeta>;
 }
 
 #[async_trait::async_trait]
@@ -179,6 +235,14 @@ impl<T: Plugin> Pluginable for T {
     Plugin::name(self)
   }
 
+  fn call_apply(&self) -> PluginApply {
+    Plugin::apply(self)
+  }
+
+  fn call_enforce(&self) -> Option<PluginOrder> {
+    Plugin::enforce(self)
+  }
+
   async fn call_build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
     Plugin::build_start(self, ctx).await
   }
@@ -244,6 +308,19 @@ impl<T: Plugin> Pluginable for T {
     Plugin::module_parsed_meta(self)
   }
 
+  async fn call_rename_exports(
+    &self,
+    ctx: &PluginContext,
+    id: &ModuleId,
+    exports: &mut FxHashMap<Rstr, LocalExport>,
+  ) -> HookNoopReturn {
+    Plugin::rename_exports(self, ctx, id, exports).await
+  }
+
+  fn call_rename_exports_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::rename_exports_meta(self)
+  }
+
   async fn call_build_end(
     &self,
     ctx: &PluginContext,
@@ -256,8 +333,33 @@ impl<T: Plugin> Pluginable for T {
     Plugin::build_end_meta(self)
   }
 
-  async fn call_render_start(&self, ctx: &PluginContext) -> HookNoopReturn {
-    Plugin::render_start(self, ctx).await
+  async fn call_watch_change(
+    &self,
+    ctx: &PluginContext,
+    id: &str,
+    event: ChangeEvent,
+  ) -> HookNoopReturn {
+    Plugin::watch_change(self, ctx, id, event).await
+  }
+
+  fn call_watch_change_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::watch_change_meta(self)
+  }
+
+  async fn call_close_watcher(&self, ctx: &PluginContext) -> HookNoopReturn {
+    Plugin::close_watcher(self, ctx).await
+  }
+
+  fn call_close_watcher_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::close_watcher_meta(self)
+  }
+
+  async fn call_render_start(
+    &self,
+    ctx: &PluginContext,
+    args: &HookRenderStartArgs,
+  ) -> HookNoopReturn {
+    Plugin::render_start(self, ctx, args).await
   }
 
   fn call_render_start_meta(&self) -> Option<PluginHookMeta> {
@@ -324,6 +426,30 @@ impl<T: Plugin> Pluginable for T {
     Plugin::render_chunk_meta(self)
   }
 
+  async fn call_render_external_specifier(
+    &self,
+    ctx: &PluginContext,
+    args: &HookRenderExternalSpecifierArgs<'_>,
+  ) -> HookRenderExternalSpecifierReturn {
+    Plugin::render_external_specifier(self, ctx, args).await
+  }
+
+  fn call_render_external_specifier_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::render_external_specifier_meta(self)
+  }
+
+  async fn call_resolve_file_url(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookResolveFileUrlReturn {
+    Plugin::resolve_file_url(self, ctx, args).await
+  }
+
+  fn call_resolve_file_url_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::resolve_file_url_meta(self)
+  }
+
   async fn call_augment_chunk_hash(
     &self,
     ctx: &PluginContext,
@@ -364,15 +490,23 @@ impl<T: Plugin> Pluginable for T {
   async fn call_write_bundle(
     &self,
     ctx: &PluginContext,
-    bundle: &mut Vec<Output>,
+    args: &mut HookWriteBundleArgs<'_>,
   ) -> HookNoopReturn {
-    Plugin::write_bundle(self, ctx, bundle).await
+    Plugin::write_bundle(self, ctx, args).await
   }
 
   fn call_write_bundle_meta(&self) -> Option<PluginHookMeta> {
     Plugin::write_bundle_meta(self)
   }
 
+  async fn call_close_bundle(&self, ctx: &PluginContext) -> HookNoopReturn {
+    Plugin::close_bundle(self, ctx).await
+  }
+
+  fn call_close_bundle_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::close_bundle_meta(self)
+  }
+
   fn call_transform_ast(
     &self,
     ctx: &PluginContext,
@@ -1,5 +1,5 @@
 use crate::PluginContext;
-use rolldown_sourcemap::SourceMap;
+use rolldown_sourcemap::{collapse_sourcemaps, SourceMap};
 
 #[allow(unused)]
 #[derive(Debug)]
@@ -20,25 +20,13 @@ impl<'a> TransformPluginContext<'a> {
     Self { inner, sourcemap_chain, original_code, id }
   }
 
-  // pub fn get_combined_sourcemap(&self) -> SourceMap {
-  //   if self.sourcemap_chain.is_empty() {
-  //     self.create_sourcemap()
-  //   } else if self.sourcemap_chain.len() == 1 {
-  //     // TODO (fix): clone is not necessary
-  //     self.sourcemap_chain.first().expect("should have one sourcemap").clone()
-  //   } else {
-  //     let sourcemap_chain = self.sourcemap_chain.iter().collect::<Vec<_>>();
-  //     // TODO Here could be cache result for pervious sourcemap_chain, only remapping new sourcemap chain
-  //     collapse_sourcemaps(sourcemap_chain).unwrap_or_else(|| self.create_sourcemap())
-  //   }
-  // }
-
-  // fn create_sourcemap(&self) -> SourceMap {
-  //   let magic_string = MagicString::new(self.original_code);
-  //   magic_string.source_map(SourceMapOptions {
-  //     hires: true,
-  //     include_content: true,
-  //     source: self.id.into(),
-  //   })
-  // }
+  /// The sourcemap from the code loaded for the module to the code passed to the current `transform` hook, composed
+  /// from the sourcemaps returned by `load` and the previous `transform` hooks. `None` if none of them returned one.
+  pub fn get_combined_sourcemap(&self) -> Option<SourceMap> {
+    match self.sourcemap_chain.as_slice() {
+      [] => None,
+      [sourcemap] => Some(sourcemap.clone()),
+      sourcemap_chain => Some(collapse_sourcemaps(sourcemap_chain.iter().collect())),
+    }
+  }
 }
@@ -1,10 +1,16 @@
 use rolldown_common::{side_effects::HookSideEffects, ModuleType};
 use rolldown_sourcemap::SourceMap;
+use rustc_hash::FxHashMap;
 
 #[derive(Debug, Default)]
 pub struct HookLoadOutput {
   pub code: String,
+  /// Sourcemap of `code`, which is chained into the sourcemap of the chunk containing the module.
   pub map: Option<SourceMap>,
+  /// Overrides the side effects of the module determined by the bundler. With [HookSideEffects::False], the module is
+  /// left out of the output if none of its exports are used, even if it's imported by a bare `import 'module'`.
   pub side_effects: Option<HookSideEffects>,
   pub module_type: Option<ModuleType>,
+  /// Custom meta attached to the module. Keys set by the `resolve_id` hook are overwritten.
+  pub meta: FxHashMap<String, serde_json::Value>,
 }
@@ -0,0 +1,8 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookRenderExternalSpecifierArgs<'a> {
+  /// The specifier of the external module as it was resolved, e.g. `./lib` or `node:fs`.
+  pub specifier: &'a str,
+  pub format: &'a OutputFormat,
+}
@@ -0,0 +1,7 @@
+use rolldown_common::NormalizedBundlerOptions;
+
+#[derive(Debug)]
+pub struct HookRenderStartArgs<'a> {
+  /// The options the output is generated with, after defaults are applied.
+  pub options: &'a NormalizedBundlerOptions,
+}
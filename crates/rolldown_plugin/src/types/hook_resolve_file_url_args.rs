@@ -0,0 +1,14 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookResolveFileUrlArgs<'a> {
+  /// The reference id returned by `emit_file`, as used in `import.meta.ROLLUP_FILE_URL_<reference id>`.
+  pub reference_id: &'a str,
+  /// The file name of the emitted file relative to the output directory, e.g. `assets/img-DdqxQKgX.png`.
+  pub file_name: &'a str,
+  /// The file name of the chunk containing the reference, relative to the output directory.
+  pub chunk_id: &'a str,
+  /// The path of the emitted file relative to the directory of the chunk, e.g. `../assets/img-DdqxQKgX.png`.
+  pub relative_path: &'a str,
+  pub format: &'a OutputFormat,
+}
@@ -11,5 +11,8 @@ pub struct HookResolveIdArgs<'a> {
   // Rollup doesn't have a `kind` field, but rolldown supports cjs, css by default. So we need this
   // field to determine the import kind.
   pub kind: ImportKind,
+  /// Whether `specifier` is the source text of a non-literal `import(expr)`, like `dynamicVar` in
+  /// `import(dynamicVar)`. Such requests are only passed to `resolve_dynamic_import`.
+  pub is_non_literal: bool,
   pub custom: Arc<TypedDashMap>,
 }
@@ -1,8 +1,11 @@
-use rolldown_common::side_effects::HookSideEffects;
+use rolldown_common::{side_effects::HookSideEffects, ResolvedExternal};
+use rustc_hash::FxHashMap;
 
 #[derive(Debug, Default)]
 pub struct HookResolveIdOutput {
   pub id: String,
-  pub external: Option<bool>,
+  pub external: Option<ResolvedExternal>,
   pub side_effects: Option<HookSideEffects>,
+  /// Custom meta attached to the module, which can be read back through `PluginContext::get_module_info`.
+  pub meta: FxHashMap<String, serde_json::Value>,
 }
@@ -5,6 +5,8 @@ use rolldown_sourcemap::SourceMap;
 #[derive(Debug, Default)]
 pub struct HookTransformOutput {
   pub code: Option<String>,
+  /// Sourcemap from the code passed to the hook to `code`. Returning `code` without a sourcemap is fine as long as
+  /// lines are kept, otherwise a `SOURCEMAP_BROKEN` warning is reported.
   pub map: Option<SourceMap>,
   pub side_effects: Option<HookSideEffects>,
   pub module_type: Option<ModuleType>,
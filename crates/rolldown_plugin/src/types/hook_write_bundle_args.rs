@@ -0,0 +1,10 @@
+use std::path::Path;
+
+use rolldown_common::Output;
+
+#[derive(Debug)]
+pub struct HookWriteBundleArgs<'a> {
+  /// The absolute path of the directory the files of `bundle` were written into.
+  pub dir: &'a Path,
+  pub bundle: &'a mut Vec<Output>,
+}
@@ -5,11 +5,19 @@ pub mod hook_load_output;
 pub mod hook_render_chunk_args;
 pub mod hook_render_chunk_output;
 pub mod hook_render_error;
+pub mod hook_render_external_specifier_args;
+pub mod hook_render_start_args;
+pub mod hook_resolve_file_url_args;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_output;
 pub mod hook_resolve_id_skipped;
 pub mod hook_transform_args;
 pub mod hook_transform_ast_args;
 pub mod hook_transform_output;
+pub mod hook_write_bundle_args;
+pub mod plugin_apply;
+pub mod plugin_context_meta;
+pub mod plugin_context_parse_options;
 pub mod plugin_context_resolve_options;
 pub mod plugin_idx;
+pub mod watch_change_event;
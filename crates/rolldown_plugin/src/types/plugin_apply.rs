@@ -0,0 +1,18 @@
+/// In which mode a plugin takes effect, like the `apply` option of Vite plugins.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PluginApply {
+  #[default]
+  Always,
+  BuildOnly,
+  WatchOnly,
+}
+
+impl PluginApply {
+  pub fn is_applicable(self, watch_mode: bool) -> bool {
+    match self {
+      Self::Always => true,
+      Self::BuildOnly => !watch_mode,
+      Self::WatchOnly => watch_mode,
+    }
+  }
+}
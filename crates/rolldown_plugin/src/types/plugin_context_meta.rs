@@ -0,0 +1,19 @@
+/// Information about the running bundler, corresponding to rollup's `this.meta`.
+#[derive(Debug)]
+pub struct ContextMeta {
+  pub rolldown_version: &'static str,
+  /// Whether the bundler is running in watch mode, as set by the `watch` option.
+  pub watch_mode: bool,
+}
+
+impl ContextMeta {
+  pub fn new(watch_mode: bool) -> Self {
+    Self { rolldown_version: env!("CARGO_PKG_VERSION"), watch_mode }
+  }
+}
+
+impl Default for ContextMeta {
+  fn default() -> Self {
+    Self::new(false)
+  }
+}
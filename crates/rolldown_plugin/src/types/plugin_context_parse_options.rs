@@ -0,0 +1,15 @@
+use oxc::span::SourceType;
+
+/// Options of [`crate::PluginContext::parse`]. The code is parsed as JavaScript in an ES module by default, just
+/// like modules loaded by the bundler.
+#[derive(Debug, Default)]
+pub struct PluginContextParseOptions {
+  pub jsx: bool,
+  pub typescript: bool,
+}
+
+impl PluginContextParseOptions {
+  pub(crate) fn source_type(&self) -> SourceType {
+    SourceType::default().with_module(true).with_jsx(self.jsx).with_typescript(self.typescript)
+  }
+}
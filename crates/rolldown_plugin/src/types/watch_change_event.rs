@@ -0,0 +1,7 @@
+/// How a watched file changed, passed to the `watch_change` hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeEvent {
+  Create,
+  Update,
+  Delete,
+}
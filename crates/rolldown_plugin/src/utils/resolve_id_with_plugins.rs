@@ -1,7 +1,8 @@
 use crate::{
-  types::hook_resolve_id_skipped::HookResolveIdSkipped, HookResolveIdArgs, PluginDriver,
+  types::hook_resolve_id_skipped::HookResolveIdSkipped, HookResolveIdArgs, HookResolveIdOutput,
+  PluginDriver,
 };
-use rolldown_common::{ImportKind, ModuleDefFormat, ResolvedId};
+use rolldown_common::{ImportKind, ModuleDefFormat, ModuleMeta, ResolvedExternal, ResolvedId};
 use rolldown_resolver::{ResolveError, Resolver};
 use std::{path::Path, sync::Arc};
 use typedmap::TypedDashMap;
@@ -26,51 +27,39 @@ pub async fn resolve_id_with_plugins(
   custom: Arc<TypedDashMap>,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   if matches!(import_kind, ImportKind::DynamicImport) {
-    if let Some(r) = plugin_driver
+    if let Some((r, plugin_name)) = plugin_driver
       .resolve_dynamic_import(
         &HookResolveIdArgs {
           importer: importer.map(std::convert::AsRef::as_ref),
           specifier: request,
           is_entry,
           kind: import_kind,
+          is_non_literal: false,
           custom: Arc::clone(&custom),
         },
         skipped_resolve_calls.as_ref(),
       )
       .await?
     {
-      return Ok(Ok(ResolvedId {
-        module_def_format: ModuleDefFormat::from_path(&r.id),
-        ignored: false,
-        id: r.id.into(),
-        is_external: matches!(r.external, Some(true)),
-        package_json: None,
-        side_effects: r.side_effects,
-      }));
+      return Ok(Ok(resolved_id_from_hook_output(r, &plugin_name)));
     }
   }
   // Run plugin resolve_id first, if it is None use internal resolver as fallback
-  if let Some(r) = plugin_driver
+  if let Some((r, plugin_name)) = plugin_driver
     .resolve_id(
       &HookResolveIdArgs {
         importer: importer.map(std::convert::AsRef::as_ref),
         specifier: request,
         is_entry,
         kind: import_kind,
+        is_non_literal: false,
         custom: Arc::clone(&custom),
       },
       skipped_resolve_calls.as_ref(),
     )
     .await?
   {
-    return Ok(Ok(ResolvedId {
-      module_def_format: ModuleDefFormat::from_path(&r.id),
-      ignored: false,
-      id: r.id.into(),
-      is_external: matches!(r.external, Some(true)),
-      package_json: None,
-      side_effects: r.side_effects,
-    }));
+    return Ok(Ok(resolved_id_from_hook_output(r, &plugin_name)));
   }
 
   // Auto external http url or data url
@@ -80,14 +69,56 @@ pub async fn resolve_id_with_plugins(
       module_def_format: ModuleDefFormat::Unknown,
       ignored: false,
       is_external: true,
+      is_absolute_external: false,
       package_json: None,
       side_effects: None,
+      meta: ModuleMeta::default(),
     }));
   }
 
   resolve_id(resolver, request, importer, import_kind)
 }
 
+/// Resolve the request of a non-literal `import(expr)`, which is the source text of `expr`. It isn't a path, so
+/// only the `resolve_dynamic_import` hook is called. Returns `None` if no plugin resolves it.
+pub async fn resolve_non_literal_dynamic_import(
+  plugin_driver: &PluginDriver,
+  request: &str,
+  importer: &str,
+) -> anyhow::Result<Option<ResolvedId>> {
+  let resolved = plugin_driver
+    .resolve_dynamic_import(
+      &HookResolveIdArgs {
+        importer: Some(importer),
+        specifier: request,
+        is_entry: false,
+        kind: ImportKind::DynamicImport,
+        is_non_literal: true,
+        custom: Arc::default(),
+      },
+      None,
+    )
+    .await?;
+  Ok(resolved.map(|(r, plugin_name)| resolved_id_from_hook_output(r, &plugin_name)))
+}
+
+fn resolved_id_from_hook_output(r: HookResolveIdOutput, plugin_name: &str) -> ResolvedId {
+  ResolvedId {
+    module_def_format: ModuleDefFormat::from_path(&r.id),
+    ignored: false,
+    id: r.id.into(),
+    is_external: r.external.is_some_and(ResolvedExternal::is_external),
+    is_absolute_external: matches!(r.external, Some(ResolvedExternal::Absolute)),
+    package_json: None,
+    side_effects: r.side_effects,
+    meta: {
+      let mut meta = ModuleMeta::default();
+      meta.merge(plugin_name, r.meta);
+      meta
+    },
+  }
+}
+
 fn resolve_id(
   resolver: &Resolver,
   request: &str,
@@ -102,18 +133,22 @@ fn resolve_id(
         id: specifier.into(),
         ignored: false,
         is_external: true,
+        is_absolute_external: false,
         module_def_format: ModuleDefFormat::Unknown,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       })),
       ResolveError::Ignored(p) => Ok(Ok(ResolvedId {
         //(hyf0) TODO: This `p` doesn't seem to contains `query` or `fragment` of the input. We need to make sure this is ok
         id: p.to_str().expect("Should be valid utf8").into(),
         ignored: true,
         is_external: false,
+        is_absolute_external: false,
         module_def_format: ModuleDefFormat::Unknown,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       })),
       _ => Ok(Err(err)),
     }
@@ -123,8 +158,10 @@ fn resolve_id(
       ignored: false,
       module_def_format: resolved.module_def_format,
       is_external: false,
+      is_absolute_external: false,
       package_json: resolved.package_json,
       side_effects: None,
+      meta: ModuleMeta::default(),
     }))
   }
 }
@@ -1,5 +1,6 @@
 use rolldown_common::ModuleType;
 use rolldown_plugin::{HookTransformOutput, Plugin};
+use rolldown_sourcemap::SourceMap;
 use serde_json::Value;
 use std::borrow::Cow;
 
@@ -36,6 +37,8 @@ impl Plugin for JsonPlugin {
       };
       return Ok(Some(HookTransformOutput {
         code: Some(normalized_code),
+        // There is nothing worth mapping in JSON, so an empty sourcemap is enough.
+        map: Some(SourceMap::new(None, vec![], None, vec![], None, vec![], None)),
         module_type: Some(ModuleType::Js),
         ..Default::default()
       }));
@@ -1,4 +1,4 @@
-use rolldown_common::{EmittedAsset, ModuleId, Output, OutputAsset, OutputChunk};
+use rolldown_common::{EmittedAsset, EmittedFile, ModuleId, Output, OutputAsset, OutputChunk};
 use rolldown_plugin::{HookNoopReturn, Plugin, PluginContext};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Serialize;
@@ -94,8 +94,9 @@ impl Plugin for ManifestPlugin {
       }
       let original_name = &asset.original_name;
       if !manifest.contains_key(original_name) {
-        let filename = ctx.get_file_name(reference_id.as_str());
-        let asset = file_name_to_asset.remove(&filename);
+        let asset = ctx
+          .get_file_name(reference_id.as_str())
+          .and_then(|filename| file_name_to_asset.remove(&filename));
         if let Some(asset) = asset {
           manifest.insert(original_name.clone(), asset);
         }
@@ -107,11 +108,11 @@ impl Plugin for ManifestPlugin {
     // let output = config.build.rollupOptions?.output
     // let outputLength = Array.isArray(output) ? output.length : 1
     // if output_count >= outputLength {
-    ctx.emit_file(EmittedAsset {
+    ctx.emit_file(EmittedFile::Asset(EmittedAsset {
       file_name: Some(self.config.out_path.clone()),
       name: None,
       source: (serde_json::to_string_pretty(&manifest).unwrap()).into(),
-    });
+    }))?;
     // }
 
     Ok(())
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, fs, path::Path};
 
-use rolldown_common::{AssetSource, EmittedAsset};
+use rolldown_common::{AssetSource, EmittedAsset, EmittedFile};
 use rolldown_plugin::{
   HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
   HookResolveIdReturn, Plugin, PluginContext,
@@ -39,12 +39,12 @@ impl Plugin for WasmHelperPlugin {
     if args.id.ends_with(".wasm?init") {
       let id = args.id.replace("?init", "");
       let file_path = Path::new(&id);
-      let reference_id = ctx.emit_file(EmittedAsset {
+      let reference_id = ctx.emit_file(EmittedFile::Asset(EmittedAsset {
         name: file_path.file_name().map(|x| x.to_string_lossy().to_string()),
         source: AssetSource::Buffer(fs::read(file_path)?),
         file_name: None,
-      });
-      let url = ctx.get_file_name(&reference_id);
+      }))?;
+      let url = ctx.get_file_name(&reference_id).expect("emitted assets should have file names");
       return Ok(Some(HookLoadOutput {
         code: format!(
           r#"import initWasm from "{WASM_HELPER_ID}"; 
@@ -135,6 +135,11 @@ impl<F: FileSystem + Default> Resolver<F> {
   pub fn cwd(&self) -> &PathBuf {
     &self.cwd
   }
+
+  /// Whether resolved paths are canonicalized to their real paths, which is controlled by `resolve.symlinks`.
+  pub fn resolves_symlinks(&self) -> bool {
+    self.default_resolver.options().symlinks
+  }
 }
 
 #[derive(Debug)]
@@ -152,7 +157,9 @@ impl<F: FileSystem + Default> Resolver<F> {
     import_kind: ImportKind,
   ) -> anyhow::Result<Result<ResolveReturn, ResolveError>> {
     let selected_resolver = match import_kind {
-      ImportKind::Import | ImportKind::DynamicImport => &self.import_resolver,
+      ImportKind::Import | ImportKind::DynamicImport | ImportKind::ResolveOnly => {
+        &self.import_resolver
+      }
       ImportKind::Require => &self.require_resolver,
     };
     let resolution = if let Some(importer) = importer {
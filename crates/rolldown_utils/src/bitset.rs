@@ -21,6 +21,12 @@ impl BitSet {
   pub fn is_empty(&self) -> bool {
     self.entries.iter().all(|&e| e == 0)
   }
+
+  /// Sets all bits that are set in `other`, which must have the same size.
+  pub fn union(&mut self, other: &BitSet) {
+    debug_assert_eq!(self.entries.len(), other.entries.len());
+    self.entries.iter_mut().zip(&other.entries).for_each(|(a, b)| *a |= b);
+  }
 }
 
 impl Display for BitSet {
@@ -62,5 +68,11 @@ mod tests {
     assert_eq!(bs.to_string(), "10000011_00000001");
     bs.set_bit(15);
     assert_eq!(bs.to_string(), "10000011_10000001");
+
+    let mut other = BitSet::new(9);
+    other.set_bit(2);
+    other.set_bit(8);
+    bs.union(&other);
+    assert_eq!(bs.to_string(), "10000111_10000001");
   }
 }
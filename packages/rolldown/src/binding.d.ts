@@ -53,6 +53,7 @@ export declare class BindingOutputs {
 export declare class BindingPluginContext {
   resolve(specifier: string, importer?: string | undefined | null, extraOptions?: BindingPluginContextResolveOptions | undefined | null): Promise<BindingPluginContextResolvedId | null>
   emitFile(file: BindingEmittedAsset): string
+  emitChunk(file: BindingEmittedChunk): string
   getFileName(referenceId: string): string
   getModuleInfo(moduleId: string): BindingModuleInfo | null
   getModuleIds(): Array<string> | null
@@ -138,6 +139,11 @@ export interface BindingEmittedAsset {
   source: BindingAssetSource
 }
 
+export interface BindingEmittedChunk {
+  name?: string
+  id: string
+}
+
 export interface BindingGlobImportPluginConfig {
   root?: string
   restoreQueryExtension?: boolean
@@ -158,7 +164,7 @@ export interface BindingHookRenderChunkOutput {
 export interface BindingHookResolveIdExtraArgs {
   custom?: number
   isEntry: boolean
-  kind: 'import' | 'dynamic-import' | 'require-call'
+  kind: 'import' | 'dynamic-import' | 'require-call' | 'resolve-only'
 }
 
 export interface BindingHookResolveIdOutput {
@@ -204,9 +210,12 @@ export interface BindingInputOptions {
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
   resolve?: BindingResolveOptions
   shimMissingExports?: boolean
+  /** Known-safe circular dependencies, each listing its modules relative to `cwd` in execution order. */
+  cycleOrder?: Array<Array<string>>
   platform?: 'node' | 'browser' | 'neutral'
   logLevel?: BindingLogLevel
   onLog: (logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void
+  onModuleAdded?: undefined | ((id: string) => void)
   cwd: string
   treeshake?: BindingTreeshake
   moduleTypes?: Record<string, string>
@@ -256,8 +265,9 @@ export interface BindingOutputOptions {
   extend?: boolean
   externalLiveBindings?: boolean
   footer?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
-  format?: 'es' | 'cjs' | 'iife'
+  format?: 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'
   globals?: Record<string, string>
+  interop?: 'auto' | 'default' | 'esModule' | 'compat'
   intro?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   outro?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
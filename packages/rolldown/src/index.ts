@@ -34,6 +34,7 @@ import { ConfigExport } from './types/config-export'
 import { RolldownBuild } from './rolldown-build'
 import {
   EmittedAsset,
+  EmittedChunk,
   EmittedFile,
   PluginContext,
 } from './plugin/plugin-context'
@@ -87,6 +88,7 @@ export type {
   MinimalPluginContext,
   EmittedFile,
   EmittedAsset,
+  EmittedChunk,
   CustomPluginOptions,
   AsyncPluginHooks,
   ParallelPluginHooks,
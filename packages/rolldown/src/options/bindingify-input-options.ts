@@ -68,11 +68,13 @@ export function bindingifyInputOptions(
       : undefined,
     platform: options.platform,
     shimMissingExports: options.shimMissingExports,
+    cycleOrder: options.cycleOrder,
     // @ts-ignore TODO The typing should import from binding
     logLevel: bindingifyLogLevel(options.logLevel),
     onLog: (level, log) => {
       options.onLog(level, { code: log.code, message: log.message })
     },
+    onModuleAdded: options.onModuleAdded,
     treeshake: options.treeshake,
     moduleTypes: options.moduleTypes,
     define: options.define ? Object.entries(options.define) : undefined,
@@ -8,6 +8,7 @@ export function bindingifyOutputOptions(
     dir,
     format,
     exports,
+    interop,
     sourcemap,
     sourcemapIgnoreList,
     sourcemapPathTransform,
@@ -32,9 +33,16 @@ export function bindingifyOutputOptions(
           return 'cjs'
         case 'iife':
           return 'iife'
+        case 'umd':
+          return 'umd'
+        case 'amd':
+          return 'amd'
+        case 'system':
+          return 'system'
       }
     })(),
     exports,
+    interop,
     sourcemap: bindingifySourcemap(sourcemap),
     sourcemapIgnoreList,
     sourcemapPathTransform,
@@ -66,11 +74,13 @@ function bindingifySourcemap(
     case 'inline':
       return 'inline'
 
-    case false:
-    case undefined:
     case 'hidden':
       return 'hidden'
 
+    case false:
+    case undefined:
+      return undefined
+
     default:
       throw new Error(`unknown sourcemap: ${sourcemap}`)
   }
@@ -68,6 +68,12 @@ export const inputOptionsSchema = z.strictObject({
     )
     .optional(),
   shimMissingExports: z.boolean().optional(),
+  cycleOrder: z
+    .array(z.array(z.string()))
+    .describe(
+      'known-safe circular dependencies, each listing its modules relative to `cwd` in execution order.',
+    )
+    .optional(),
   // FIXME: should use a more specific schema
   treeshake: zodExt.phantom<boolean | TreeshakingOptions>().optional(),
   logLevel: LogLevelOptionSchema.describe(
@@ -94,6 +100,12 @@ export const inputOptionsSchema = z.strictObject({
         ),
     )
     .optional(),
+  onModuleAdded: z
+    .function()
+    .args(z.string())
+    .returns(z.void())
+    .describe('called each time a module is added to the module graph.')
+    .optional(),
   moduleTypes: moduleTypesSchema
     .describe('module types for customized extensions.')
     .optional(),
@@ -129,8 +141,10 @@ export const inputCliOptionsSchema = inputOptionsSchema
     plugins: true,
     onwarn: true,
     onLog: true,
+    onModuleAdded: true,
     resolve: true,
     experimental: true,
+    cycleOrder: true,
   })
 
 type RawInputOptions = z.infer<typeof inputOptionsSchema>
@@ -6,7 +6,7 @@ import type { OutputOptions } from './output-options'
 import type { RolldownPlugin } from '../plugin'
 import type { RenderedChunk } from '../binding'
 
-export type InternalModuleFormat = 'es' | 'cjs' | 'iife'
+export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'
 
 type AddonFunction = (chunk: RenderedChunk) => string | Promise<string>
 
@@ -15,6 +15,7 @@ export interface NormalizedOutputOptions extends OutputOptions {
   dir: string | undefined
   format: InternalModuleFormat
   exports: 'auto' | 'named' | 'default' | 'none'
+  interop: 'auto' | 'default' | 'esModule' | 'compat'
   sourcemap: boolean | 'inline' | 'hidden'
   sourcemapIgnoreList: SourcemapIgnoreListOption
   sourcemapPathTransform: SourcemapPathTransformOption | undefined
@@ -10,8 +10,12 @@ const ModuleFormatSchema = z
   .or(z.literal('module'))
   .or(z.literal('commonjs'))
   .or(z.literal('iife'))
+  .or(z.literal('umd'))
+  .or(z.literal('amd'))
+  .or(z.literal('system'))
+  .or(z.literal('systemjs'))
   .describe(
-    `output format of the generated bundle (supports ${underline('esm')}, cjs, and iife).`,
+    `output format of the generated bundle (supports ${underline('esm')}, cjs, iife, umd, amd, and system).`,
   )
   .optional()
 
@@ -32,6 +36,15 @@ const outputOptionsSchema = z.strictObject({
     )
     .optional(),
   format: ModuleFormatSchema,
+  interop: z
+    .literal('auto')
+    .or(z.literal('default'))
+    .or(z.literal('esModule'))
+    .or(z.literal('compat'))
+    .describe(
+      `what the default import of a CommonJS module binds to (${underline('auto')}, default, esModule, compat)`,
+    )
+    .optional(),
   sourcemap: z
     .boolean()
     .or(z.literal('inline'))
@@ -72,7 +72,7 @@ export interface SourceDescription extends Partial<PartialNull<ModuleOptions>> {
 interface ResolveIdExtraOptions {
   custom?: CustomPluginOptions
   isEntry: boolean
-  kind: 'import' | 'dynamic-import' | 'require-call'
+  kind: 'import' | 'dynamic-import' | 'require-call' | 'resolve-only'
 }
 
 export interface PrivateResolveIdExtraOptions extends ResolveIdExtraOptions {
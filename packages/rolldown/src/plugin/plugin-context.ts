@@ -23,7 +23,13 @@ export interface EmittedAsset {
   source: AssetSource
 }
 
-export type EmittedFile = EmittedAsset
+export interface EmittedChunk {
+  type: 'chunk'
+  name?: string
+  id: string
+}
+
+export type EmittedFile = EmittedAsset | EmittedChunk
 
 export interface PluginContextResolveOptions {
   skipSelf?: boolean
@@ -45,7 +51,7 @@ export class PluginContext {
     importer?: string,
     options?: PluginContextResolveOptions,
   ) => Promise<ResolvedId | null>
-  emitFile: (file: EmittedAsset) => string
+  emitFile: (file: EmittedFile) => string
   getFileName: (referenceId: string) => string
   getModuleInfo: (id: string) => ModuleInfo | null
   getModuleIds: () => IterableIterator<string>
@@ -105,10 +111,13 @@ export class PluginContext {
       const info = data.getModuleOption(res.id) || ({} as ModuleOptions)
       return { ...res, ...info }
     }
-    this.emitFile = (file: EmittedAsset): string => {
+    this.emitFile = (file: EmittedFile): string => {
+      if (file.type === 'chunk') {
+        return context.emitChunk({ name: file.name, id: file.id })
+      }
       if (file.type !== 'asset') {
         return unimplemented(
-          'PluginContext.emitFile: only asset type is supported',
+          'PluginContext.emitFile: only asset and chunk types are supported',
         )
       }
       return context.emitFile({
@@ -9,6 +9,7 @@ export function normalizeOutputOptions(
     dir,
     format,
     exports,
+    interop,
     sourcemap,
     sourcemapIgnoreList,
     sourcemapPathTransform,
@@ -23,6 +24,7 @@ export function normalizeOutputOptions(
     dir: dir,
     format: getFormat(format),
     exports: exports ?? 'auto',
+    interop: interop ?? 'auto',
     sourcemap: sourcemap ?? false,
     sourcemapIgnoreList:
       typeof sourcemapIgnoreList === 'function'
@@ -70,6 +72,19 @@ function getFormat(
       return 'iife'
     }
 
+    case 'umd': {
+      return 'umd'
+    }
+
+    case 'amd': {
+      return 'amd'
+    }
+
+    case 'system':
+    case 'systemjs': {
+      return 'system'
+    }
+
     default:
       unimplemented(`output.format: ${format}`)
   }
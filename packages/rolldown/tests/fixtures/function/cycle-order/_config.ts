@@ -0,0 +1,17 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onwarn = vi.fn()
+
+export default defineTest({
+  config: {
+    cycleOrder: [['./a.js', './b.js']],
+    onwarn,
+  },
+  afterTest: (output) => {
+    // The cycle is known to be safe, so there is no `CIRCULAR_DEPENDENCY` warning.
+    expect(onwarn).not.toHaveBeenCalled()
+    const code = output.output[0].code
+    expect(code.indexOf('"a"')).toBeLessThan(code.indexOf('"b"'))
+  },
+})
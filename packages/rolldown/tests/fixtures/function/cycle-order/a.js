@@ -0,0 +1,2 @@
+import './b.js'
+globalThis.order = [...(globalThis.order ?? []), 'a']
@@ -0,0 +1,2 @@
+import './a.js'
+globalThis.order = [...(globalThis.order ?? []), 'b']
@@ -0,0 +1,24 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+import path from 'node:path'
+
+const addedIds: string[] = []
+
+export default defineTest({
+  config: {
+    onModuleAdded(id) {
+      addedIds.push(path.relative(import.meta.dirname, id))
+    },
+  },
+  afterTest: () => {
+    // Modules are scanned in parallel, but each one is reported once.
+    expect(addedIds.sort()).toStrictEqual([
+      'a.js',
+      'b.js',
+      'main.js',
+      'shared.js',
+    ])
+    // The fixture is run again with composed plugins.
+    addedIds.length = 0
+  },
+})
@@ -0,0 +1,36 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+import path from 'node:path'
+
+let referenceId: string
+let emittedFileName: string
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        buildStart() {
+          referenceId = this.emitFile({
+            type: 'chunk',
+            name: 'emitted-chunk',
+            id: './emitted.js',
+          })
+        },
+        generateBundle() {
+          emittedFileName = this.getFileName(referenceId)
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    const chunk = getOutputChunk(output).find(
+      (chunk) => chunk.fileName === emittedFileName,
+    )
+    expect(chunk).toBeDefined()
+    expect(chunk!.name).toBe('emitted-chunk')
+    expect(chunk!.isEntry).toBe(true)
+    expect(chunk!.facadeModuleId).toBe(path.join(__dirname, 'emitted.js'))
+  },
+})
@@ -0,0 +1 @@
+export const emitted = 'emitted'